@@ -6,6 +6,10 @@ fn main() {
     let radar_coords = dwd_fetch::latlong_to_idx(data.latitude, data.longitude);
     let dwd = dwd_fetch::Config {
         poi_station: dwd_fetch::PoiStation(data.station),
+        coordinates: dwd_fetch::Coordinates {
+            latitude: data.latitude,
+            longitude: data.longitude,
+        },
         radar_coords,
         synop_stations: data.synop_stations,
     };
@@ -1,17 +1,27 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::LazyLock;
 
 use base64::Engine;
-use dwd_fetch::PoiStation;
+use dwd_fetch::{Cache, Location, description::Language};
+use icu_locale::Locale;
+use jiff::tz::TimeZone;
+use weather_layout::{
+    PlanOptions,
+    config::{DisplayConfig, DisplayData, LocationData, dwd_locations},
+    fmt::Units,
+};
 
 #[derive(Debug, Clone, serde::Deserialize)]
 struct ConfigData {
-    station: u16,
-    latitude: f64,
-    longitude: f64,
-    synop_stations: Vec<String>,
+    #[serde(flatten)]
+    location: LocationData,
+    #[serde(default)]
+    locations: BTreeMap<String, LocationData>,
     secret: String,
     access_secret: String,
+    #[serde(flatten)]
+    display: DisplayData,
     #[serde(default)]
     port: Option<u16>,
     #[serde(default)]
@@ -19,11 +29,12 @@ struct ConfigData {
 }
 
 pub struct Config {
-    dwd: dwd_fetch::Config,
+    locations: Vec<(String, dwd_fetch::Config)>,
     secret: [u8; 512],
     access_secret: String, // to view the past x readings
     port: u16,
     host: String,
+    display: DisplayConfig,
 }
 
 pub static CONFIG: LazyLock<Config> = LazyLock::new(|| {
@@ -39,26 +50,26 @@ pub static CONFIG: LazyLock<Config> = LazyLock::new(|| {
 
 impl Config {
     fn new(data: ConfigData) -> Self {
-        let radar_coords = dwd_fetch::latlong_to_idx(data.latitude, data.longitude);
-        let dwd = dwd_fetch::Config {
-            poi_station: PoiStation(data.station),
-            radar_coords,
-            synop_stations: data.synop_stations,
-        };
+        let locations = dwd_locations(data.location, data.locations);
         let secret = base64::prelude::BASE64_STANDARD
             .decode(&data.secret)
             .unwrap();
         Self {
-            dwd,
+            locations,
             secret: secret.try_into().unwrap(),
             access_secret: data.access_secret,
+            display: data.display.load(),
             port: data.port.unwrap_or(8080),
             host: data.host.unwrap_or_else(|| "127.0.0.1".into()),
         }
     }
 
-    pub fn dwd(&self) -> &dwd_fetch::Config {
-        &self.dwd
+    /// Creates the locations with empty caches - the default location is always first
+    pub fn make_locations(&self) -> Vec<Location> {
+        self.locations
+            .iter()
+            .map(|(name, config)| Location::new(name.clone(), config.clone(), Cache::default()))
+            .collect()
     }
 
     pub fn secret(&self) -> &[u8; 512] {
@@ -69,6 +80,27 @@ impl Config {
         &self.access_secret
     }
 
+    pub fn language(&self) -> Language {
+        self.display.language
+    }
+
+    pub fn units(&self) -> Units {
+        self.display.units
+    }
+
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.display.time_zone
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.display.locale
+    }
+
+    /// Time span of the forecast graph, already validated
+    pub fn plan_options(&self) -> &PlanOptions {
+        &self.display.plan_options
+    }
+
     pub fn port(&self) -> u16 {
         self.port
     }
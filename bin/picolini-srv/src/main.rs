@@ -1,5 +1,3 @@
-use std::sync::{Arc, RwLock};
-
 use dwd_fetch::Cache;
 
 use crate::{config::CONFIG, fonts::Fonts, paint::PaintCtx};
@@ -25,13 +23,13 @@ fn main() -> std::io::Result<()> {
 }
 
 fn one_off_render() {
-    let cache = Arc::new(RwLock::new(Cache::from_file_or_default("cache.bin")));
-    Cache::refetch(&cache, CONFIG.dwd()).unwrap();
+    let location = CONFIG.make_locations().swap_remove(0);
+    *location.cache.write().unwrap() = Cache::from_file_or_default("cache.bin");
+    Cache::refetch(&location.cache, &location.config).unwrap();
 
     let paint_ctx = PaintCtx {
         fonts: Fonts::new("./fonts/InterVariable.ttf"),
     };
-    let data =
-        render::to_dithered_png(&cache.read().unwrap(), &paint_ctx, 20.3, 50.0, 400.0).unwrap();
+    let data = render::to_dithered_png(&location, &paint_ctx, 20.3, 50.0, 400.0).unwrap();
     std::fs::write("epd.png", &data).unwrap()
 }
@@ -1,12 +1,12 @@
-use icu_datetime::{fieldsets::YMDE, input::Date};
-use icu_locale::locale;
+use dwd_fetch::{Coordinates, moon, sun::SunTimes};
 use skia_safe::{Canvas, Color, Paint, PaintStyle, Point, RRect, Rect};
 use skia_util::{
     PointExt, RectExt,
     text::{Align, align_text},
 };
+use weather_layout::fmt::{self, DateFormatter};
 
-use crate::{fonts::Fonts, layout_ctx::LayoutCtx, plan::Plans};
+use crate::{config::CONFIG, fonts::Fonts, layout_ctx::LayoutCtx, plan::Plans};
 
 pub struct PaintCtx {
     pub fonts: Fonts,
@@ -15,6 +15,20 @@ pub struct PaintCtx {
 pub fn main(canvas: &Canvas, plans: &Plans, layout_ctx: &LayoutCtx, paint_ctx: &PaintCtx) {
     let mut p = Paint::default();
 
+    // nights are dithered to a light pattern
+    p.set_color(Color::from_rgb(225, 225, 225));
+    for night in &plans.nights.nights {
+        canvas.draw_rect(
+            Rect::from_ltrb(
+                night.start_x,
+                plans.inner_main_rect.top,
+                night.end_x,
+                plans.inner_main_rect.bottom,
+            ),
+            &p,
+        );
+    }
+
     p.set_color(Color::from_rgb(0, 0, 0));
     p.set_stroke(true);
     p.set_stroke_width(1.0);
@@ -35,6 +49,19 @@ pub fn main(canvas: &Canvas, plans: &Plans, layout_ctx: &LayoutCtx, paint_ctx: &
             &p,
         );
     }
+    for x in plans
+        .nights
+        .nights
+        .iter()
+        .flat_map(|n| [n.sunset_x, n.sunrise_x])
+        .flatten()
+    {
+        canvas.draw_line(
+            (x, plans.inner_main_rect.bottom - 3.0),
+            (x, plans.inner_main_rect.bottom + 3.0),
+            &p,
+        );
+    }
     p.set_anti_alias(true);
 
     p.set_style(PaintStyle::Fill);
@@ -77,26 +104,46 @@ pub fn main(canvas: &Canvas, plans: &Plans, layout_ctx: &LayoutCtx, paint_ctx: &
     }
 }
 
-pub fn top(canvas: &Canvas, layout_ctx: &LayoutCtx, paint_ctx: &PaintCtx) {
+pub fn top(canvas: &Canvas, layout_ctx: &LayoutCtx, paint_ctx: &PaintCtx, at: Coordinates) {
     let mut p = Paint::default();
     p.set_style(PaintStyle::Fill);
     p.set_color(Color::from_rgb(0, 0, 0));
 
-    let my_tz = jiff::tz::db().get("Europe/Berlin").unwrap();
-    let now = jiff::Timestamp::now().to_zoned(my_tz);
+    let now = jiff::Timestamp::now().to_zoned(CONFIG.time_zone().clone());
 
     canvas.draw_text_align(
-        icu_datetime::DateTimeFormatter::try_new(locale!("en-GB").into(), YMDE::long())
-            .unwrap()
-            .format(
-                &Date::try_new_iso(now.year() as i32, now.month() as u8, now.day() as u8).unwrap(),
-            )
-            .to_string(),
+        DateFormatter::new(CONFIG.locale()).long(now.date()),
         layout_ctx.top_rect.bottom_left() + Point::new(5.0, -5.0),
         &paint_ctx.fonts.big_bold,
         &p,
         skia_safe::utils::text_utils::Align::Left,
     );
+
+    // sunrise, sunset and the moon on the right
+    let sun = SunTimes::on(now.date(), at);
+    let time = |ts: Option<jiff::Timestamp>| {
+        ts.map_or_else(
+            || "--:--".to_owned(),
+            |ts| {
+                ts.to_zoned(CONFIG.time_zone().clone())
+                    .strftime("%H:%M")
+                    .to_string()
+            },
+        )
+    };
+    let moon_at = now.timestamp();
+    canvas.draw_text_align(
+        format!(
+            "{}  {} ({:.0}%)",
+            fmt::sun_times(CONFIG.language(), &time(sun.sunrise), &time(sun.sunset)),
+            moon::phase(moon_at).text(CONFIG.language()),
+            moon::illumination(moon_at) * 100.0
+        ),
+        layout_ctx.top_rect.bottom_right() + Point::new(-5.0, -5.0),
+        &paint_ctx.fonts.small,
+        &p,
+        skia_safe::utils::text_utils::Align::Right,
+    );
 }
 
 pub fn bottom_left(canvas: &Canvas, plans: &Plans, layout_ctx: &LayoutCtx, paint_ctx: &PaintCtx) {
@@ -121,7 +168,7 @@ pub fn bottom_left(canvas: &Canvas, plans: &Plans, layout_ctx: &LayoutCtx, paint
     if let Some(ref current) = plans.current {
         let temp_y = if let Some(temp) = current.temperature {
             let (blob, origin) = align_text(
-                &format!("{temp:.*}°", if temp.fract() == 0.0 { 0 } else { 1 }),
+                &CONFIG.units().temperature(temp),
                 &paint_ctx.fonts.big_bold,
                 top_half.center(),
                 Align::Bottom,
@@ -132,9 +179,18 @@ pub fn bottom_left(canvas: &Canvas, plans: &Plans, layout_ctx: &LayoutCtx, paint
             None
         };
 
-        if let Some(wind) = current.mean_wind {
+        let mut below_y = temp_y;
+        // wind and pressure share a line
+        let wind_pressure = [
+            current.mean_wind.map(|wind| CONFIG.units().wind(wind)),
+            current.pressure_msl.map(|hpa| CONFIG.units().pressure(hpa)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if !wind_pressure.is_empty() {
             let (blob, origin) = align_text(
-                &format!("{wind:.*} km/h", if wind.fract() == 0.0 { 0 } else { 1 }),
+                &wind_pressure.join("  "),
                 &paint_ctx.fonts.medium,
                 if let Some(y) = temp_y {
                     Point::new(top_half.center_x(), y + 4.0)
@@ -147,6 +203,32 @@ pub fn bottom_left(canvas: &Canvas, plans: &Plans, layout_ctx: &LayoutCtx, paint
                     Align::Center
                 },
             );
+            below_y = Some(origin.y);
+            canvas.draw_text_blob(blob, origin, &p);
+        }
+
+        let feels_like = current
+            .apparent_temperature()
+            .zip(current.temperature)
+            .and_then(|(feels, temp)| CONFIG.units().feels_like(CONFIG.language(), temp, feels));
+        if let (Some(feels_like), Some(y)) = (feels_like, below_y) {
+            let (blob, origin) = align_text(
+                &feels_like,
+                &paint_ctx.fonts.small,
+                Point::new(top_half.center_x(), y + 4.0),
+                Align::TopCenter,
+            );
+            below_y = Some(origin.y);
+            canvas.draw_text_blob(blob, origin, &p);
+        }
+
+        if let (Some(text), Some(y)) = (current.description(CONFIG.language()), below_y) {
+            let (blob, origin) = align_text(
+                text,
+                &paint_ctx.fonts.small,
+                Point::new(top_half.center_x(), y + 4.0),
+                Align::TopCenter,
+            );
             canvas.draw_text_blob(blob, origin, &p);
         }
     }
@@ -228,7 +310,7 @@ pub fn bottom_right(
     canvas.draw_text_blob(blob, origin, &p);
 
     let (blob, origin) = align_text(
-        &format!("{temp:.*}°", if temp.fract() == 0.0 { 0 } else { 1 }),
+        &CONFIG.units().temperature(temp),
         &paint_ctx.fonts.big_bold,
         layout_ctx.bottom_right_rect.center(),
         Align::Bottom,
@@ -1,18 +1,19 @@
 use skia_safe::Rect;
 
-use dwd_fetch::{Cache, Datapoint};
+use dwd_fetch::{Cache, Coordinates, Datapoint};
 use weather_layout::{
-    self, Grayscale, HorizontalLine, PPrecipitationPlan, RadarPlan, RainPlan, SectionPlan,
-    TemperaturePlan,
+    self, Grayscale, HorizontalLine, NightPlan, PPrecipitationPlan, RadarPlan, RainPlan,
+    SectionPlan, TemperaturePlan,
 };
 
-use crate::layout_ctx::LayoutCtx;
+use crate::{config::CONFIG, layout_ctx::LayoutCtx};
 
 pub struct Plans {
     pub sections: SectionPlan,
     pub temperature: Option<TemperaturePlan>,
     pub rain: Option<RainPlan>,
     pub p_precipitation: Option<PPrecipitationPlan>,
+    pub nights: NightPlan,
     pub horizontal_lines: Vec<HorizontalLine>,
     pub current: Option<Datapoint>,
     pub radar: Option<RadarPlan>,
@@ -20,22 +21,30 @@ pub struct Plans {
 }
 
 impl Plans {
-    pub fn new(cache: &Cache, ctx: &LayoutCtx) -> Self {
+    pub fn new(cache: &Cache, at: Coordinates, ctx: &LayoutCtx) -> Self {
         let points = Datapoint::merge_series_ref(&cache.report, &cache.forecast);
         let inner_rect = ctx.main_rect.with_inset((20.0, 20.0));
-        let (plan, sections) = weather_layout::plan_in(inner_rect, &points);
+        let (plan, sections) = weather_layout::plan_in(
+            inner_rect,
+            &points,
+            CONFIG.plan_options(),
+            CONFIG.time_zone(),
+            CONFIG.locale(),
+        )
+        .expect("The plan options are validated with the config");
         let (temperature, mut horizontal_lines) =
-            weather_layout::create_temperature_path::<Grayscale>(&plan);
+            weather_layout::create_temperature_path::<Grayscale>(&plan, CONFIG.units().temperature);
         let rain = weather_layout::create_rain_plan::<Grayscale>(&plan, &mut horizontal_lines);
         let p_precipitation = weather_layout::create_p_precipitation_plan(&plan);
+        let nights = weather_layout::create_night_plan(&plan, at);
 
         let current = cache
-            .observation
-            .clone()
+            .observation()
             .or_else(|| points.iter().rfind(|x| x.is_report).cloned());
         let radar = weather_layout::create_radar_plan::<Grayscale>(
             ctx.bottom_left_rect.with_inset((20.0, 0.0)),
             &cache.radar,
+            CONFIG.time_zone(),
         );
 
         Self {
@@ -43,6 +52,7 @@ impl Plans {
             temperature,
             rain,
             p_precipitation,
+            nights,
             horizontal_lines,
             current,
             radar,
@@ -1,5 +1,5 @@
 use anyhow::{anyhow, bail};
-use dwd_fetch::Cache;
+use dwd_fetch::Location;
 use skia_safe::{
     Color, Color4f, ColorInfo, EncodedImageFormat, Image, ImageInfo, Paint, Pixmap, surfaces,
 };
@@ -12,13 +12,13 @@ use crate::{
 };
 
 pub fn to_dithered_png(
-    cache: &Cache,
+    location: &Location,
     paint_ctx: &PaintCtx,
     temp: f32,
     iaq: f32,
     co2: f32,
 ) -> anyhow::Result<Box<[u8]>> {
-    let image = to_image(cache, paint_ctx, temp, iaq, co2)?;
+    let image = to_image(location, paint_ctx, temp, iaq, co2)?;
     let mut pixmap = image
         .peek_pixels()
         .ok_or_else(|| anyhow!("Can't peek pixels"))?;
@@ -30,13 +30,13 @@ pub fn to_dithered_png(
 }
 
 pub fn full(
-    cache: &Cache,
+    location: &Location,
     paint_ctx: &PaintCtx,
     temp: f32,
     iaq: f32,
     co2: f32,
 ) -> anyhow::Result<Box<[u8]>> {
-    let image = to_image(cache, paint_ctx, temp, iaq, co2)?;
+    let image = to_image(location, paint_ctx, temp, iaq, co2)?;
     let mut pixmap = image
         .peek_pixels()
         .ok_or_else(|| anyhow!("Can't peek pixels"))?;
@@ -44,7 +44,7 @@ pub fn full(
 }
 
 fn to_image(
-    cache: &Cache,
+    location: &Location,
     paint_ctx: &PaintCtx,
     temp: f32,
     iaq: f32,
@@ -80,10 +80,14 @@ fn to_image(
     );
     canvas.draw_line(layout_ctx.bottom_divider.0, layout_ctx.bottom_divider.1, &p);
 
-    let plans = Plans::new(cache, &layout_ctx);
+    let plans = Plans::new(
+        &location.cache.read().unwrap(),
+        location.config.coordinates,
+        &layout_ctx,
+    );
 
     paint::main(canvas, &plans, &layout_ctx, paint_ctx);
-    paint::top(canvas, &layout_ctx, paint_ctx);
+    paint::top(canvas, &layout_ctx, paint_ctx, location.config.coordinates);
     paint::bottom_left(canvas, &plans, &layout_ctx, paint_ctx);
     paint::bottom_right(canvas, &layout_ctx, paint_ctx, temp, iaq, co2);
 
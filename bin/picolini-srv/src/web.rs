@@ -1,4 +1,7 @@
-use std::sync::{Arc, RwLock};
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 use actix_web::{
     App, HttpRequest, HttpResponse, HttpServer, Responder,
@@ -9,12 +12,13 @@ use actix_web::{
 };
 use arraydeque::ArrayDeque;
 use constant_time_eq::constant_time_eq;
-use dwd_fetch::Cache;
+use dwd_fetch::{Cache, Location};
 use jiff::tz::TimeZone;
 
 use crate::{config::CONFIG, fonts::Fonts, paint::PaintCtx, render};
 
 const BSEC_STATE_LEN: usize = 180;
+const FETCH_TIMEOUT: Duration = Duration::from_secs(20);
 
 #[derive(bincode::Decode, bincode::Encode)]
 struct BodyData {
@@ -26,10 +30,18 @@ struct BodyData {
 }
 
 struct AppState {
-    pub cache: Arc<RwLock<Cache>>,
+    /// The default location is always first
+    pub locations: Vec<Location>,
     pub paint_ctx: PaintCtx,
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct RefreshQuery {
+    /// Location to render - each device can request its own
+    #[serde(default)]
+    pub location: Option<String>,
+}
+
 #[derive(Default, Debug, serde::Serialize, serde::Deserialize)]
 struct DataItem {
     pub timestamp: i64,
@@ -55,6 +67,9 @@ enum MyError {
     #[error("Silly input")]
     #[status(401)]
     SillyInput,
+    #[error("Unknown location")]
+    #[status(404)]
+    UnknownLocation,
     #[error("Idk kev")]
     Other,
 }
@@ -62,6 +77,7 @@ enum MyError {
 #[post("/refresh")]
 async fn refresh(
     data: web::Bytes,
+    query: web::Query<RefreshQuery>,
     state: web::Data<AppState>,
     hist: web::Data<RwLock<History>>,
 ) -> Result<Vec<u8>, MyError> {
@@ -71,6 +87,12 @@ async fn refresh(
     if !constant_time_eq::constant_time_eq_n(&data.secret, CONFIG.secret()) {
         return Err(MyError::SillyInput);
     }
+    let location = match query.into_inner().location {
+        Some(name) => Location::find(&state.locations, &name)
+            .ok_or(MyError::UnknownLocation)?
+            .clone(),
+        None => state.locations[0].clone(),
+    };
     std::fs::write("state.bin", data.state).map_err(|_| MyError::Other)?;
 
     let item = DataItem {
@@ -83,11 +105,21 @@ async fn refresh(
         w.0.push_back(item);
     }
 
-    let res = actix_web::rt::task::spawn_blocking(move || -> anyhow::Result<Box<[u8]>> {
-        Cache::refetch(&state.cache, CONFIG.dwd())?;
+    // slow downloads are cancelled - the previous data is rendered instead
+    match actix_web::rt::time::timeout(
+        FETCH_TIMEOUT,
+        Cache::refetch_async(&location.cache, &location.config),
+    )
+    .await
+    {
+        Ok(Err(e)) => tracing::warn!(%e, "Failed to refetch"),
+        Err(_) => tracing::warn!(timeout = ?FETCH_TIMEOUT, "Refetching timed out"),
+        Ok(Ok(_)) => (),
+    }
 
+    let res = actix_web::rt::task::spawn_blocking(move || -> anyhow::Result<Box<[u8]>> {
         render::full(
-            &state.cache.read().unwrap(),
+            &location,
             &state.paint_ctx,
             data.temperature,
             data.iaq,
@@ -133,14 +165,13 @@ fn is_night(tz: TimeZone) -> bool {
 
 #[actix_web::main]
 pub async fn main() -> std::io::Result<()> {
-    let my_tz = jiff::tz::db().get("Europe/Berlin").unwrap();
-
     let history_data = Arc::new(RwLock::new(History::default()));
+    let locations = CONFIG.make_locations();
     HttpServer::new(move || {
-        let tz = my_tz.clone();
+        let tz = CONFIG.time_zone().clone();
         App::new()
             .app_data(web::Data::new(AppState {
-                cache: Arc::new(RwLock::new(Cache::default())),
+                locations: locations.clone(),
                 paint_ctx: PaintCtx {
                     fonts: Fonts::new("./fonts/InterVariable.ttf"),
                 },
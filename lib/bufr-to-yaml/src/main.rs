@@ -1,176 +1,298 @@
-use std::io::{Read, Write};
+use std::{
+    collections::HashSet,
+    io::{BufWriter, Read, Write},
+    path::PathBuf,
+};
 
 use anyhow::bail;
-use dwd_bufr_tables::{DWD_BUFR_TABLE_B, DWD_BUFR_TABLE_D};
-use dwd_gts::GtsHeader;
-use tinybufr::{DataEvent, DataReader, DataSpec, HeaderSections, Tables};
+use clap::Parser;
+use dwd_bufr::{Element, Message, Node, Query, Subset, VersionedTables};
+use dwd_gts::{BulletinReader, GtsHeader};
+use serde_json::{Map, Value, json};
+use tinybufr::{Tables, XY};
+
+use crate::output::{Format, Output};
+
+mod output;
+
+/// Dumps the BUFR messages in a GTS file.
+#[derive(Parser)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// The GTS file to read, `-` or nothing reads from stdin
+    input: Option<PathBuf>,
+    /// Write to this file instead of stdout
+    output: Option<PathBuf>,
+    #[arg(short, long, value_enum, default_value_t = Format::Yaml)]
+    format: Format,
+    /// Only include these stations (WIGOS local identifier or WMO block and station number)
+    #[arg(short, long)]
+    station: Vec<String>,
+    /// Only include elements matching a query (e.g. `012101 under 007032=2.0`)
+    #[arg(short, long)]
+    descriptor: Vec<String>,
+    /// Only include bulletins whose product (T1T2A1A2ii) starts with this
+    #[arg(short, long)]
+    product: Vec<String>,
+    /// List the stations and observation times instead of the data
+    #[arg(long)]
+    summary: bool,
+}
+
+const BLOCK_NUMBER: XY = XY { x: 1, y: 1 };
+const STATION_NUMBER: XY = XY { x: 1, y: 2 };
+const STATION_NAME: XY = XY { x: 1, y: 15 };
+const WIGOS_LOCAL_ID: XY = XY { x: 1, y: 128 };
 
 fn main() -> anyhow::Result<()> {
-    let mut args = std::env::args();
-    let _ = args.next();
-    let Some(input) = args.next() else {
-        bail!("Missing input");
+    let args = Args::parse();
+
+    let reader: Box<dyn Read> = match args.input {
+        Some(input) if input.as_os_str() != "-" => Box::new(std::fs::File::open(input)?),
+        _ => Box::new(std::io::stdin().lock()),
     };
-    let output = args.next();
-
-    let reader = std::fs::File::open(input)?;
-    let writer: Box<dyn Write> = if let Some(out) = output {
-        Box::new(
-            std::fs::OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(out)?,
-        )
-    } else {
-        Box::new(std::io::stdout().lock())
+    let writer: Box<dyn Write> = match args.output {
+        Some(out) => Box::new(BufWriter::new(std::fs::File::create(out)?)),
+        None => Box::new(std::io::stdout().lock()),
+    };
+    let filter = Filter {
+        stations: args.station,
+        queries: args
+            .descriptor
+            .iter()
+            .map(|q| q.parse())
+            .collect::<Result<_, _>>()?,
+        products: args.product,
     };
-    let mut dumper = Dumper::new(writer);
-    handle_file(reader, &mut dumper)?;
 
-    Ok(())
+    let mut output = Output::new(writer, args.format);
+    handle_file(reader, &filter, args.summary, &mut output)?;
+    output.finish()
 }
 
-struct Dumper<W: Write> {
-    indent: usize,
-    writer: W,
+struct Filter {
+    stations: Vec<String>,
+    queries: Vec<Query>,
+    products: Vec<String>,
 }
 
-impl<W: Write> Dumper<W> {
-    pub fn new(writer: W) -> Self {
-        Self { indent: 0, writer }
+impl Filter {
+    fn includes_bulletin(&self, header: &GtsHeader) -> bool {
+        self.products.is_empty()
+            || self
+                .products
+                .iter()
+                .any(|p| header.product_id.as_str().starts_with(p.as_str()))
     }
 
-    pub fn writer(&mut self) -> std::io::Result<&mut W> {
-        for _ in 0..self.indent {
-            self.writer.write_all(b"  ")?;
-        }
-        Ok(&mut self.writer)
+    fn includes_subset(&self, subset: &Subset) -> bool {
+        self.stations.is_empty() || station_id(subset).is_some_and(|id| self.stations.contains(&id))
     }
 
-    pub fn indent(&mut self) {
-        self.indent += 1;
+    /// The elements matched by the queries or `None` if all elements are included.
+    fn matched_elements(&self, subset: &Subset) -> Option<HashSet<*const Element>> {
+        if self.queries.is_empty() {
+            return None;
+        }
+        Some(
+            self.queries
+                .iter()
+                .flat_map(|q| subset.query(q))
+                .map(|m| std::ptr::from_ref(m.element))
+                .collect(),
+        )
     }
+}
 
-    pub fn dedent(&mut self) {
-        self.indent -= 1;
+/// The WIGOS local identifier or the WMO block and station number.
+fn station_id(subset: &Subset) -> Option<String> {
+    if let Some(id) = subset
+        .get(WIGOS_LOCAL_ID)
+        .and_then(Element::as_str)
+        .filter(|id| !id.is_empty())
+    {
+        return Some(id.to_owned());
     }
+    let block = subset.get(BLOCK_NUMBER)?.as_i32()?;
+    let station = subset.get(STATION_NUMBER)?.as_i32()?;
+    Some(format!("{block:02}{station:03}"))
 }
 
-fn dump<R: Read>(
-    mut bounded: R,
-    tables: &Tables,
-    dumper: &mut Dumper<impl Write>,
-) -> anyhow::Result<R> {
-    let header = HeaderSections::read(&mut bounded).unwrap();
-    let data_spec =
-        DataSpec::from_data_description(&header.data_description_section, tables).unwrap();
-    let mut data_reader = DataReader::new(bounded, &data_spec).unwrap();
-
-    loop {
-        match data_reader.read_event() {
-            Ok(DataEvent::SubsetStart(id)) => {
-                writeln!(dumper.writer()?, "- {id}:")?;
-                dumper.indent();
-            }
-            Ok(DataEvent::SubsetEnd) => dumper.dedent(),
-            Ok(DataEvent::ReplicationStart { idx, count }) => {
-                writeln!(dumper.writer()?, "- replicate_{idx}({count}):")?;
-                dumper.indent();
-            }
-            Ok(DataEvent::ReplicationEnd) => dumper.dedent(),
-            Ok(DataEvent::ReplicationItemStart) => {
-                writeln!(dumper.writer()?, "-")?;
-                dumper.indent();
-            }
-            Ok(DataEvent::ReplicationItemEnd) => dumper.dedent(),
-            Ok(DataEvent::SequenceStart { xy, .. }) => {
-                if let Some(xd) = tables.table_d.get(&xy) {
-                    writeln!(dumper.writer()?, "# {}", xd.title)?;
-                }
-                writeln!(dumper.writer()?, "- 3 {} {}:", xy.x, xy.y)?;
-                dumper.indent();
-            }
-            Ok(DataEvent::SequenceEnd) => dumper.dedent(),
-            Ok(DataEvent::OperatorHandled { .. }) => (),
-            Ok(DataEvent::Data { xy, value, .. }) => {
-                let w = dumper.writer()?;
-                write!(w, "- 0 {} {}: {value:?}", xy.x, xy.y)?;
-                if let Some(xd) = tables.table_b.get(&xy) {
-                    write!(w, " # [{}] {}", xd.unit, xd.element_name)?;
-                }
-                writeln!(w)?;
-            }
+/// The time of the observation (`004001` - `004005`) as `YYYY-MM-DDTHH:MMZ`.
+fn observation_time(subset: &Subset) -> Option<String> {
+    let get = |y| subset.get(XY { x: 4, y })?.as_i32();
+    Some(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}Z",
+        get(1)?,
+        get(2)?,
+        get(3)?,
+        get(4)?,
+        get(5)?
+    ))
+}
 
-            Ok(DataEvent::Eof) => break,
-            Err(e) => return Err(e.into()),
-            _ => unimplemented!(),
-        }
+/// The abbreviated heading (e.g. `ISMD01 EDZW 171200 CCA`).
+fn heading(header: &GtsHeader) -> String {
+    let mut heading = format!(
+        "{} {} {:02}{:02}{:02}",
+        header.product_id, header.source, header.day, header.hour, header.minute
+    );
+    if let Some(bbb) = header.bbb {
+        heading.push(' ');
+        heading.push_str(&bbb.to_string());
     }
-    Ok(data_reader.into_inner())
+    heading
 }
 
-fn handle_file(mut r: impl Read, dumper: &mut Dumper<impl Write>) -> anyhow::Result<()> {
-    let tables = make_tables();
-
-    loop {
-        // 8 bytes ASCII message length
-        let mut gts_bytes = [0u8; 8];
-        r.read_exact(&mut gts_bytes)?;
-        let Some(length) = atoi::atoi::<u64>(&gts_bytes).filter(|x| *x > 0) else {
-            break;
-        };
-        // skip two '0'
-        r.read_exact(&mut gts_bytes[..2])?;
-
-        let mut bounded = r.by_ref().take(length);
-        let mut gts_header_bytes = [0; 35];
-        bounded.read_exact(&mut gts_header_bytes[..31])?;
-        if &gts_header_bytes[28..31] != b"\r\r\n" {
-            bounded.read_exact(&mut gts_header_bytes[31..])?;
-            if &gts_header_bytes[32..] != b"\r\r\n" {
-                bail!("invalid GTC header end");
-            }
-        }
-        let gts_header = GtsHeader::read(&gts_header_bytes)?;
-        println!("{} {}", gts_header.product_id, gts_header.source);
-        if length == 31 + 7 {
-            let mut nil_end = [0; 7];
-            bounded.read_exact(&mut nil_end)?;
-            if &nil_end != b"NIL\r\r\n\x03" {
-                bail!("Invalid nil message");
-            }
+fn handle_file(
+    r: impl Read,
+    filter: &Filter,
+    summary: bool,
+    output: &mut Output<impl Write>,
+) -> anyhow::Result<()> {
+    let tables = VersionedTables::new();
+
+    let mut bulletins = BulletinReader::new(r);
+    while let Some(bulletin) = bulletins.next_bulletin() {
+        let bulletin = bulletin?;
+        if !filter.includes_bulletin(&bulletin.header) {
             continue;
         }
+        let heading = heading(&bulletin.header);
 
-        writeln!(
-            dumper.writer()?,
-            "- {} {}:",
-            gts_header.product_id,
-            gts_header.source
-        )?;
-        dumper.indent();
-
-        let mut bounded = dump(bounded, &tables, dumper)?;
-        let mut footer = [0; 8];
-        bounded.read_exact(&mut footer)?;
-        if &footer != b"7777\r\r\n\x03" {
-            bail!("invalid BUFR end or GTC end");
+        let (message, mut bulletin) = Message::read(bulletin, &tables)?;
+        let mut end = [0; 4];
+        bulletin.read_exact(&mut end)?;
+        if &end != b"7777" {
+            bail!("invalid BUFR end");
         }
 
-        dumper.dedent();
+        let tables = tables.get(&message.header);
+        let subsets = message
+            .subsets
+            .iter()
+            .enumerate()
+            .filter(|(_, subset)| filter.includes_subset(subset));
+        if summary {
+            for (id, subset) in subsets {
+                output.record(&json!({
+                    "bulletin": heading,
+                    "subset": id,
+                    "station": station_id(subset),
+                    "name": subset.get(STATION_NAME).and_then(Element::as_str),
+                    "time": observation_time(subset),
+                }))?;
+            }
+        } else if output.is_flat() {
+            for (id, subset) in subsets {
+                let matched = filter.matched_elements(subset);
+                let station = station_id(subset);
+                for (element, _) in subset.elements() {
+                    if !is_included(matched.as_ref(), element) {
+                        continue;
+                    }
+                    let mut record = Map::new();
+                    record.insert("bulletin".into(), heading.clone().into());
+                    record.insert("subset".into(), id.into());
+                    record.insert("station".into(), station.clone().into());
+                    record.extend(element_fields(element));
+                    output.record(&Value::Object(record))?;
+                }
+            }
+        } else {
+            let subsets = subsets
+                .map(|(id, subset)| {
+                    let matched = filter.matched_elements(subset);
+                    json!({
+                        "subset": id,
+                        "station": station_id(subset),
+                        "nodes": nodes_json(&subset.nodes, tables, matched.as_ref()),
+                    })
+                })
+                .collect::<Vec<_>>();
+            if subsets.is_empty() && !filter.stations.is_empty() {
+                continue;
+            }
+            output.record(&json!({ "bulletin": heading, "subsets": subsets }))?;
+        }
     }
 
     Ok(())
 }
 
-fn make_tables() -> Tables {
-    let mut tables = Tables::default();
-    for it in &DWD_BUFR_TABLE_B {
-        tables.table_b.insert(it.xy, it);
-    }
-    for it in &DWD_BUFR_TABLE_D {
-        tables.table_d.insert(it.xy, it);
+fn is_included(matched: Option<&HashSet<*const Element>>, element: &Element) -> bool {
+    matched.is_none_or(|m| m.contains(&std::ptr::from_ref(element)))
+}
+
+/// The nodes as a tree - with a descriptor filter, sequences and replications without matches are
+/// left out.
+fn nodes_json(
+    nodes: &[Node],
+    tables: &Tables,
+    matched: Option<&HashSet<*const Element>>,
+) -> Vec<Value> {
+    nodes
+        .iter()
+        .filter_map(|node| match node {
+            Node::Data(element) => {
+                is_included(matched, element).then(|| Value::Object(element_fields(element)))
+            }
+            Node::Sequence { xy, children } => {
+                let children = nodes_json(children, tables, matched);
+                if children.is_empty() && matched.is_some() {
+                    return None;
+                }
+                Some(json!({
+                    "descriptor": format!("3{:02}{:03}", xy.x, xy.y),
+                    "title": tables.table_d.get(xy).map(|d| d.title),
+                    "children": children,
+                }))
+            }
+            Node::Replication { items } => {
+                let items = items
+                    .iter()
+                    .map(|item| nodes_json(item, tables, matched))
+                    .filter(|item| !item.is_empty() || matched.is_none())
+                    .collect::<Vec<_>>();
+                if items.is_empty() && matched.is_some() {
+                    return None;
+                }
+                Some(json!({ "replication": items }))
+            }
+        })
+        .collect()
+}
+
+fn element_fields(element: &Element) -> Map<String, Value> {
+    let meanings = element.meanings();
+    let mut fields = Map::new();
+    fields.insert(
+        "descriptor".into(),
+        format!("0{:02}{:03}", element.xy.x, element.xy.y).into(),
+    );
+    fields.insert("name".into(), element.name().into());
+    fields.insert("unit".into(), element.unit().into());
+    fields.insert("value".into(), value_json(&element.value));
+    fields.insert(
+        "meaning".into(),
+        (!meanings.is_empty()).then(|| meanings.join("; ")).into(),
+    );
+    fields
+}
+
+/// The value with its exact decimal representation.
+fn value_json(value: &tinybufr::Value) -> Value {
+    match value {
+        tinybufr::Value::Integer(i) => (*i).into(),
+        tinybufr::Value::Decimal(v, s) => {
+            let scaled = if *s < 0 {
+                *v as f64 / 10f64.powi(-*s as i32)
+            } else {
+                *v as f64 * 10f64.powi(*s as i32)
+            };
+            scaled.into()
+        }
+        tinybufr::Value::String(s) => s.trim_ascii_end().into(),
+        _ => Value::Null,
     }
-    tables
 }
@@ -0,0 +1,149 @@
+use std::io::Write;
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// A list of records
+    Yaml,
+    /// An array of records
+    Json,
+    /// One row per record, nested values are written as JSON
+    Csv,
+}
+
+/// Writes records as they come in.
+pub struct Output<W: Write> {
+    writer: W,
+    format: Format,
+    records: usize,
+    /// The keys of the first record
+    columns: Vec<String>,
+}
+
+impl<W: Write> Output<W> {
+    pub fn new(writer: W, format: Format) -> Self {
+        Self {
+            writer,
+            format,
+            records: 0,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Whether records have to be flat.
+    pub fn is_flat(&self) -> bool {
+        self.format == Format::Csv
+    }
+
+    pub fn record(&mut self, record: &Value) -> anyhow::Result<()> {
+        match self.format {
+            Format::Yaml => {
+                self.writer.write_all(b"- ")?;
+                write_yaml(&mut self.writer, record, 1)?;
+            }
+            Format::Json => {
+                self.writer
+                    .write_all(if self.records == 0 { b"[\n" } else { b",\n" })?;
+                serde_json::to_writer_pretty(&mut self.writer, record)?;
+            }
+            Format::Csv => {
+                let Value::Object(fields) = record else {
+                    anyhow::bail!("CSV records must be objects");
+                };
+                if self.records == 0 {
+                    self.columns = fields.keys().cloned().collect();
+                    let header = self.columns.iter().map(|c| Value::String(c.clone()));
+                    write_csv_row(&mut self.writer, header.collect::<Vec<_>>().iter())?;
+                }
+                let row = self
+                    .columns
+                    .iter()
+                    .map(|c| fields.get(c).unwrap_or(&Value::Null));
+                write_csv_row(&mut self.writer, row)?;
+            }
+        }
+        self.records += 1;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> anyhow::Result<()> {
+        if self.format == Format::Json {
+            self.writer
+                .write_all(if self.records == 0 { b"[]\n" } else { b"\n]\n" })?;
+        }
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Writes `value` as block YAML, the first line continues the current one.
+///
+/// Strings are written as JSON strings, which are valid YAML scalars.
+fn write_yaml(w: &mut impl Write, value: &Value, indent: usize) -> anyhow::Result<()> {
+    let pad = "  ".repeat(indent);
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (i, (key, value)) in map.iter().enumerate() {
+                if i > 0 {
+                    w.write_all(pad.as_bytes())?;
+                }
+                write!(w, "{key}:")?;
+                write_yaml_child(w, value, indent)?;
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    w.write_all(pad.as_bytes())?;
+                }
+                w.write_all(b"- ")?;
+                write_yaml(w, item, indent + 1)?;
+            }
+        }
+        scalar => writeln!(w, "{scalar}")?,
+    }
+    Ok(())
+}
+
+/// Writes the value of a key - collections start on the next line.
+fn write_yaml_child(w: &mut impl Write, value: &Value, indent: usize) -> anyhow::Result<()> {
+    let pad = "  ".repeat(indent);
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            write!(w, "\n{pad}  ")?;
+            write_yaml(w, value, indent + 1)
+        }
+        Value::Array(items) if !items.is_empty() => {
+            write!(w, "\n{pad}")?;
+            write_yaml(w, value, indent)
+        }
+        _ => {
+            w.write_all(b" ")?;
+            write_yaml(w, value, indent)
+        }
+    }
+}
+
+fn write_csv_row<'a>(
+    w: &mut impl Write,
+    fields: impl Iterator<Item = &'a Value>,
+) -> anyhow::Result<()> {
+    for (i, field) in fields.enumerate() {
+        if i > 0 {
+            w.write_all(b",")?;
+        }
+        let field = match field {
+            Value::Null => String::new(),
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        if field.contains([',', '"', '\n', '\r']) {
+            write!(w, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            w.write_all(field.as_bytes())?;
+        }
+    }
+    writeln!(w)?;
+    Ok(())
+}
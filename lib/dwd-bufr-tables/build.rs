@@ -0,0 +1,194 @@
+//! Generates the tables from the CSVs in `tables/`.
+//!
+//! - `tables/<master>_<local>/table_b.csv` and `table_d.csv` hold the local descriptors of a table
+//!   version (see `scripts/read-libdwd-tables.py`).
+//! - `tables/code_tables.csv` and `tables/flag_tables.csv` hold the code and flag tables.
+
+use std::{fmt::Write, fs, path::Path};
+
+fn main() {
+    let dir = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("tables");
+    println!("cargo::rerun-if-changed=tables");
+
+    let mut versions = fs::read_dir(&dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let entry = entry.unwrap();
+            let name = entry.file_name().into_string().ok()?;
+            let (master, local) = name.split_once('_')?;
+            Some((master.parse::<u8>().ok()?, local.parse::<u8>().ok()?))
+        })
+        .collect::<Vec<_>>();
+    versions.sort_unstable();
+    assert!(
+        !versions.is_empty(),
+        "no table versions in {}",
+        dir.display()
+    );
+
+    let mut out = String::from("// This file was automatically generated by build.rs\n\n");
+    for (master, local) in &versions {
+        let version_dir = dir.join(format!("{master}_{local}"));
+        write_table_b(
+            &mut out,
+            &format!("TABLE_B_{master}_{local}"),
+            &version_dir.join("table_b.csv"),
+        );
+        write_table_d(
+            &mut out,
+            &format!("TABLE_D_{master}_{local}"),
+            &version_dir.join("table_d.csv"),
+        );
+    }
+
+    writeln!(
+        out,
+        "pub static TABLE_VERSIONS: [TableVersion; {}] = [",
+        versions.len()
+    )
+    .unwrap();
+    for (master, local) in &versions {
+        writeln!(
+            out,
+            "TableVersion {{ master: {master}, local: {local}, table_b: &TABLE_B_{master}_{local}, table_d: &TABLE_D_{master}_{local} }},"
+        )
+        .unwrap();
+    }
+    out.push_str("];\n\n");
+
+    write_code_table(
+        &mut out,
+        "CODE_TABLES",
+        "CodeTableEntry",
+        "code",
+        &dir.join("code_tables.csv"),
+    );
+    write_code_table(
+        &mut out,
+        "FLAG_TABLES",
+        "FlagTableEntry",
+        "bit",
+        &dir.join("flag_tables.csv"),
+    );
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("tables.rs"), out).unwrap();
+}
+
+fn write_table_b(out: &mut String, name: &str, path: &Path) {
+    let rows = read_csv(path);
+    writeln!(out, "static {name}: [TableBEntry; {}] = [", rows.len()).unwrap();
+    for row in rows {
+        let [fxy, element_name, unit, scale, reference, bits] = &row[..] else {
+            panic!("invalid row in {}: {row:?}", path.display());
+        };
+        let (_, x, y) = parse_fxy(fxy);
+        writeln!(
+            out,
+            "TableBEntry {{ xy: XY {{ x: {x}, y: {y} }}, class_name: \"dwd\", element_name: {element_name:?}, unit: {unit:?}, scale: {scale}, reference_value: {reference}, bits: {bits} }},"
+        )
+        .unwrap();
+    }
+    out.push_str("];\n\n");
+}
+
+fn write_table_d(out: &mut String, name: &str, path: &Path) {
+    // one row per element, the rows of a sequence are consecutive - empty sequences have a single
+    // row without an element
+    let mut sequences: Vec<(String, String, Vec<String>)> = Vec::new();
+    for row in read_csv(path) {
+        let [fxy, title, element] = &row[..] else {
+            panic!("invalid row in {}: {row:?}", path.display());
+        };
+        if !matches!(sequences.last(), Some((last, _, _)) if last == fxy) {
+            sequences.push((fxy.clone(), title.clone(), Vec::new()));
+        }
+        if !element.is_empty() {
+            sequences.last_mut().unwrap().2.push(element.clone());
+        }
+    }
+
+    writeln!(out, "static {name}: [TableDEntry; {}] = [", sequences.len()).unwrap();
+    for (fxy, title, elements) in sequences {
+        let (_, x, y) = parse_fxy(&fxy);
+        write!(
+            out,
+            "TableDEntry {{ xy: XY {{ x: {x}, y: {y} }}, category: \"DWD sequences\", title: {title:?}, sub_title: \"\", elements: &["
+        )
+        .unwrap();
+        for element in elements {
+            let (f, x, y) = parse_fxy(&element);
+            write!(out, "Descriptor {{ f: {f}, x: {x}, y: {y} }},").unwrap();
+        }
+        out.push_str("] },\n");
+    }
+    out.push_str("];\n\n");
+}
+
+fn write_code_table(out: &mut String, name: &str, ty: &str, field: &str, path: &Path) {
+    let mut rows = read_csv(path)
+        .into_iter()
+        .map(|row| {
+            let [fxy, code, meaning] = &row[..] else {
+                panic!("invalid row in {}: {row:?}", path.display());
+            };
+            let (_, x, y) = parse_fxy(fxy);
+            (x, y, code.parse::<u32>().unwrap(), meaning.clone())
+        })
+        .collect::<Vec<_>>();
+    // sorted for the binary search in `code_meaning`
+    rows.sort_unstable_by_key(|(x, y, code, _)| (*x, *y, *code));
+
+    writeln!(out, "pub static {name}: [{ty}; {}] = [", rows.len()).unwrap();
+    for (x, y, code, meaning) in rows {
+        writeln!(
+            out,
+            "{ty} {{ xy: XY {{ x: {x}, y: {y} }}, {field}: {code}, meaning: {meaning:?} }},"
+        )
+        .unwrap();
+    }
+    out.push_str("];\n\n");
+}
+
+/// Parses `FXXYYY`.
+fn parse_fxy(fxy: &str) -> (u8, u8, u8) {
+    let parse = |s: &str| {
+        s.parse()
+            .unwrap_or_else(|_| panic!("invalid descriptor {fxy}"))
+    };
+    assert_eq!(fxy.len(), 6, "invalid descriptor {fxy}");
+    (parse(&fxy[..1]), parse(&fxy[1..3]), parse(&fxy[3..]))
+}
+
+/// Reads the rows of a CSV file without the header.
+fn read_csv(path: &Path) -> Vec<Vec<String>> {
+    let content = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+    content
+        .lines()
+        .skip(1)
+        .filter(|line| !line.is_empty())
+        .map(parse_csv_line)
+        .collect()
+}
+
+/// Splits a line at commas - fields containing commas or quotes are quoted (`"a, ""b"""`).
+fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
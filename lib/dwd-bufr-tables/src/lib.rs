@@ -1,16814 +1,139 @@
-// This file was automatically generated
-
 use tinybufr::{Descriptor, TableBEntry, TableDEntry, XY};
-pub static DWD_BUFR_TABLE_B: [TableBEntry; 822] = [
-    TableBEntry {
-        xy: XY { x: 1, y: 192 },
-        class_name: "dwd",
-        element_name: "ICAO LOCATION INDICATOR",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 193 },
-        class_name: "dwd",
-        element_name: "TYPE OF DATA",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 194 },
-        class_name: "dwd",
-        element_name: "TYPE OF RECCO OBSERVATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 195 },
-        class_name: "dwd",
-        element_name: "AREA OF MIN QNH FORECAST",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 196 },
-        class_name: "dwd",
-        element_name: "TEMPLATE VERSION",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 197 },
-        class_name: "dwd",
-        element_name: "SENSOR INDEX",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 198 },
-        class_name: "dwd",
-        element_name: "TYPE DE SPEMET",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 199 },
-        class_name: "dwd",
-        element_name: "OBJET PRINCIPAL DU SPEMET",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 200 },
-        class_name: "dwd",
-        element_name: "REPORT TRIGGERING WEATHER ELEMENT",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 201 },
-        class_name: "dwd",
-        element_name: "PLACE OF OBS. OF VISIBILTY/CLOUD COVER",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 202 },
-        class_name: "dwd",
-        element_name: "Numero de departement elargi",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 208 },
-        class_name: "dwd",
-        element_name: "X-velocity from earth's centre in direction 0 est",
-        unit: "m/s",
-        scale: 5,
-        reference_value: -1073741824,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 209 },
-        class_name: "dwd",
-        element_name: "Y-velocity from earth's centre in direction 90 est",
-        unit: "m/s",
-        scale: 5,
-        reference_value: -1073741824,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 210 },
-        class_name: "dwd",
-        element_name: "Z-velocity from earth's centre in direction north pole",
-        unit: "m/s",
-        scale: 5,
-        reference_value: -1073741824,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 220 },
-        class_name: "dwd",
-        element_name: "Identification mark of a cloud system ( format YYYYMMDDhhmm_Tb_lat_lon or ``FIRST'')",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 248,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 221 },
-        class_name: "dwd",
-        element_name: "Modele de reference - reference producteur",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 104,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 192 },
-        class_name: "dwd",
-        element_name: "COR MESSAGE MARK",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 193 },
-        class_name: "dwd",
-        element_name: "PSEUDO REPORT",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 194 },
-        class_name: "dwd",
-        element_name: "Indicateur du dernier etalonnage",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 195 },
-        class_name: "dwd",
-        element_name: "Frequence du dernier etalonnage",
-        unit: "Hz",
-        scale: -6,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 196 },
-        class_name: "dwd",
-        element_name: "Reglage bas du dernier etalonnage",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 197 },
-        class_name: "dwd",
-        element_name: "Reglage haut du dernier etalonnage",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 198 },
-        class_name: "dwd",
-        element_name: "Indicateur d'arret ou de panne",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 199 },
-        class_name: "dwd",
-        element_name: "Constante radar",
-        unit: "dB",
-        scale: 1,
-        reference_value: -1000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 200 },
-        class_name: "dwd",
-        element_name: "Indicateur de fonctionnement de l eliminateur echos fixes",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 201 },
-        class_name: "dwd",
-        element_name: "OVERALL QUALITY BITS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 202 },
-        class_name: "dwd",
-        element_name: "INDICATOR FOR TYPE OF PLATFORM",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 203 },
-        class_name: "dwd",
-        element_name: "WAVE MEASUREMENT INSTRUMENTATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 204 },
-        class_name: "dwd",
-        element_name: "INDICATEUR DES UNITES DE VITESSE DU VENT ET DU TYPE D'INSTRUMENTS UTILISES",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 205 },
-        class_name: "dwd",
-        element_name: "TYPE DE RADAR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 206 },
-        class_name: "dwd",
-        element_name: "Indicateur de positionnement en site inhibe",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 207 },
-        class_name: "dwd",
-        element_name: "Sens de balayage en azimut",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 210 },
-        class_name: "dwd",
-        element_name: "TYPE DE SITE (pour les SYNOR)",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 211 },
-        class_name: "dwd",
-        element_name: "TEMPS DE BON FONCTIONNEMENT DE LA STATION",
-        unit: "h",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 212 },
-        class_name: "dwd",
-        element_name: "TEMPS DE BON FONCTIONNEMENT DE LA STATION",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 213 },
-        class_name: "dwd",
-        element_name: "PRECISION DE MESURE DE TEMPERA TURE AMDAR",
-        unit: "K",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 214 },
-        class_name: "dwd",
-        element_name: "RENSEIGNEMENTS SUR L'ETAT DE FONCTIONNEMENT DE LA BOUEE",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 215 },
-        class_name: "dwd",
-        element_name: "INDICATEUR DU TYPE D'ANCRE FLOT TANTE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 1,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 216 },
-        class_name: "dwd",
-        element_name: "Resolution du pluviometre",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 192 },
-        class_name: "dwd",
-        element_name: "DURATION OF TIME RELAT.TO FOLLOW.VALUE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 193 },
-        class_name: "dwd",
-        element_name: "DAY OF OCC.OF DATA VAL.OF PREV.ELEMENT",
-        unit: "d",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 194 },
-        class_name: "dwd",
-        element_name: "DURATION OF PRECIPIT.DURING PAST HOUR",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 195 },
-        class_name: "dwd",
-        element_name: "NOMBRE D'HEURES ECOULEES ENTRE L'HEURE D'OBSERVATION GGgg ET L'HEURE A LAQUELLE SE REFERE LA DERNIERE POSITION",
-        unit: "h",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 196 },
-        class_name: "dwd",
-        element_name: "Duree des phenomenes",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 197 },
-        class_name: "dwd",
-        element_name: "Periode de reference pour les cumuls et extremums",
-        unit: "min",
-        scale: 0,
-        reference_value: -1440,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 198 },
-        class_name: "dwd",
-        element_name: "Duree pendant laquelle l'humidite est >= 80%",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 199 },
-        class_name: "dwd",
-        element_name: "Duree pendant laquelle l'humidite est <= 40%",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 200 },
-        class_name: "dwd",
-        element_name: "Duree de gel sous abri",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 201 },
-        class_name: "dwd",
-        element_name: "NUMBER OF DAYS WITH PRECIPITATION GE 1MM",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 192 },
-        class_name: "dwd",
-        element_name: "Distance Ouest-Est du coin Nord-Ouest de l'image au radar",
-        unit: "m",
-        scale: 0,
-        reference_value: -1024000,
-        bits: 24,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 193 },
-        class_name: "dwd",
-        element_name: "Distance w-e sur le plan de projection du coin nw de l'image",
-        unit: "m",
-        scale: 0,
-        reference_value: -1024000,
-        bits: 24,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 194 },
-        class_name: "dwd",
-        element_name: "Indicateur du centre de projection",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 195 },
-        class_name: "dwd",
-        element_name: "ENSEMBLE MEMBER NUMBER (ECMF)",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 196 },
-        class_name: "dwd",
-        element_name: "Increment de l'azimut entre chaque tir de l'image polaire",
-        unit: "deg",
-        scale: 2,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 197 },
-        class_name: "dwd",
-        element_name: "Increment de l'azimut entre chaque tir de l'image polaire avant interpolation",
-        unit: "deg",
-        scale: 2,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 217 },
-        class_name: "dwd",
-        element_name: "X-location from earth's centre in direction 0 Est",
-        unit: "m",
-        scale: 2,
-        reference_value: -1073741824,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 232 },
-        class_name: "dwd",
-        element_name: "VERIF.:NUMBER OF GRID POINTS IN X DIRECT",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 192 },
-        class_name: "dwd",
-        element_name: "DISTANCE (RECCO)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 193 },
-        class_name: "dwd",
-        element_name: "Distance d application de la correction en 1/r2",
-        unit: "m",
-        scale: -3,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 194 },
-        class_name: "dwd",
-        element_name: "Portee de debut de traitement d'un site",
-        unit: "m",
-        scale: -3,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 195 },
-        class_name: "dwd",
-        element_name: "Portee de fin de traitement d'un site",
-        unit: "m",
-        scale: -3,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 196 },
-        class_name: "dwd",
-        element_name: "Distance oblique maximal d'utilisation des donnees",
-        unit: "m",
-        scale: -3,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 197 },
-        class_name: "dwd",
-        element_name: "Distance NS sur le plan de projection du coin NW de l'image",
-        unit: "m",
-        scale: 0,
-        reference_value: -1024000,
-        bits: 24,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 198 },
-        class_name: "dwd",
-        element_name: "Longitude du meridien parallele a l'axe des Y",
-        unit: "deg",
-        scale: 5,
-        reference_value: -18000000,
-        bits: 26,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 199 },
-        class_name: "dwd",
-        element_name: "Portee hydrologique utile",
-        unit: "m",
-        scale: -3,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 217 },
-        class_name: "dwd",
-        element_name: "Y-location from earth's centre in direction 90 est",
-        unit: "m",
-        scale: 2,
-        reference_value: -1073741824,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 232 },
-        class_name: "dwd",
-        element_name: "VERIF.:NUMBER OF GRID POINTS IN Y DIRECT",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 7, y: 192 },
-        class_name: "dwd",
-        element_name: "HAUTEUR AU DESSUS DU SOL",
-        unit: "m",
-        scale: 2,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 7, y: 193 },
-        class_name: "dwd",
-        element_name: "Brightness temperature",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 7, y: 217 },
-        class_name: "dwd",
-        element_name: "Z-location from earth's centre in direction north pole",
-        unit: "m",
-        scale: 2,
-        reference_value: -1073741824,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 192 },
-        class_name: "dwd",
-        element_name: "FIELD FROM WHICH DEVIATION IS CALCULATED",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 193 },
-        class_name: "dwd",
-        element_name: "SEA-LEVEL PRESSURE OR GEOP. HEIGHT SIGN.",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 194 },
-        class_name: "dwd",
-        element_name: "Caracteristique de l'alerte",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 23,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 195 },
-        class_name: "dwd",
-        element_name: "DATA TYPE (ECMF)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 200 },
-        class_name: "dwd",
-        element_name: "Nature (convective or not) of the cloud system",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 201 },
-        class_name: "dwd",
-        element_name: "Qualitative value",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 202 },
-        class_name: "dwd",
-        element_name: "type de prevision",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 203 },
-        class_name: "dwd",
-        element_name: "Caracteristique temporelle MF",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 204 },
-        class_name: "dwd",
-        element_name: "Caracteristique meteorologique MF",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 10, y: 192 },
-        class_name: "dwd",
-        element_name: "DEPTH OF ROOTS",
-        unit: "m",
-        scale: 2,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 10, y: 193 },
-        class_name: "dwd",
-        element_name: "HEIGHT OF LIGHTNING STRIKE (OVER NN)",
-        unit: "m",
-        scale: -1,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 195 },
-        class_name: "dwd",
-        element_name: "RADIUS OF ZONE",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 35,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 196 },
-        class_name: "dwd",
-        element_name: "ANGLE OF PROPAGATION",
-        unit: "degree true",
-        scale: -1,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 197 },
-        class_name: "dwd",
-        element_name: "SIZE OF ZONE",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 35,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 198 },
-        class_name: "dwd",
-        element_name: "PROBABILITY OF GUSTS",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 199 },
-        class_name: "dwd",
-        element_name: "FORCE OF SURFACE WIND (BEAUFORT SCALE)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 200 },
-        class_name: "dwd",
-        element_name: "TURBULENCE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 201 },
-        class_name: "dwd",
-        element_name: "TYPE OF WIND AT FLIGHT LEVEL",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 202 },
-        class_name: "dwd",
-        element_name: "MEAN WIND SPEED FROM 6 10MIN-MEASUREMENT",
-        unit: "m/s",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 203 },
-        class_name: "dwd",
-        element_name: "QUOTIENT OF VECT.U.SCAL.MEAN OF WIND SP.",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 192 },
-        class_name: "dwd",
-        element_name: "GROUND MINIMUM TEMP.,PAST 10 HOURS(CLIM)",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 193 },
-        class_name: "dwd",
-        element_name: "DEPTH OF SNOW ABOVE GROUND MINIMUM THERM",
-        unit: "m",
-        scale: 2,
-        reference_value: -2,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 194 },
-        class_name: "dwd",
-        element_name: "GROUND MINIMUM TEMP.,PERIOD SPECIFIED",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 195 },
-        class_name: "dwd",
-        element_name: "SNOW ABOVE GROUND MINIMUM THERM., Y/N",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 196 },
-        class_name: "dwd",
-        element_name: "INVERSE MONIN-OBUKHOV LENGTH",
-        unit: "m-1",
-        scale: 3,
-        reference_value: -100000,
-        bits: 18,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 197 },
-        class_name: "dwd",
-        element_name: "AIR STABILITY",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 198 },
-        class_name: "dwd",
-        element_name: "Virtual temperature",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 202 },
-        class_name: "dwd",
-        element_name: "MEAN TEMPERATURE AT 2M",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 203 },
-        class_name: "dwd",
-        element_name: "Minimum temperature,at height and over period specified",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 204 },
-        class_name: "dwd",
-        element_name: "Ground minimum temperature, past 12 hours",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 205 },
-        class_name: "dwd",
-        element_name: "Maximum temperature at 2m, past 12 hours",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 206 },
-        class_name: "dwd",
-        element_name: "Minimum temperature at 2m, past 12 hours",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 207 },
-        class_name: "dwd",
-        element_name: "Maximum temperature at 2m, past 24 hours",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 208 },
-        class_name: "dwd",
-        element_name: "Minimum temperature at 2m, past 24 hours",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 209 },
-        class_name: "dwd",
-        element_name: "Temperature non specifiee (voie 1)",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 210 },
-        class_name: "dwd",
-        element_name: "Temperature non specifiee (voie 2)",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 221 },
-        class_name: "dwd",
-        element_name: "TEMPERAT.AT BOTTOM OF MIDDLE SOIL LAYER",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 222 },
-        class_name: "dwd",
-        element_name: "TEMPERATURE OF SNOW COVER",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 223 },
-        class_name: "dwd",
-        element_name: "TEMPERATURE AT TOP OF MIDDLE SOIL LAYER",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 224 },
-        class_name: "dwd",
-        element_name: "BODY TEMPERATURE OF THE PYRGEOMETER",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 225 },
-        class_name: "dwd",
-        element_name: "COVER TEMPERATURE OF THE PYRGEOMETER",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 226 },
-        class_name: "dwd",
-        element_name: "Temperature de l'air a -20cm",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 227 },
-        class_name: "dwd",
-        element_name: "Temperature de l'air a -50cm",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 228 },
-        class_name: "dwd",
-        element_name: "Temperature de l'air a -100cm",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 229 },
-        class_name: "dwd",
-        element_name: "Temperature minimale a 50cm",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 230 },
-        class_name: "dwd",
-        element_name: "Temperature minimale a 10cm",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 231 },
-        class_name: "dwd",
-        element_name: "Largeur de l'intervalle Tmin",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 232 },
-        class_name: "dwd",
-        element_name: "Largeur de l'intervalle Tmax",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 250 },
-        class_name: "dwd",
-        element_name: "Cooling rate of the cloud system",
-        unit: "K/s",
-        scale: 4,
-        reference_value: -1024,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 252 },
-        class_name: "dwd",
-        element_name: "Skin temperature",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 253 },
-        class_name: "dwd",
-        element_name: "Equivalent black body temperature",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 192 },
-        class_name: "dwd",
-        element_name: "FREQUENCY GROUP, PREC. (UNTIL 1 NOV 94)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 193 },
-        class_name: "dwd",
-        element_name: "TOTAL PRECIPITATION FOR THE MONTH",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 194 },
-        class_name: "dwd",
-        element_name: "MAXIMUM OF DAILY PRECIPITATION",
-        unit: "m",
-        scale: 4,
-        reference_value: -1,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 195 },
-        class_name: "dwd",
-        element_name: "ATP 45 STABILITY CATEGORY",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 196 },
-        class_name: "dwd",
-        element_name: "TYPE OF PRECIPITATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 197 },
-        class_name: "dwd",
-        element_name: "TENEUR EN EAU LIQUIDE VOLUMIQUE DE LA NEIGE",
-        unit: "%",
-        scale: 1,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 198 },
-        class_name: "dwd",
-        element_name: "PROBABILITY OF PRECIPITATION",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 199 },
-        class_name: "dwd",
-        element_name: "Depot de verglas",
-        unit: "kg m-2",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 200 },
-        class_name: "dwd",
-        element_name: "Depot de givre",
-        unit: "kg m-2",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 201 },
-        class_name: "dwd",
-        element_name: "TOTAL DEPTH OF SNOW AT SNOW SAMPLER",
-        unit: "m",
-        scale: 2,
-        reference_value: -2,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 202 },
-        class_name: "dwd",
-        element_name: "TOTAL WATER EQUIVALENT AT SNOW SAMPLER",
-        unit: "m",
-        scale: 4,
-        reference_value: -1,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 204 },
-        class_name: "dwd",
-        element_name: "SNOW, LARGE SCALE + CONVECTIVE",
-        unit: "kg m-2",
-        scale: 1,
-        reference_value: -1,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 209 },
-        class_name: "dwd",
-        element_name: "GROUND WATER RUNOFF",
-        unit: "kg m-2",
-        scale: 2,
-        reference_value: -1,
-        bits: 19,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 192 },
-        class_name: "dwd",
-        element_name: "TOTAL SUNSHINE FOR THE MONTH",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 193 },
-        class_name: "dwd",
-        element_name: "SURFACE HEAT FLUX DENSITY",
-        unit: "W m-2",
-        scale: 1,
-        reference_value: -5000,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 194 },
-        class_name: "dwd",
-        element_name: "Rayonnement global (tres precis) integre sur une periode determinee",
-        unit: "J m-2",
-        scale: -2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 195 },
-        class_name: "dwd",
-        element_name: "Rayonnement diffus (tres precis) integre sur une periode determinee",
-        unit: "J m-2",
-        scale: -2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 196 },
-        class_name: "dwd",
-        element_name: "Rayonnement direct (tres precis) integre sur une periode determinee",
-        unit: "J m-2",
-        scale: -2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 197 },
-        class_name: "dwd",
-        element_name: "Rayonnement infra rouge (tres precis) integre sur une periode determinee",
-        unit: "J m-2",
-        scale: -2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 202 },
-        class_name: "dwd",
-        element_name: "GNSS least squares residual",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 16, y: 193 },
-        class_name: "dwd",
-        element_name: "Number of negative cloud-to-ground lightning flashes",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 16, y: 194 },
-        class_name: "dwd",
-        element_name: "Number of positive cloud-to-ground lightning flashes",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 16, y: 195 },
-        class_name: "dwd",
-        element_name: "Number of intracloud lightning flashes",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 19, y: 192 },
-        class_name: "dwd",
-        element_name: "Length of the small axis of the fitting ellipse of the cloud system",
-        unit: "m",
-        scale: -3,
-        reference_value: 0,
-        bits: 25,
-    },
-    TableBEntry {
-        xy: XY { x: 19, y: 193 },
-        class_name: "dwd",
-        element_name: "Angle of the fitting ellipse of the cloud system",
-        unit: "degree true",
-        scale: 0,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 19, y: 194 },
-        class_name: "dwd",
-        element_name: "Area of the cloud system at this brightness temperature threshold",
-        unit: "m2",
-        scale: -6,
-        reference_value: 0,
-        bits: 28,
-    },
-    TableBEntry {
-        xy: XY { x: 19, y: 200 },
-        class_name: "dwd",
-        element_name: "Area expansion rate of the cloud system",
-        unit: "/s",
-        scale: 5,
-        reference_value: -1048576,
-        bits: 21,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 192 },
-        class_name: "dwd",
-        element_name: "TYPE OF SPECIAL WEATHER PHENOMENA",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 193 },
-        class_name: "dwd",
-        element_name: "ADDITIONAL WEATHER PHENOMENA",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 194 },
-        class_name: "dwd",
-        element_name: "HEIGHT OF TOP OF PHENOMENA",
-        unit: "m",
-        scale: -1,
-        reference_value: -40,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 195 },
-        class_name: "dwd",
-        element_name: "WEATHER PHENOMENA",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 196 },
-        class_name: "dwd",
-        element_name: "DIRECTION OF MAIN OCC.OF WEATHER PHEN.",
-        unit: "deg",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 197 },
-        class_name: "dwd",
-        element_name: "BASE OF CLOUD CLASS",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 198 },
-        class_name: "dwd",
-        element_name: "NUMBER OF VALUES IN BASE OF CLOUD CLASS",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 199 },
-        class_name: "dwd",
-        element_name: "LOWEST HEIGHT O.BASE O.CLOUD DUR.LAST H.",
-        unit: "m",
-        scale: -1,
-        reference_value: -40,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 200 },
-        class_name: "dwd",
-        element_name: "AMOUNT OF (CONVECTIVE) CLOUDS",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 201 },
-        class_name: "dwd",
-        element_name: "PRECIPIT.AT TIME OF OBSERV.(AUTOM.STAT.)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 202 },
-        class_name: "dwd",
-        element_name: "PRESENT WEATHER (CLIMATOLOG.OBSERVATION)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 203 },
-        class_name: "dwd",
-        element_name: "FORM OF PRECIPITATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 204 },
-        class_name: "dwd",
-        element_name: "FALLEN PRECIPITATION (DAY BEFORE)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 205 },
-        class_name: "dwd",
-        element_name: "DEW, RIME ETC. (DAY BEFORE)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 206 },
-        class_name: "dwd",
-        element_name: "OTHER WEATHER (DAY BEFORE)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 207 },
-        class_name: "dwd",
-        element_name: "FLIGHT CONDITIONS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 209 },
-        class_name: "dwd",
-        element_name: "MOISTURE OF GROUND",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 210 },
-        class_name: "dwd",
-        element_name: "SIGNIFICANT WEATRHER PHENOMENA (METGM)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 211 },
-        class_name: "dwd",
-        element_name: "PRECIPITATION DURING PRECEDING HOUR Y/N",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 212 },
-        class_name: "dwd",
-        element_name: "AMOUNT OF SEA ICE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 215 },
-        class_name: "dwd",
-        element_name: "SEA-ICE OPENINGS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 220 },
-        class_name: "dwd",
-        element_name: "AMPLIFICATION OF PHE. REP. BY 020219",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 221 },
-        class_name: "dwd",
-        element_name: "INTENSITY OR PROXIMITY OF WEATHER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 222 },
-        class_name: "dwd",
-        element_name: "DESCRIPTOR OF WEATHER",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 223 },
-        class_name: "dwd",
-        element_name: "WEATHER PHENOMENA (METAR)",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 48,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 192 },
-        class_name: "dwd",
-        element_name: "ORIENTATION OF ELLIPSE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 193 },
-        class_name: "dwd",
-        element_name: "ECHO WIDTH OR DIAMETER",
-        unit: "m",
-        scale: -1,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 194 },
-        class_name: "dwd",
-        element_name: "LENGTH OF ECHO AXIS",
-        unit: "m",
-        scale: -1,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 195 },
-        class_name: "dwd",
-        element_name: "CHARACTER OF ECHO",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 196 },
-        class_name: "dwd",
-        element_name: "INTENSITY OF ECHO",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 197 },
-        class_name: "dwd",
-        element_name: "Uwi product confidence",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 198 },
-        class_name: "dwd",
-        element_name: "Ura product confidence",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 199 },
-        class_name: "dwd",
-        element_name: "Uat quality analysis tests",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 201 },
-        class_name: "dwd",
-        element_name: "Peakiness",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 202 },
-        class_name: "dwd",
-        element_name: "Altimeter calibration status",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 203 },
-        class_name: "dwd",
-        element_name: "Altimeter instrument mode",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 204 },
-        class_name: "dwd",
-        element_name: "Multi/single view difference",
-        unit: "K",
-        scale: 2,
-        reference_value: -512,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 205 },
-        class_name: "dwd",
-        element_name: "Spectral intensity",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 206 },
-        class_name: "dwd",
-        element_name: "Representation of intensity",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 207 },
-        class_name: "dwd",
-        element_name: "Altitude correction (ionosphere)",
-        unit: "m",
-        scale: 3,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 208 },
-        class_name: "dwd",
-        element_name: "Altitude correction (dry troposphere)",
-        unit: "m",
-        scale: 3,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 209 },
-        class_name: "dwd",
-        element_name: "Altitude correction (wet troposphere)",
-        unit: "m",
-        scale: 3,
-        reference_value: 2000,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 210 },
-        class_name: "dwd",
-        element_name: "Altitude correction (calibration constant)",
-        unit: "m",
-        scale: 3,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 211 },
-        class_name: "dwd",
-        element_name: "Open loop correction (htl)",
-        unit: "m",
-        scale: 3,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 212 },
-        class_name: "dwd",
-        element_name: "Open loop correction (agc)",
-        unit: "dB",
-        scale: 3,
-        reference_value: -3000,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 214 },
-        class_name: "dwd",
-        element_name: "Uat quality summary",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 215 },
-        class_name: "dwd",
-        element_name: "Uat across-track band number",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 216 },
-        class_name: "dwd",
-        element_name: "Reflectivite pour la valeur du pixel",
-        unit: "dB",
-        scale: 1,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 217 },
-        class_name: "dwd",
-        element_name: "Reflectivite theorique",
-        unit: "dB",
-        scale: 1,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 218 },
-        class_name: "dwd",
-        element_name: "Reflectivite mesuree",
-        unit: "dB",
-        scale: 1,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 219 },
-        class_name: "dwd",
-        element_name: "Reflectivite minimale pour la valeur du pixel",
-        unit: "dB",
-        scale: 0,
-        reference_value: -127,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 220 },
-        class_name: "dwd",
-        element_name: "Reflectivite horizontale",
-        unit: "dB",
-        scale: 1,
-        reference_value: -400,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 222 },
-        class_name: "dwd",
-        element_name: "DOPPLER SPECTRUM 0 TH MOMENT (AMPLITUDE)",
-        unit: "dB",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 225 },
-        class_name: "dwd",
-        element_name: "PRESCAT PRODUCT CONFIDENCE",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 226 },
-        class_name: "dwd",
-        element_name: "Pourcentage de masque",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 192 },
-        class_name: "dwd",
-        element_name: "LENGTH OF SWELL (METGM)",
-        unit: "m",
-        scale: -2,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 200 },
-        class_name: "dwd",
-        element_name: "SIGNIFICANT WAVE HEIGHT (NOTE 1)",
-        unit: "m",
-        scale: 2,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 201 },
-        class_name: "dwd",
-        element_name: "SPECTRAL PEAK PERIOD",
-        unit: "s",
-        scale: 1,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 202 },
-        class_name: "dwd",
-        element_name: "SPECTRAL PEAK WAVE LENGTH",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 203 },
-        class_name: "dwd",
-        element_name: "MAXIMUM WAVE HEIGHT",
-        unit: "m",
-        scale: 2,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 204 },
-        class_name: "dwd",
-        element_name: "AVERAGE WAVE PERIOD",
-        unit: "s",
-        scale: 1,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 205 },
-        class_name: "dwd",
-        element_name: "AVERAGE WAVE LENGTH",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 206 },
-        class_name: "dwd",
-        element_name: "DIRECTION OF DOMINANT WAVE (NOTE 2)",
-        unit: "degree true",
-        scale: 0,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 207 },
-        class_name: "dwd",
-        element_name: "DIRECTIONNAL SPREAD OF DOMINANT WAVE (NOTE 2)",
-        unit: "degree true",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 210 },
-        class_name: "dwd",
-        element_name: "DURATION OF WAVE RECORD",
-        unit: "s",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 211 },
-        class_name: "dwd",
-        element_name: "LENGTH OF WAVE RECORD",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 212 },
-        class_name: "dwd",
-        element_name: "WAVEBAND CENTRAL FREQUENCY",
-        unit: "Hz",
-        scale: 3,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 213 },
-        class_name: "dwd",
-        element_name: "WAVEBAND CENTRAL WAVE NUMBER",
-        unit: "m-1",
-        scale: 5,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 214 },
-        class_name: "dwd",
-        element_name: "MAXIMUM NON-DIRECTIONAL SPECTRAL DENSITY FREQUENCY",
-        unit: "m2/Hz",
-        scale: 2,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 215 },
-        class_name: "dwd",
-        element_name: "MAXIMUM NON-DIRECTIONAL SPECTRAL DENSITY WAVE NUMBER",
-        unit: "m3",
-        scale: 2,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 216 },
-        class_name: "dwd",
-        element_name: "NUMBER OF THE BAND IN WHICH THE MAXIMUM NON-DIRECTIONAL SPECTRAL DENSITY LIES",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 217 },
-        class_name: "dwd",
-        element_name: "SPECTRAL DENSITY RATIO",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 218 },
-        class_name: "dwd",
-        element_name: "MEAN DIRECTION FROM WHICH WAVES ARE COMING",
-        unit: "degree true",
-        scale: 0,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 219 },
-        class_name: "dwd",
-        element_name: "PRINCIPAL DIRECTION FROM WHICH WAVES ARE COMING (NOTE 3)",
-        unit: "degree true",
-        scale: 0,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 220 },
-        class_name: "dwd",
-        element_name: "FIRST NORMALISED POLAR CO-ORDINATE DERIVED FROM FOURIER COEFFICIENTS",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 221 },
-        class_name: "dwd",
-        element_name: "SECOND NORMALISED POLAR CO-ORDINATE DERIVED FROM FOURIER COEFFICIENTS",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 222 },
-        class_name: "dwd",
-        element_name: "NON-DIRECTIONAL SPECTRAL ESTIMATE OF FREQUENCY",
-        unit: "m2/Hz",
-        scale: 2,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 223 },
-        class_name: "dwd",
-        element_name: "NON-DIRECTIONAL SPECTRAL ESTIMATE OF WAVE NUMBER",
-        unit: "m3",
-        scale: 2,
-        reference_value: 2,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 224 },
-        class_name: "dwd",
-        element_name: "DIRECTIONAL SPECTRAL ESTIMATE OF FREQUENCY",
-        unit: "m2 rad-1 s",
-        scale: 2,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 225 },
-        class_name: "dwd",
-        element_name: "DIRECTIONAL SPECTRAL ESTIMATE OF WAVE NUMBER",
-        unit: "m4",
-        scale: 2,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 226 },
-        class_name: "dwd",
-        element_name: "TOTAL NUMBER OF BANDS",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 227 },
-        class_name: "dwd",
-        element_name: "DIRECTIONAL SPREAD OF INDIVIDUAL WAVES",
-        unit: "degree true",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 228 },
-        class_name: "dwd",
-        element_name: "INDICATEUR DE FOND",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 230 },
-        class_name: "dwd",
-        element_name: "Seuil de dangerosite par rapport au niveaux moyen",
-        unit: "m",
-        scale: 2,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 231 },
-        class_name: "dwd",
-        element_name: "Hauteur des marees par rapport au niveau moyen",
-        unit: "m",
-        scale: 2,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 232 },
-        class_name: "dwd",
-        element_name: "Somme de la maree astronomique et de la surcote",
-        unit: "m",
-        scale: 2,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 233 },
-        class_name: "dwd",
-        element_name: "Surcote-decote par rapport au niveau moyen",
-        unit: "m",
-        scale: 2,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 243 },
-        class_name: "dwd",
-        element_name: "Standard deviation significant wave height",
-        unit: "m",
-        scale: 2,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 192 },
-        class_name: "dwd",
-        element_name: "DEW POINT,AIRCRAFT ALTI+AIR TT ID(RECCO)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 193 },
-        class_name: "dwd",
-        element_name: "Numero de version logicielle du calculateur",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 194 },
-        class_name: "dwd",
-        element_name: "Numero de version de composite",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 195 },
-        class_name: "dwd",
-        element_name: "Numero de version de carte utilisee pour composition",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 196 },
-        class_name: "dwd",
-        element_name: "Methode de cumul",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 197 },
-        class_name: "dwd",
-        element_name: "Nombre d'images prises en compte",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 24,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 198 },
-        class_name: "dwd",
-        element_name: "Nombre de portes par tir",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 199 },
-        class_name: "dwd",
-        element_name: "Methode d'integration distance",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 200 },
-        class_name: "dwd",
-        element_name: "Methode d'integration azimut",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 207 },
-        class_name: "dwd",
-        element_name: "Correction d'intensite de precipitation",
-        unit: "kg m-2",
-        scale: 3,
-        reference_value: 0,
-        bits: 18,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 208 },
-        class_name: "dwd",
-        element_name: "SAMPLING INTERVAL (TIME)",
-        unit: "s",
-        scale: 4,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 209 },
-        class_name: "dwd",
-        element_name: "SAMPLING INTERVAL (SPACE)",
-        unit: "m",
-        scale: 2,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 210 },
-        class_name: "dwd",
-        element_name: "Facteur de correction global (pluie pluviometre)/(pluie radar)",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 211 },
-        class_name: "dwd",
-        element_name: "Seuil de reflectivite pour le calcul des deplacements d'echos radar",
-        unit: "dB",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 212 },
-        class_name: "dwd",
-        element_name: "Seuil d'occurrence pour la definition des echos fixes (%)",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 213 },
-        class_name: "dwd",
-        element_name: "Seuil de presence minimal des echos fixes pour valider l'image (%)",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 214 },
-        class_name: "dwd",
-        element_name: "Seuil de temperature des images satellitales IR pour le filtrage",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 215 },
-        class_name: "dwd",
-        element_name: "Marge de temperature pour le filtrage IR",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 216 },
-        class_name: "dwd",
-        element_name: "Seuil de radiance des images satellitales visibles pour le filtrage",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 217 },
-        class_name: "dwd",
-        element_name: "Mode de fonctionnement du filtrage des echos non meteorologiques",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 218 },
-        class_name: "dwd",
-        element_name: "Correction par un profil vertical de reflectivite",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 219 },
-        class_name: "dwd",
-        element_name: "Methode d'elaboration du masque d'echos fixes",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 228 },
-        class_name: "dwd",
-        element_name: "Data used for diagnostic",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 229 },
-        class_name: "dwd",
-        element_name: "Method used to diagnose the nature of a cloud system",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 24,
-    },
-    TableBEntry {
-        xy: XY { x: 26, y: 192 },
-        class_name: "dwd",
-        element_name: "Duree d'humectation",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 30, y: 192 },
-        class_name: "dwd",
-        element_name: "Mode de balayage",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 31, y: 192 },
-        class_name: "dwd",
-        element_name: "Facteur super elargi de repetition differe du descripteur",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 192 },
-        class_name: "dwd",
-        element_name: "QUALITY INFORMATION OF LIGHTNING NET",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 193 },
-        class_name: "dwd",
-        element_name: "Extended quality flags for ground-based GNSS data",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 212 },
-        class_name: "dwd",
-        element_name: "QUALITE DE LA MESURE DE PRESSION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 213 },
-        class_name: "dwd",
-        element_name: "QUALITE DU PARAMETRE TECHNOLOGI QUE(DEUXIEME VARIABLE DU PREMIERBLOC DE DONNEES CAPTEURS DES PLATES-FORMES ARGOS)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 214 },
-        class_name: "dwd",
-        element_name: "QUALITE DE LA MESURE DE LA TEMPERATURE DE L'EAU EN SURFACE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 215 },
-        class_name: "dwd",
-        element_name: "QUALITE DE LA MESURE DE LA TEMPERATURE DE L'AIR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 216 },
-        class_name: "dwd",
-        element_name: "QUALITE DES TRANSMISSIONS ENTRE LA BOUEE ET LE SATELLITE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 217 },
-        class_name: "dwd",
-        element_name: "QUALITE DE LA POSITION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 218 },
-        class_name: "dwd",
-        element_name: "INDICATEUR CONTROLE DE QUALITE DES DONNEES RELATIVES A LA POSI TION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 219 },
-        class_name: "dwd",
-        element_name: "INDICATEUR CONTROLE DE QUALITE DES DONNEES RELATIVES A L'HEURE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 220 },
-        class_name: "dwd",
-        element_name: "INDICATEUR CONTROLE DE QUALITE DES DONNEES METEOROLOGIQUES",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 221 },
-        class_name: "dwd",
-        element_name: "INDICATEUR DE POSITION DU GROUPE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 222 },
-        class_name: "dwd",
-        element_name: "INDICATEUR CONTROLE DE QUALITE DES DONNEES MARINES",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 223 },
-        class_name: "dwd",
-        element_name: "INDICATEUR DE CONTROLE DE QUALI TE DES DONNEES RELATIVES AUX PROFILS DE TEMPERATURE ET DE SALINITE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 224 },
-        class_name: "dwd",
-        element_name: "INDICATEUR CONTROLE DE QUALITE DES DONNEES RELATIVES AUX PROFILS DES COURANTS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 230 },
-        class_name: "dwd",
-        element_name: "Spatial quality indicator of the cloud system",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 231 },
-        class_name: "dwd",
-        element_name: "Temporal quality indicator of the tracking of the cloud system",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 232 },
-        class_name: "dwd",
-        element_name: "Quality indicator of the height of top of the cloud system",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 233 },
-        class_name: "dwd",
-        element_name: "Degre de confiance associe au parametre defini par l'intervalle qui suit",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 234 },
-        class_name: "dwd",
-        element_name: "Probabilite d'orage",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 235 },
-        class_name: "dwd",
-        element_name: "Probabilite de pluie faible",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 236 },
-        class_name: "dwd",
-        element_name: "Probabilite de pluie moderee",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 237 },
-        class_name: "dwd",
-        element_name: "Probabilite de pluie forte",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 35, y: 192 },
-        class_name: "dwd",
-        element_name: "Numero de l'amendement",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 48, y: 192 },
-        class_name: "dwd",
-        element_name: "Bit de calage",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 1,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 192 },
-        class_name: "dwd",
-        element_name: "Cq integrite du message",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 193 },
-        class_name: "dwd",
-        element_name: "Code de qualite arret-panne",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 194 },
-        class_name: "dwd",
-        element_name: "Code de qualite etalonnage",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 195 },
-        class_name: "dwd",
-        element_name: "Code de qualite eliminateur d'echos fixes",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 196 },
-        class_name: "dwd",
-        element_name: "Cq controle temporel",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 197 },
-        class_name: "dwd",
-        element_name: "Cq controle spatial",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 198 },
-        class_name: "dwd",
-        element_name: "Cq controle de coherence echos fixes",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 199 },
-        class_name: "dwd",
-        element_name: "Cq comparaison avec les donnees satellitaires",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 200 },
-        class_name: "dwd",
-        element_name: "Taux de pixels identifies propagation anormale",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: -10,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 201 },
-        class_name: "dwd",
-        element_name: "Code detection de propagation anormale",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 204 },
-        class_name: "dwd",
-        element_name: "Statut de la donnee",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 205 },
-        class_name: "dwd",
-        element_name: "CODE ATTENUATION PAR LA PLUIE(8 BITS)",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 206 },
-        class_name: "dwd",
-        element_name: "Valeur de la composante ouest/est du vecteur advection",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -600,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 207 },
-        class_name: "dwd",
-        element_name: "Valeur de la composante nord/sud du vecteur advection",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -600,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 208 },
-        class_name: "dwd",
-        element_name: "Donnees avec/sans correction d advection",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 209 },
-        class_name: "dwd",
-        element_name: "Code pour l'unite de mesure de la lame d'eau",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 210 },
-        class_name: "dwd",
-        element_name: "Numero de PVR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 211 },
-        class_name: "dwd",
-        element_name: "Cptq images emises par le calculateur radar",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 212 },
-        class_name: "dwd",
-        element_name: "Cptq images recues par le concentrateur",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 213 },
-        class_name: "dwd",
-        element_name: "Cptq images emises par le concentrateur radar",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 214 },
-        class_name: "dwd",
-        element_name: "Cptq images recues par le systeme de diffusion",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 215 },
-        class_name: "dwd",
-        element_name: "Cptq images emises par le systeme de diffusion",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 216 },
-        class_name: "dwd",
-        element_name: "Cptq images recues par la machine d'archivage",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 220 },
-        class_name: "dwd",
-        element_name: "Altitude de l'isotherme 0 degre du PVR",
-        unit: "m",
-        scale: -2,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 221 },
-        class_name: "dwd",
-        element_name: "Taux de decroissance du PVR au dessus de l'isotherme 0 degre",
-        unit: "dB/km",
-        scale: 1,
-        reference_value: -100,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 222 },
-        class_name: "dwd",
-        element_name: "Largeur de la bande brillante du PVR",
-        unit: "m",
-        scale: -2,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 223 },
-        class_name: "dwd",
-        element_name: "Facteur du renforcement du PVR",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 224 },
-        class_name: "dwd",
-        element_name: "Indicateur radar DOPPLER",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 225 },
-        class_name: "dwd",
-        element_name: "Indicateur radar double polarisation",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 227 },
-        class_name: "dwd",
-        element_name: "Statut de la donnee dans les fichiers pour la prevision numerique",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 228 },
-        class_name: "dwd",
-        element_name: "Code vitesse radiale",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 229 },
-        class_name: "dwd",
-        element_name: "Valeur minimale de la vitesse radiale en m/s",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -600,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 230 },
-        class_name: "dwd",
-        element_name: "Valeur maximale de la vitesse radiale en m/s",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -600,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 231 },
-        class_name: "dwd",
-        element_name: "Ecart entre deux valeurs consecutives des vitesses radiales",
-        unit: "m/s",
-        scale: 1,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 235 },
-        class_name: "dwd",
-        element_name: "Numero de colonne du pixel d'application du premier vecteur advection",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 236 },
-        class_name: "dwd",
-        element_name: "Numero de ligne du pixel d application du premier vecteur advection",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 237 },
-        class_name: "dwd",
-        element_name: "Resolution en X (nombre de colonnes)du champ d advection",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 238 },
-        class_name: "dwd",
-        element_name: "Resolution en Y (nombre de lignes) du champ d advection",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 239 },
-        class_name: "dwd",
-        element_name: "Correction globale mensuelle (pluviometre/radar) en dBZ",
-        unit: "dB",
-        scale: 1,
-        reference_value: -160,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 240 },
-        class_name: "dwd",
-        element_name: "Donnees avec/sans correction de l'attenuation par les precipitations",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 192 },
-        class_name: "dwd",
-        element_name: "KIND OF PLANTS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 193 },
-        class_name: "dwd",
-        element_name: "STEP OF GROWTH",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 194 },
-        class_name: "dwd",
-        element_name: "PROGRESS OF GROWTH",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 195 },
-        class_name: "dwd",
-        element_name: "PLANT DAMAGE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 196 },
-        class_name: "dwd",
-        element_name: "LAND USE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 197 },
-        class_name: "dwd",
-        element_name: "Nombre de pixels traites par le filtre (valeur > seuil de traitement)",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 198 },
-        class_name: "dwd",
-        element_name: "Seuil de traitement du filtre",
-        unit: "-",
-        scale: 1,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 199 },
-        class_name: "dwd",
-        element_name: "Pourcentage (en nombre) des valeurs supprimees par le filtre",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 200 },
-        class_name: "dwd",
-        element_name: "Signalisation des valeurs aberrantes detectees par le filtre",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 201 },
-        class_name: "dwd",
-        element_name: "TYPE OF LAND SURFACE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 202 },
-        class_name: "dwd",
-        element_name: "PLANT COVER",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 203 },
-        class_name: "dwd",
-        element_name: "LEAF AREA INDEX",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 204 },
-        class_name: "dwd",
-        element_name: "Site utilise lors de la constitution du masque d'echos fixes",
-        unit: "deg",
-        scale: 2,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 205 },
-        class_name: "dwd",
-        element_name: "Portee utilisee lors de la constitution du masque d'echos fixes",
-        unit: "m",
-        scale: -3,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 206 },
-        class_name: "dwd",
-        element_name: "Nombre de portes par tir",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 207 },
-        class_name: "dwd",
-        element_name: "Nombre de tirs integres",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 208 },
-        class_name: "dwd",
-        element_name: "Nombre de tirs dans l'image",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 209 },
-        class_name: "dwd",
-        element_name: "Angle de site reel moyen sur la periode de cumul",
-        unit: "deg",
-        scale: 2,
-        reference_value: -9000,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 210 },
-        class_name: "dwd",
-        element_name: "Annee de fin de constitution du masque d'echos fixes",
-        unit: "a",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 211 },
-        class_name: "dwd",
-        element_name: "Mois de fin de constitution du masque d'echos fixes",
-        unit: "mon",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 212 },
-        class_name: "dwd",
-        element_name: "Jour de fin de constitution du masque d'echos fixes",
-        unit: "d",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 213 },
-        class_name: "dwd",
-        element_name: "Heure de fin de constitution du masque d'echos fixes",
-        unit: "h",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 214 },
-        class_name: "dwd",
-        element_name: "Minute de fin de constitution du masque d'echos fixes",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 215 },
-        class_name: "dwd",
-        element_name: "Seconde de fin de constitution du masque d'echos fixes",
-        unit: "s",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 216 },
-        class_name: "dwd",
-        element_name: "Duree de constitution du masque d'echos fixes (Jours)",
-        unit: "d",
-        scale: 0,
-        reference_value: -1024,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 217 },
-        class_name: "dwd",
-        element_name: "Duree de constitution du masque d'echos fixes (Heures)",
-        unit: "h",
-        scale: 0,
-        reference_value: -1024,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 218 },
-        class_name: "dwd",
-        element_name: "Duree de constitution du masque d'echos fixes (Minutes)",
-        unit: "min",
-        scale: 0,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 219 },
-        class_name: "dwd",
-        element_name: "Nom du fichier contenant le masque d'echos fixes",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 80,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 220 },
-        class_name: "dwd",
-        element_name: "Duree utilisee pour le calcul duprofil vertical de reflectivite",
-        unit: "min",
-        scale: 0,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 221 },
-        class_name: "dwd",
-        element_name: "Pourcentage d'images valides necessaire au calcul du profil vertical de reflectivite",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 222 },
-        class_name: "dwd",
-        element_name: "Seuil de reflectivite utilise pour le calcul du champ de deplacement",
-        unit: "dB",
-        scale: 0,
-        reference_value: -64,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 223 },
-        class_name: "dwd",
-        element_name: "Pourcentage minimum (echos fixes presents/echos fixes attendus) pour valider l'image",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 224 },
-        class_name: "dwd",
-        element_name: "Facteur de correction (reflectivite sol/reflectivite a l'altitude de mesure)",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 225 },
-        class_name: "dwd",
-        element_name: "Correction de reflectivite",
-        unit: "dB",
-        scale: 1,
-        reference_value: -64,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 226 },
-        class_name: "dwd",
-        element_name: "Signalisation des valeurs modifiees",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 227 },
-        class_name: "dwd",
-        element_name: "Valeur de signalisation des pixels modifiees",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 228 },
-        class_name: "dwd",
-        element_name: "Bit de signalisation des pixels modifiees",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 229 },
-        class_name: "dwd",
-        element_name: "Facteur de la loi exprimant A (attenuation) en fonction de Z (reflectivite)",
-        unit: "Numeric",
-        scale: 7,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 230 },
-        class_name: "dwd",
-        element_name: "Exposant de la loi exprimant A (attenuation) en fonction de Z (reflectivite)",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 231 },
-        class_name: "dwd",
-        element_name: "Nombre d'images cumulees lors de la constitution du masque d'echos fixes",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 232 },
-        class_name: "dwd",
-        element_name: "Mode de constitution du masque d'echos fixes",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 233 },
-        class_name: "dwd",
-        element_name: "Longueur de la porte distance apres integration",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 241 },
-        class_name: "dwd",
-        element_name: "STATE/FEDERAL STATE IDENTIFIER",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 242 },
-        class_name: "dwd",
-        element_name: "HIGHWAY DESIGNATOR",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 40,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 243 },
-        class_name: "dwd",
-        element_name: "ROUTES KILOMETER OF ROAD",
-        unit: "m",
-        scale: -2,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 244 },
-        class_name: "dwd",
-        element_name: "GH_ID (CLIDATA NUMBER)",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 241 },
-        class_name: "dwd",
-        element_name: "NAME OF ROAD SENSOR MANUFACTURER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 242 },
-        class_name: "dwd",
-        element_name: "POSITION OF ROAD SENSORS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 243 },
-        class_name: "dwd",
-        element_name: "EXTENDED TYPE OF STATION",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 244 },
-        class_name: "dwd",
-        element_name: "TYPE OF ROAD",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 245 },
-        class_name: "dwd",
-        element_name: "TYPE OF CONSTRUCTION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 241 },
-        class_name: "dwd",
-        element_name: "ROAD SURFACE TEMPERATURE",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 242 },
-        class_name: "dwd",
-        element_name: "ROAD SUB-SURFACE TEMPERATURE",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 241 },
-        class_name: "dwd",
-        element_name: "WATER FILM THICKNESS",
-        unit: "m",
-        scale: 3,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 241 },
-        class_name: "dwd",
-        element_name: "ROAD SURFACE CONDITION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 205 },
-        class_name: "dwd",
-        element_name: "SATELLITE IDENTIFIER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 220 },
-        class_name: "dwd",
-        element_name: "DATA PRODUCER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 221 },
-        class_name: "dwd",
-        element_name: "UNITS OF ELEVATION/CONFIDENCE FACTOR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 222 },
-        class_name: "dwd",
-        element_name: "SATELL.DATA PROCESS.TECHN.USED F.TROPOP.",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 223 },
-        class_name: "dwd",
-        element_name: "TOVS/ATOVS/AVHRR CHANNEL NUMBER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 231 },
-        class_name: "dwd",
-        element_name: "VERIFICATION: OBSERVATION CODE TYPE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 232 },
-        class_name: "dwd",
-        element_name: "TRACER CORRELATION METHOD",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 240 },
-        class_name: "dwd",
-        element_name: "TYPE OF TERMODYNAMIC SENSING",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 251 },
-        class_name: "dwd",
-        element_name: "RADIANCE COMPUTATIONAL METHOD",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 252 },
-        class_name: "dwd",
-        element_name: "SATELLITE INSTRUMENT DATA USED IN PROCESSING",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 253 },
-        class_name: "dwd",
-        element_name: "HUMIDITY COMPUTATIONAL METHOD",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 254 },
-        class_name: "dwd",
-        element_name: "RADIANCE TYPE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 222 },
-        class_name: "dwd",
-        element_name: "GENERATOR SIGNIFICANCE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 223 },
-        class_name: "dwd",
-        element_name: "LAND/ICE SURFACE FLAG",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 224 },
-        class_name: "dwd",
-        element_name: "LAND/SEA FRACTION",
-        unit: "Numeric",
-        scale: 3,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 10, y: 195 },
-        class_name: "dwd",
-        element_name: "HEIGHT(HIGH ACCURACY)",
-        unit: "M",
-        scale: 1,
-        reference_value: -4096,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 10, y: 196 },
-        class_name: "dwd",
-        element_name: "SOLAR ZENITH ANGLE",
-        unit: "deg",
-        scale: 2,
-        reference_value: -9000,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 10, y: 197 },
-        class_name: "dwd",
-        element_name: "ANEMOMETER HEIGHT",
-        unit: "M",
-        scale: 0,
-        reference_value: 0,
-        bits: 9,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 193 },
-        class_name: "dwd",
-        element_name: "V-COMPONENT AT 10 M",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -4096,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 194 },
-        class_name: "dwd",
-        element_name: "BOUNDARY LAYER DEPTH",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 210 },
-        class_name: "dwd",
-        element_name: "1D VAR WIND SPEED RETREIVED ERRO(S)",
-        unit: "m/s",
-        scale: 2,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 211 },
-        class_name: "dwd",
-        element_name: "WIND SPEED INDEPENDENT ESTIMATE OF ERROR(S)",
-        unit: "m/s",
-        scale: 2,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 220 },
-        class_name: "dwd",
-        element_name: "FORMAL UNCERTAINTY IN WIND SPEED",
-        unit: "m/s",
-        scale: 2,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 221 },
-        class_name: "dwd",
-        element_name: "FORMAL UNCERTAINTY IN WIND DIRECTION",
-        unit: "degree true",
-        scale: 2,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 230 },
-        class_name: "dwd",
-        element_name: "MAXIMUM MEAN WIND SPEED",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -4096,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 231 },
-        class_name: "dwd",
-        element_name: "MEAN WIND SPEED",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -4096,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 232 },
-        class_name: "dwd",
-        element_name: "MINIMUM MEAN WIND SPEED",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -4096,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 233 },
-        class_name: "dwd",
-        element_name: "HIGHEST GUST DURING THE 10 MINUTE PERIOD PRECEDING OBSERVATION",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -4096,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 199 },
-        class_name: "dwd",
-        element_name: "MAX. TEMPER. AT 2 M, PAST 6 HOURS, ECMF",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 200 },
-        class_name: "dwd",
-        element_name: "MIN. TEMPER. AT 2 M, PAST 6 HOURS, ECMF",
-        unit: "K",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 203 },
-        class_name: "dwd",
-        element_name: "TOTAL WATER EQUIVALENT AT SNOW SAMPLER",
-        unit: "M/M",
-        scale: 2,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 205 },
-        class_name: "dwd",
-        element_name: "LARGE SCALE PRECIPITION (RAIN AND SNOW)",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 206 },
-        class_name: "dwd",
-        element_name: "CONVECTIVE PRECIPITION (RAIN AND SNOW)",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 207 },
-        class_name: "dwd",
-        element_name: "SKIN RESERVOIR CONTENT",
-        unit: "m",
-        scale: 5,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 208 },
-        class_name: "dwd",
-        element_name: "SURFACE WATER RUNOFF",
-        unit: "kg m-2",
-        scale: 2,
-        reference_value: -1,
-        bits: 19,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 210 },
-        class_name: "dwd",
-        element_name: "Indice de classe de risque pluviometrique",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 211 },
-        class_name: "dwd",
-        element_name: "SOIL HUMIDITY",
-        unit: "m",
-        scale: -3,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 212 },
-        class_name: "dwd",
-        element_name: "CLOUD LIQUID WATER PATH INDIPENDENT ESTIMATE OF ERROR(S)",
-        unit: "kg m-2",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 230 },
-        class_name: "dwd",
-        element_name: "CLOUD WATER CONTENT",
-        unit: "kg/kg",
-        scale: 7,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 231 },
-        class_name: "dwd",
-        element_name: "TOTAL PRECIPITATION PAST 10 MINUTES",
-        unit: "kg m-2",
-        scale: 2,
-        reference_value: -1,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 240 },
-        class_name: "dwd",
-        element_name: "AIR DENSITY",
-        unit: "kg m-3",
-        scale: 3,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 250 },
-        class_name: "dwd",
-        element_name: "CALCULATED SURFACE TYPE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 251 },
-        class_name: "dwd",
-        element_name: "ICE AGE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 252 },
-        class_name: "dwd",
-        element_name: "ICE EDGE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 253 },
-        class_name: "dwd",
-        element_name: "SURFACE TYPE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 254 },
-        class_name: "dwd",
-        element_name: "ICE CONCENTRATION",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 200 },
-        class_name: "dwd",
-        element_name: "MWI PRODUCT CONFIDENCE",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 213 },
-        class_name: "dwd",
-        element_name: "AMBIGUITY REMOVAL CONFIDENCE",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 230 },
-        class_name: "dwd",
-        element_name: "NUMBER OF VECTOR AMBIGUITIES",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 231 },
-        class_name: "dwd",
-        element_name: "INDEX OF SELECTED WIND VECTOR",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 232 },
-        class_name: "dwd",
-        element_name: "TOTAL NMBER OF SIGMA-0 MEASUREMENTS",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 233 },
-        class_name: "dwd",
-        element_name: "NUMBER OF SIGMA-0 USABLE FOR WIND",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 234 },
-        class_name: "dwd",
-        element_name: "LIKELIHOOD COMPUTED FOR SOLUTION",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: -10000,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 235 },
-        class_name: "dwd",
-        element_name: "WIND VECTOR CELL QUALITY FLAG",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 236 },
-        class_name: "dwd",
-        element_name: "NUMBER OF FORE-BEAM SIGMA-0",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 237 },
-        class_name: "dwd",
-        element_name: "NUMBER OF MID-BEAM V-POL SIGMA-0",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 238 },
-        class_name: "dwd",
-        element_name: "NUMBER OF MID-BEAM H-POL SIGMA-0",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 239 },
-        class_name: "dwd",
-        element_name: "NUMBER OF AFT-BEAM SIGMA-0",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 240 },
-        class_name: "dwd",
-        element_name: "NORMALIZED RADAR CROSS SECTION",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: -10000,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 241 },
-        class_name: "dwd",
-        element_name: "Kp VARIANCE COEFFICIENT (ALPHA)",
-        unit: "Numeric",
-        scale: 5,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 242 },
-        class_name: "dwd",
-        element_name: "Kp VARIANCE COEFFICIENT (BETHA)",
-        unit: "Numeric",
-        scale: 6,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 243 },
-        class_name: "dwd",
-        element_name: "Kp VARIANCE COEFFICIENT (GAMMA)",
-        unit: "Numeric",
-        scale: 6,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 21, y: 244 },
-        class_name: "dwd",
-        element_name: "SIGMA-0 QUALITY FLAG",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 193 },
-        class_name: "dwd",
-        element_name: "STATE OF WATER SURFACE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 194 },
-        class_name: "dwd",
-        element_name: "DIRECTION OF APPROACH OF WAVES TO BEACH",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 195 },
-        class_name: "dwd",
-        element_name: "WIDTH OF SURF ZONE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 196 },
-        class_name: "dwd",
-        element_name: "PEAK WAVE PROPAGATION DIRECTION",
-        unit: "deg",
-        scale: 1,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 197 },
-        class_name: "dwd",
-        element_name: "DIRECTION SPREAD",
-        unit: "deg",
-        scale: 1,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 198 },
-        class_name: "dwd",
-        element_name: "VARIANCE SPECTRAL DENSITY",
-        unit: "m2 rad-1 s",
-        scale: 4,
-        reference_value: 0,
-        bits: 18,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 199 },
-        class_name: "dwd",
-        element_name: "CORRELATION COEFF OF SIMULATED FG AND OBSERVED SAR SPECTRUM",
-        unit: "Numeric",
-        scale: 4,
-        reference_value: -10000,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 208 },
-        class_name: "dwd",
-        element_name: "PEAK PERIOD OF 1D SPECTRA",
-        unit: "S",
-        scale: 1,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 22, y: 209 },
-        class_name: "dwd",
-        element_name: "COEFICIENT OF DRAG WITH WAVES",
-        unit: "Numeric",
-        scale: 6,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 201 },
-        class_name: "dwd",
-        element_name: "Niveau de bruit par radiale",
-        unit: "dB",
-        scale: 2,
-        reference_value: -2000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 202 },
-        class_name: "dwd",
-        element_name: "ALBEDO-RAD EQUIVALENT FILTER WIDTH",
-        unit: "Numeric",
-        scale: 10,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 203 },
-        class_name: "dwd",
-        element_name: "SCAN LINE STATUS FLAGS FOR ATOVS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 204 },
-        class_name: "dwd",
-        element_name: "SCAN LINE QUALITY FLAGS FOR ATOVS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 205 },
-        class_name: "dwd",
-        element_name: "CHANNEL QUALITY FLAGS FOR ATOVS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 206 },
-        class_name: "dwd",
-        element_name: "FOV QUALITY FLAGS FOR ATOVS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 26, y: 193 },
-        class_name: "dwd",
-        element_name: "YEAR",
-        unit: "YEAR",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 26, y: 194 },
-        class_name: "dwd",
-        element_name: "MONTH",
-        unit: "mon",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 26, y: 195 },
-        class_name: "dwd",
-        element_name: "DAY",
-        unit: "d",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 26, y: 196 },
-        class_name: "dwd",
-        element_name: "HOUR",
-        unit: "h",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 26, y: 197 },
-        class_name: "dwd",
-        element_name: "MINUTE",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 26, y: 198 },
-        class_name: "dwd",
-        element_name: "SECOND",
-        unit: "s",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 26, y: 200 },
-        class_name: "dwd",
-        element_name: "LOCATION WITHIN ASSIMILATION WINDOW IN TIME",
-        unit: "Numeric",
-        scale: 6,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 26, y: 201 },
-        class_name: "dwd",
-        element_name: "HOURS INCLUDED",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 26,
-    },
-    TableBEntry {
-        xy: XY { x: 27, y: 193 },
-        class_name: "dwd",
-        element_name: "SOLAR AZIMUTH",
-        unit: "degree true",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 30, y: 193 },
-        class_name: "dwd",
-        element_name: "POSITION NUMBER ALONG SCAN",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 194 },
-        class_name: "dwd",
-        element_name: "DATA QUALITY 2 BIT CODE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 195 },
-        class_name: "dwd",
-        element_name: "ECMWF SAR QC INDICATOR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 196 },
-        class_name: "dwd",
-        element_name: "ECMWF WAM QC INDICATOR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 197 },
-        class_name: "dwd",
-        element_name: "ECMWF SAR INVERSION QC INDICATOR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 198 },
-        class_name: "dwd",
-        element_name: "MINIMUM COST",
-        unit: "Numeric",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 199 },
-        class_name: "dwd",
-        element_name: "1D VAR TOTAL PRECIPITABLE WATER RETRIEVED ERROR(S)",
-        unit: "kg m-2",
-        scale: 2,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 200 },
-        class_name: "dwd",
-        element_name: "ANALYSIS REPORT EVENTS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 201 },
-        class_name: "dwd",
-        element_name: "ANALYSIS REPORT STATUS EVENT",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 202 },
-        class_name: "dwd",
-        element_name: "ANALYSIS DATUM EVENT FLAGS (1)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 203 },
-        class_name: "dwd",
-        element_name: "ANALYSIS DATUM EVENT FLAGS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 204 },
-        class_name: "dwd",
-        element_name: "ANALYSIS DATUM STATUS FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 21,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 205 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS BLACK LIST FLAG",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 206 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS QUALITY CONTROL FLAG",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 207 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS DEPARTURE FLAG",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 208 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS FIRST QUESS CHECK FLAG",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 209 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS FINAL FLAG",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 210 },
-        class_name: "dwd",
-        element_name: "SCAN LINE QUALITY FLAG FOR SAPHIR/MADRAS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 211 },
-        class_name: "dwd",
-        element_name: "PAYLOAD MODE FOR SAPHIR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 225 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS TEMP EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 226 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS PILOT EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 227 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS SATEM/TOVS EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 228 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS PAOB EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 229 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS SCATTEROMETER EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 238 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS AIREP DATUM EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 239 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS SATOB DATUM EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 240 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS DRIBU DATUM EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 241 },
-        class_name: "dwd",
-        element_name: "GROSS ERROR PROBABILITY",
-        unit: "Numeric",
-        scale: 3,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 242 },
-        class_name: "dwd",
-        element_name: "GROSS ERROR INDICATOR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 243 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS TEMP DATUM EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 244 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS PILOT DATUM EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 245 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS SATEM/TOVS DATUM EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 246 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS PAOB DATUM EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 247 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS SCATTEROMETER DATUM EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 248 },
-        class_name: "dwd",
-        element_name: "VARIATIONAL ANALYSIS RAW RADIANCES DATUM EVENTS (2)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 249 },
-        class_name: "dwd",
-        element_name: "DATUM BLACK LIST EVENTS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 250 },
-        class_name: "dwd",
-        element_name: "PROBABILITY OF GROSS ERROR",
-        unit: "Numeric",
-        scale: 6,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 251 },
-        class_name: "dwd",
-        element_name: "RANGE OF POSSIBLE VALUES",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 252 },
-        class_name: "dwd",
-        element_name: "MANUAL-AUTOMATIC QUALITY CONTROL",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 253 },
-        class_name: "dwd",
-        element_name: "NOMINAL CONFIDENCE THRESHOLD",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 33, y: 254 },
-        class_name: "dwd",
-        element_name: "WIND CORRELATION METHOD",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 192 },
-        class_name: "dwd",
-        element_name: "Systeme geodesique",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 193 },
-        class_name: "dwd",
-        element_name: "LONG ORIGIN",
-        unit: "deg",
-        scale: 2,
-        reference_value: -18000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 194 },
-        class_name: "dwd",
-        element_name: "LAT ORIGIN",
-        unit: "deg",
-        scale: 2,
-        reference_value: -9000,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 195 },
-        class_name: "dwd",
-        element_name: "X-OFFSET",
-        unit: "M",
-        scale: 0,
-        reference_value: -33554432,
-        bits: 26,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 196 },
-        class_name: "dwd",
-        element_name: "Y-OFFSET",
-        unit: "M",
-        scale: 0,
-        reference_value: -33554432,
-        bits: 26,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 197 },
-        class_name: "dwd",
-        element_name: "STANDARD PARALLEL 1",
-        unit: "deg",
-        scale: 2,
-        reference_value: -9000,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 198 },
-        class_name: "dwd",
-        element_name: "STANDARD PARALLEL 2",
-        unit: "deg",
-        scale: 2,
-        reference_value: -9000,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 199 },
-        class_name: "dwd",
-        element_name: "SEMI-MAJOR AXIS OF ROTATION ELLIPSOID",
-        unit: "M",
-        scale: 0,
-        reference_value: 0,
-        bits: 26,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 200 },
-        class_name: "dwd",
-        element_name: "SEMI-MINOR AXIS OF ROTATION ELLIPSOID",
-        unit: "M",
-        scale: 0,
-        reference_value: 0,
-        bits: 26,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 201 },
-        class_name: "dwd",
-        element_name: "PROJECTION TYPE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 202 },
-        class_name: "dwd",
-        element_name: "AZIMUTH OF INITIAL LINE",
-        unit: "deg",
-        scale: 2,
-        reference_value: -9000,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 203 },
-        class_name: "dwd",
-        element_name: "LONG ORIGIN",
-        unit: "deg",
-        scale: 5,
-        reference_value: -18000000,
-        bits: 26,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 204 },
-        class_name: "dwd",
-        element_name: "LAT ORIGIN",
-        unit: "deg",
-        scale: 5,
-        reference_value: -9000000,
-        bits: 25,
-    },
-    TableBEntry {
-        xy: XY { x: 30, y: 194 },
-        class_name: "dwd",
-        element_name: "NUMBER OF BINS ALONG THE RADIAL",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 30, y: 195 },
-        class_name: "dwd",
-        element_name: "NUMBER OF AZIMUTHS",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 30, y: 196 },
-        class_name: "dwd",
-        element_name: "TYPE OF PRODUCT",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 30, y: 197 },
-        class_name: "dwd",
-        element_name: "COMPRESSION METHOD",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 30, y: 198 },
-        class_name: "dwd",
-        element_name: "BYTE ELEMENT OF A COMPRESSED ARRAY",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 29, y: 205 },
-        class_name: "dwd",
-        element_name: "Geographic projection as PROJ initialisation string",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 800,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 18 },
-        class_name: "dwd",
-        element_name: "LONG TIME INCREMENT",
-        unit: "s",
-        scale: 0,
-        reference_value: -8192,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 7, y: 66 },
-        class_name: "dwd",
-        element_name: "REPRE.H. OF SENSOR ABOVE WATER SURFACE",
-        unit: "m",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 61 },
-        class_name: "dwd",
-        element_name: "INSTANTANEOUS LONG-WAVE RADIATION",
-        unit: "W m-2",
-        scale: 0,
-        reference_value: -512,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 62 },
-        class_name: "dwd",
-        element_name: "INSTANTANEOUS SHORT-WAVE RADIATION",
-        unit: "W m-2",
-        scale: 0,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 202 },
-        class_name: "dwd",
-        element_name: "NUMBER OF DAYS WITH SPEC.WEA.PHENOMENA",
-        unit: "d",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 192 },
-        class_name: "dwd",
-        element_name: "U-COMPONENT AT 10 M",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -4096,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 201 },
-        class_name: "dwd",
-        element_name: "WATER/ICE/SIGN(WET BULB TEMPERATURE)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 211 },
-        class_name: "dwd",
-        element_name: "DRY BULB TEMP.AT 2 M, DEVIAT.FROM NORMAL",
-        unit: "K",
-        scale: 1,
-        reference_value: -128,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 221 },
-        class_name: "dwd",
-        element_name: "TOTAL WATER CONTENTS IN LOWER SOIL LAYER",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 222 },
-        class_name: "dwd",
-        element_name: "TOTAL WATER CONTENTS I.MIDDLE SOIL LAYER",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 223 },
-        class_name: "dwd",
-        element_name: "TOTAL WATER CONTENTS I.UPPER SOIL LAYER",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 224 },
-        class_name: "dwd",
-        element_name: "SPECIFIC HUMIDITY AT SURF./EARTH OR SNOW",
-        unit: "kg/kg",
-        scale: 5,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 225 },
-        class_name: "dwd",
-        element_name: "LARGE SCALE RAIN DURING FORECAST TIME",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 226 },
-        class_name: "dwd",
-        element_name: "LARGE SCALE SNOW DURING FORECAST TIME",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 227 },
-        class_name: "dwd",
-        element_name: "CONVECTIVE RAIN DURING FORECAST TIME",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 228 },
-        class_name: "dwd",
-        element_name: "CONVECTIVE SNOW DURING FORECAST TIME",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 201 },
-        class_name: "dwd",
-        element_name: "PHOTOSYTHETIC ACTIVE RADIATION AT SURF.",
-        unit: "J m-2",
-        scale: -3,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 202 },
-        class_name: "dwd",
-        element_name: "ALBEDO OF SURFACE (SHORT WAVE)",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 192 },
-        class_name: "dwd",
-        element_name: "OZONE, VERTICALLY INTEGRATED",
-        unit: "Pa",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 193 },
-        class_name: "dwd",
-        element_name: "PRESSURE OF OZONE MAXIMUM",
-        unit: "Pa",
-        scale: -1,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 192 },
-        class_name: "dwd",
-        element_name: "TYPE OF OBSERVATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 193 },
-        class_name: "dwd",
-        element_name: "METHOD OF OBSERVATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 194 },
-        class_name: "dwd",
-        element_name: "STATUS OF OBSERVATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 195 },
-        class_name: "dwd",
-        element_name: "NUCLID",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 196 },
-        class_name: "dwd",
-        element_name: "BASE OF OBSERVED VALUE",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 197 },
-        class_name: "dwd",
-        element_name: "EXPONENT OF OBSERVED VALUE",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: -15,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 198 },
-        class_name: "dwd",
-        element_name: "INDICATOR ON OBSERVED VALUE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 199 },
-        class_name: "dwd",
-        element_name: "UNIT OF OBSERVED VALUE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 200 },
-        class_name: "dwd",
-        element_name: "ERROR OF OBSERVED DATA",
-        unit: "%",
-        scale: 1,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 192 },
-        class_name: "dwd",
-        element_name: "LAND SEA RATIO",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 193 },
-        class_name: "dwd",
-        element_name: "COEFF.OF TURB.TRANF.OF IMPULSE,SURFACE",
-        unit: "Code table",
-        scale: 5,
-        reference_value: 0,
-        bits: 17,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 194 },
-        class_name: "dwd",
-        element_name: "COEFF.OF TURB.TRANF.OF HEAT/HUM.,SURFACE",
-        unit: "Code table",
-        scale: 5,
-        reference_value: 0,
-        bits: 17,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 195 },
-        class_name: "dwd",
-        element_name: "IMPULSE FLOW(U) AT SURF.,PERIOD SPECIF.",
-        unit: "G/(M*S**2)",
-        scale: 0,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 196 },
-        class_name: "dwd",
-        element_name: "IMPULSE FLOW(V) AT SURF.,PERIOD SPECIF.",
-        unit: "G/(M*S**2)",
-        scale: 0,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 197 },
-        class_name: "dwd",
-        element_name: "FLOW OF SENS.HEAT AT SURF.,PERIOD SPEC.",
-        unit: "J m-2",
-        scale: -3,
-        reference_value: -16384,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 198 },
-        class_name: "dwd",
-        element_name: "FLOW OF LAT.HEAT AT SURF.,PERIOD SPECIF.",
-        unit: "J m-2",
-        scale: -3,
-        reference_value: -16384,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 199 },
-        class_name: "dwd",
-        element_name: "ROUGHNESS LENGTH (MULTIPLIED BY G)",
-        unit: "m2 s-2",
-        scale: 2,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 1 },
-        class_name: "dwd",
-        element_name: "GEOPOTENTIAL,DEV.FROM FIRST GUESS",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 2 },
-        class_name: "dwd",
-        element_name: "U-COMPONENT,DEV.FROM FIRST GUESS",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 3 },
-        class_name: "dwd",
-        element_name: "V-COMPONENT,DEV.FROM FIRST GUESS",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 4 },
-        class_name: "dwd",
-        element_name: "REL.HUMIDITY,DEV.FROM FIRST GUESS",
-        unit: "%",
-        scale: 1,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 5 },
-        class_name: "dwd",
-        element_name: "THICKNESS,DEV.FROM FIRST GUESS",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 11 },
-        class_name: "dwd",
-        element_name: "GEOPOTENTIAL,DEV.FROM ANALYSIS",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 12 },
-        class_name: "dwd",
-        element_name: "U-COMPONENT,DEV.FROM ANALYSIS",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 13 },
-        class_name: "dwd",
-        element_name: "V-COMPONENT,DEV.FROM ANALYSIS",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 14 },
-        class_name: "dwd",
-        element_name: "REL.HUMIDITY,DEV.FROM ANALYSIS",
-        unit: "%",
-        scale: 1,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 15 },
-        class_name: "dwd",
-        element_name: "THICKNESS,DEV.FROM ANALYSIS",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 21 },
-        class_name: "dwd",
-        element_name: "GEOPOTENTIAL,DEV.FR.INITIALIZED ANALYSIS",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 22 },
-        class_name: "dwd",
-        element_name: "U-COMPONENT,DEV.FR.INITIALIZED ANALYSIS",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 23 },
-        class_name: "dwd",
-        element_name: "V-COMPONENT,DEV.FR.INITIALIZED ANALYSIS",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 24 },
-        class_name: "dwd",
-        element_name: "REL.HUMIDITY,DEV.FR.INITIALIZED ANALYSIS",
-        unit: "%",
-        scale: 1,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 25 },
-        class_name: "dwd",
-        element_name: "THICKNESS,DEV.FR.INITIALIZED ANALYSIS",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 31 },
-        class_name: "dwd",
-        element_name: "GEOPOTENTIAL,DEV.FROM FORECAST A",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 32 },
-        class_name: "dwd",
-        element_name: "U-COMPONENT,DEV.FROM FORECAST A",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 33 },
-        class_name: "dwd",
-        element_name: "V-COMPONENT,DEV.FROM FORECAST A",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 34 },
-        class_name: "dwd",
-        element_name: "REL.HUMIDITY,DEV.FROM FORECAST A",
-        unit: "%",
-        scale: 1,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 35 },
-        class_name: "dwd",
-        element_name: "THICKNESS,DEV.FROM FORECAST A",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 41 },
-        class_name: "dwd",
-        element_name: "GEOPOTENTIAL,DEV.FROM FORECAST B",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 42 },
-        class_name: "dwd",
-        element_name: "U-COMPONENT,DEV.FROM FORECAST B",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 43 },
-        class_name: "dwd",
-        element_name: "V-COMPONENT,DEV.FROM FORECAST B",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 44 },
-        class_name: "dwd",
-        element_name: "REL.HUMIDITY,DEV.FROM FORECAST B",
-        unit: "%",
-        scale: 1,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 45 },
-        class_name: "dwd",
-        element_name: "THICKNESS,DEV.FROM FORECAST B",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 51 },
-        class_name: "dwd",
-        element_name: "GEOPOTENTIAL,DEV.FROM FORECAST C",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 52 },
-        class_name: "dwd",
-        element_name: "U-COMPONENT,DEV.FROM FORECAST C",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 53 },
-        class_name: "dwd",
-        element_name: "V-COMPONENT,DEV.FROM FORECAST C",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2000,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 54 },
-        class_name: "dwd",
-        element_name: "REL.HUMIDITY,DEV.FROM FORECAST C",
-        unit: "%",
-        scale: 1,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 55 },
-        class_name: "dwd",
-        element_name: "THICKNESS,DEV.FROM FORECAST C",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -10000,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 61 },
-        class_name: "dwd",
-        element_name: "GEOPOTENTIAL, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 62 },
-        class_name: "dwd",
-        element_name: "U-COMPONENT, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 63 },
-        class_name: "dwd",
-        element_name: "V-COMPONENT, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 64 },
-        class_name: "dwd",
-        element_name: "REL.HUMIDITY, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 65 },
-        class_name: "dwd",
-        element_name: "THICKNESS, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 54, y: 201 },
-        class_name: "dwd",
-        element_name: "YEAR OF DECODING",
-        unit: "a",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 54, y: 202 },
-        class_name: "dwd",
-        element_name: "MONTH OF DECODING",
-        unit: "mon",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 54, y: 203 },
-        class_name: "dwd",
-        element_name: "DAY OF DECODING",
-        unit: "d",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 54, y: 204 },
-        class_name: "dwd",
-        element_name: "HOUR OF DECODING",
-        unit: "h",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 54, y: 205 },
-        class_name: "dwd",
-        element_name: "MINUTE OF DECODING",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 54, y: 211 },
-        class_name: "dwd",
-        element_name: "NUMBER OF DAY OF MRZ BACK-UP FILE",
-        unit: "d",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 54, y: 212 },
-        class_name: "dwd",
-        element_name: "NUMBER OF RECORD ON MRZ BACK-UP FILE",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 18,
-    },
-    TableBEntry {
-        xy: XY { x: 54, y: 213 },
-        class_name: "dwd",
-        element_name: "DECODING RUNNING ON MFA/MFB (VALUES 1/0)",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 214 },
-        class_name: "dwd",
-        element_name: "ACTUAL HOUR OF OBSERVATION",
-        unit: "h",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 215 },
-        class_name: "dwd",
-        element_name: "ACTUAL MINUTE OF OBSERVATION",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 229 },
-        class_name: "dwd",
-        element_name: "MIXING RATIO IN FOG",
-        unit: "kg/kg",
-        scale: 5,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 198 },
-        class_name: "dwd",
-        element_name: "SUNSHINE",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 201 },
-        class_name: "dwd",
-        element_name: "ROUTINE/SPECIAL/TEST OBSERVATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 51, y: 192 },
-        class_name: "dwd",
-        element_name: "SHORT DISTANCE LIGHTNING",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 51, y: 193 },
-        class_name: "dwd",
-        element_name: "DISTANT LIGHTNING",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 200 },
-        class_name: "dwd",
-        element_name: "BOUNDARY LAYER DISSIPATION",
-        unit: "J m-2",
-        scale: -3,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 201 },
-        class_name: "dwd",
-        element_name: "SOIL HEAT FLUX, SURFACE",
-        unit: "J m-2",
-        scale: -2,
-        reference_value: -16384,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 202 },
-        class_name: "dwd",
-        element_name: "SOIL HEAT FLUX, BOTTOM OF LAYER 1",
-        unit: "J m-2",
-        scale: -2,
-        reference_value: -16384,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 231 },
-        class_name: "dwd",
-        element_name: "VERIF.:X-COORD.OF REF.MODEL GRID POINT",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 231 },
-        class_name: "dwd",
-        element_name: "VERIF.:Y-COORD.OF REF.MODEL GRID POINT",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 7, y: 231 },
-        class_name: "dwd",
-        element_name: "HEIGHT OF MODEL OROGRAPHY",
-        unit: "m",
-        scale: 0,
-        reference_value: -400,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 231 },
-        class_name: "dwd",
-        element_name: "VERIFICATION: REPORT CHARACTERISTICS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 231 },
-        class_name: "dwd",
-        element_name: "VERTICAL CLOUD AMOUNT",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 232 },
-        class_name: "dwd",
-        element_name: "MODIFIED CLOUD AMOUNT",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 233 },
-        class_name: "dwd",
-        element_name: "VERIFICATION: DERIVED LOW CLOUD COVER",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 234 },
-        class_name: "dwd",
-        element_name: "MAX. METEOROLOG. OPTICAL RANGE",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 235 },
-        class_name: "dwd",
-        element_name: "MIN. METEOROLOG. OPTICAL RANGE",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 236 },
-        class_name: "dwd",
-        element_name: "METEOROLOG. OPTICAL RANGE",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 66 },
-        class_name: "dwd",
-        element_name: "TEMPERATURE,QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 67 },
-        class_name: "dwd",
-        element_name: "PRESSURE AT OBS.LEVEL,QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 68 },
-        class_name: "dwd",
-        element_name: "DEW POINT TEMPERATURE,QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 69 },
-        class_name: "dwd",
-        element_name: "MAX WIND (GUSTS), QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 70 },
-        class_name: "dwd",
-        element_name: "MAX TEMPERATURE(2M), QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 71 },
-        class_name: "dwd",
-        element_name: "MIN TEMPERATURE(2M), QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 72 },
-        class_name: "dwd",
-        element_name: "GROUND MIN.TEMPERATURE, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 73 },
-        class_name: "dwd",
-        element_name: "CLOUD COVER, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 74 },
-        class_name: "dwd",
-        element_name: "LOW CLOUD COVER, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 75 },
-        class_name: "dwd",
-        element_name: "HORIZONTAL VISIBILITY, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 76 },
-        class_name: "dwd",
-        element_name: "TOTAL PRECIPITATION, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 77 },
-        class_name: "dwd",
-        element_name: "GLOBAL RADIATION, QUALITY FLAGS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 32,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 101 },
-        class_name: "dwd",
-        element_name: "GEOPOTENTIAL,DEV.FR.DEF.FIELD",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -16384,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 102 },
-        class_name: "dwd",
-        element_name: "U-COMPONENT,DEV.FR.DEF.FIELD",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 103 },
-        class_name: "dwd",
-        element_name: "V-COMPONENT,DEV.FR.DEF.FIELD",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 104 },
-        class_name: "dwd",
-        element_name: "RELATIVE HUMIDITY,DEV.FR.DEF.FIELD",
-        unit: "%",
-        scale: 1,
-        reference_value: -1024,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 105 },
-        class_name: "dwd",
-        element_name: "THICKNESS,DEV.FR.DEF.FIELD",
-        unit: "m2 s-2",
-        scale: 0,
-        reference_value: -16384,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 106 },
-        class_name: "dwd",
-        element_name: "TEMPERATURE,DEV.FR.DEF.FIELD",
-        unit: "K",
-        scale: 2,
-        reference_value: -8096,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 107 },
-        class_name: "dwd",
-        element_name: "PRESSURE AT OBS.LEVEL,DEV.FR.DEF.FIELD",
-        unit: "Pa",
-        scale: 0,
-        reference_value: -16384,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 108 },
-        class_name: "dwd",
-        element_name: "DEW POINT TEMPERATURE,DEV.FR.DEF.FIELD",
-        unit: "K",
-        scale: 1,
-        reference_value: -1024,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 109 },
-        class_name: "dwd",
-        element_name: "MAX WIND (GUSTS),DEV.FR.DEF.FIELD",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -2048,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 110 },
-        class_name: "dwd",
-        element_name: "MAX TEMPERATURE(2M),DEV.FR.DEF.FIELD",
-        unit: "K",
-        scale: 1,
-        reference_value: -1024,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 111 },
-        class_name: "dwd",
-        element_name: "MIN TEMPERATURE(2M),DEV.FR.DEF.FIELD",
-        unit: "K",
-        scale: 1,
-        reference_value: -1024,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 112 },
-        class_name: "dwd",
-        element_name: "GROUND MIN.TEMPERATURE,DEV.FR.DEF.FIELD",
-        unit: "K",
-        scale: 1,
-        reference_value: -1024,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 113 },
-        class_name: "dwd",
-        element_name: "CLOUD COVER,DEV.FR.DEF.FIELD",
-        unit: "%",
-        scale: 0,
-        reference_value: -128,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 114 },
-        class_name: "dwd",
-        element_name: "LOW CLOUD COVER,DEV.FR.DEF.FIELD",
-        unit: "%",
-        scale: 0,
-        reference_value: -128,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 115 },
-        class_name: "dwd",
-        element_name: "HORIZONTAL VISIBILITY,DEV.FR.DEF.FIELD",
-        unit: "m",
-        scale: -1,
-        reference_value: -8192,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 116 },
-        class_name: "dwd",
-        element_name: "TOTAL PRECIPITATION,DEV.FR.DEF.FIELD",
-        unit: "kg m-2",
-        scale: 1,
-        reference_value: -16384,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 53, y: 117 },
-        class_name: "dwd",
-        element_name: "GLOBAL RADIATION,DEV.FR.DEF.FIELD",
-        unit: "J m-2",
-        scale: -4,
-        reference_value: -32768,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 196 },
-        class_name: "dwd",
-        element_name: "AMOUNT OF ICE SINGIFICANCE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 197 },
-        class_name: "dwd",
-        element_name: "OPENINGS IN THE ICE SIGNIFICANCE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 198 },
-        class_name: "dwd",
-        element_name: "DATA GROUP",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 204 },
-        class_name: "dwd",
-        element_name: "SQUARE ROOT OF THE EW VARIANCE OF FF",
-        unit: "m/s",
-        scale: 3,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 205 },
-        class_name: "dwd",
-        element_name: "SIGNED COVARIANCE OF WIND SPEED",
-        unit: "m/s",
-        scale: 3,
-        reference_value: -16384,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 206 },
-        class_name: "dwd",
-        element_name: "SQUARE ROOT OF NW VARIANCE OF WIND SPEED",
-        unit: "m/s",
-        scale: 3,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 207 },
-        class_name: "dwd",
-        element_name: "MINIMUM WIND SPEED",
-        unit: "m/s",
-        scale: 1,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 208 },
-        class_name: "dwd",
-        element_name: "RELIABILITY OF WIND AT FLIGHT LEVEL",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 232 },
-        class_name: "dwd",
-        element_name: "PRECIPITATION OCCURED",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 233 },
-        class_name: "dwd",
-        element_name: "HUMID TEMPERATURE",
-        unit: "K",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 234 },
-        class_name: "dwd",
-        element_name: "STATE OF HUMIDITY",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 235 },
-        class_name: "dwd",
-        element_name: "NUMBER OF DAYS FOR CONTROLLING MEASURME.",
-        unit: "d",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 236 },
-        class_name: "dwd",
-        element_name: "BLOCK OF PRECIPITATION AND SNOW COVER",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 203 },
-        class_name: "dwd",
-        element_name: "THERMAL RADIATION OF THE ATMOSPHERE",
-        unit: "J m-2",
-        scale: -2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 204 },
-        class_name: "dwd",
-        element_name: "DURATION OF SUNSHINE USING THE WOZ",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 208 },
-        class_name: "dwd",
-        element_name: "PRESENT WEATHER (MAWEC)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 213 },
-        class_name: "dwd",
-        element_name: "AMOUNT OF FAST ICE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 214 },
-        class_name: "dwd",
-        element_name: "SEA-ICE TOPOGRAPHY",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 217 },
-        class_name: "dwd",
-        element_name: "SIGNIFICANT WEATHER PHENOMENA (NBC CDM)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 218 },
-        class_name: "dwd",
-        element_name: "CLOUD COVER (NBC CDM)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 219 },
-        class_name: "dwd",
-        element_name: "PRESENT WEATHER (SUPREP)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 224 },
-        class_name: "dwd",
-        element_name: "STATE OF ROAD IN VICINITY OF OBS POINT",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 225 },
-        class_name: "dwd",
-        element_name: "STATE OF TERRAIN PREV. IN VICI. OF OBS P",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 226 },
-        class_name: "dwd",
-        element_name: "PRESENT WEATHER (TARWI)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 230 },
-        class_name: "dwd",
-        element_name: "CLOUD COVER OF THE HILL - CODE TABLE 50",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 237 },
-        class_name: "dwd",
-        element_name: "METEOROLOGICAL OPTICAL RANGE",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 18,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 238 },
-        class_name: "dwd",
-        element_name: "MINIMUM METEOROLOGICAL OPTICAL RANGE",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 18,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 239 },
-        class_name: "dwd",
-        element_name: "MAXIMUM METEOROLOGICAL OPTICAL RANGE",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 18,
-    },
-    TableBEntry {
-        xy: XY { x: 52, y: 210 },
-        class_name: "dwd",
-        element_name: "REFERENCE NO AND DD OF THE HILL - TAB 32",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 1 },
-        class_name: "dwd",
-        element_name: "FLIGHT CONDITIONS (RECCO)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 2 },
-        class_name: "dwd",
-        element_name: "PRESENT WEATHER (RECCO)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 3 },
-        class_name: "dwd",
-        element_name: "SIGNIFICANT WEATHER CHANGES",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 4 },
-        class_name: "dwd",
-        element_name: "DISTANT WEATHER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 5 },
-        class_name: "dwd",
-        element_name: "RATE OF ICING",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 6 },
-        class_name: "dwd",
-        element_name: "TYPE OF ICING AND TYPE OF CONTRAILS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 7 },
-        class_name: "dwd",
-        element_name: "ALTITUDE OF BASE OF LAYER IN WHICH I OCC",
-        unit: "m",
-        scale: -1,
-        reference_value: -40,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 8 },
-        class_name: "dwd",
-        element_name: "ALTITUDE OF TOP OF LAYER IN WHICH I OCC",
-        unit: "m",
-        scale: -1,
-        reference_value: -40,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 9 },
-        class_name: "dwd",
-        element_name: "IN-FLIGHT HORIZONTAL VISIBILITY",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 10 },
-        class_name: "dwd",
-        element_name: "AMOUNT OF ICE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 11 },
-        class_name: "dwd",
-        element_name: "ICE TOPOGRAPHY",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 12 },
-        class_name: "dwd",
-        element_name: "ICE PUDDLES",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 13 },
-        class_name: "dwd",
-        element_name: "ORIENTATION OF OPENINGS IN THE ICE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 14 },
-        class_name: "dwd",
-        element_name: "WIDTH OF THE OPENINGS IN THE ICE",
-        unit: "m",
-        scale: -1,
-        reference_value: 0,
-        bits: 13,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 15 },
-        class_name: "dwd",
-        element_name: "INTENSITY OF BIRDSTRIKE WARNING",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 192 },
-        class_name: "dwd",
-        element_name: "BLOCK OF THE CONTINUOUS WEATHER TREND",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 193 },
-        class_name: "dwd",
-        element_name: "BLOCK OF THE NOTES",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 194 },
-        class_name: "dwd",
-        element_name: "BLOCK OF DANGEROUS WEATHER",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 195 },
-        class_name: "dwd",
-        element_name: "BLOCK OF WINTER SPORTS OBSERVATION",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 196 },
-        class_name: "dwd",
-        element_name: "BLOCK OF ORNITHOLOGICAL OBSERVATION",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 197 },
-        class_name: "dwd",
-        element_name: "BLOCK OF WEATHER HISTORY",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 198 },
-        class_name: "dwd",
-        element_name: "BLOCK OF ADDITIONAL OBSERVATION",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 199 },
-        class_name: "dwd",
-        element_name: "BLOCK OF 9SPSPSPSP",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 200 },
-        class_name: "dwd",
-        element_name: "BLOCK OF PHAENOLOGICAL REPORT",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 201 },
-        class_name: "dwd",
-        element_name: "BLOCK OF ADDITIONAL COMMENT",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 202 },
-        class_name: "dwd",
-        element_name: "BLOCK OF ADDITIONAL COMMENT",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 200,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 230 },
-        class_name: "dwd",
-        element_name: "UNIQUE PRODUCT DESCRIPTION",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 2048,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 230 },
-        class_name: "dwd",
-        element_name: "MAXIMUM SIZE OF X DIMENSION IN A VOLUME SCAN OR DATA CUBE",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 6, y: 230 },
-        class_name: "dwd",
-        element_name: "MAXIMUM SIZE OF Y DIMENSION IN A VOLUME SCAN OR DATA CUBE",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 7, y: 230 },
-        class_name: "dwd",
-        element_name: "MAXIMUM SIZE OF Z DIMENSION IN A VOLUME SCAN OR DATA CUBE",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 224 },
-        class_name: "dwd",
-        element_name: "ROLL",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 225 },
-        class_name: "dwd",
-        element_name: "PITCH",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 2, y: 226 },
-        class_name: "dwd",
-        element_name: "YAW",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 5, y: 233 },
-        class_name: "dwd",
-        element_name: "NUMBER OF SCANLINES",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 10, y: 220 },
-        class_name: "dwd",
-        element_name: "PRESSURE (HIGH PRECISION)",
-        unit: "Pa",
-        scale: -1,
-        reference_value: 0,
-        bits: 30,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 220 },
-        class_name: "dwd",
-        element_name: "MIXING RATIO (HIGH PRECISION)",
-        unit: "kg/kg",
-        scale: 7,
-        reference_value: 0,
-        bits: 30,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 222 },
-        class_name: "dwd",
-        element_name: "RETRIEVAL ERROR FOR OZONE",
-        unit: "Numeric",
-        scale: 4,
-        reference_value: -1000000,
-        bits: 21,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 192 },
-        class_name: "dwd",
-        element_name: "CLOUD FORMATION AND HEIGHT ASSIGNMENT",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 193 },
-        class_name: "dwd",
-        element_name: "INSTRUMENT DETECTING CLOUDS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 194 },
-        class_name: "dwd",
-        element_name: "VALIDATION FLAG FOR IASI LEVEL 1 PRODUCT",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 195 },
-        class_name: "dwd",
-        element_name: "QUALITY AND COMPLETENESS OF RETRIEVAL",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 196 },
-        class_name: "dwd",
-        element_name: "RETRIEVAL CHOICE INDICATOR",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 197 },
-        class_name: "dwd",
-        element_name: "SATELLITE MANOEUVRE INDICATOR",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 198 },
-        class_name: "dwd",
-        element_name: "SELECTION OF BACKGROUND STATE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 199 },
-        class_name: "dwd",
-        element_name: "INTEGRATED N2O DENSITY",
-        unit: "kg m-2",
-        scale: 6,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 200 },
-        class_name: "dwd",
-        element_name: "INTEGRATED CO DENSITY",
-        unit: "kg m-2",
-        scale: 7,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 201 },
-        class_name: "dwd",
-        element_name: "INTEGRATED CH4 DENSITY",
-        unit: "kg m-2",
-        scale: 6,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 202 },
-        class_name: "dwd",
-        element_name: "INTEGRATED CO2 DENSITY",
-        unit: "kg m-2",
-        scale: 3,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 211 },
-        class_name: "dwd",
-        element_name: "GMI QUALITY FLAG",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 212 },
-        class_name: "dwd",
-        element_name: "SUN GLINT ANGLE",
-        unit: "deg",
-        scale: 2,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 213 },
-        class_name: "dwd",
-        element_name: "OCEAN PRODUCT QUALITY FLAG",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 214 },
-        class_name: "dwd",
-        element_name: "OCEAN SUMMARY QUALITY FLAG",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 220 },
-        class_name: "dwd",
-        element_name: "QUALITY INDICATOR FOR ATMOSPHERIC TEMPERATURE",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 221 },
-        class_name: "dwd",
-        element_name: "QUALITY INDICATOR FOR ATMOSPHERIC WATER_VAPOUR",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 222 },
-        class_name: "dwd",
-        element_name: "QUALITY INDICATOR FOR ATMOSPHERIC OZONE",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 223 },
-        class_name: "dwd",
-        element_name: "QUALITY INDICATOR FOR SURFACE TEMPERATURE",
-        unit: "Numeric",
-        scale: 1,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 230 },
-        class_name: "dwd",
-        element_name: "CLOUD FORMATION AND HEIGTH ASSIGNMENT (MODIFIED)",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 231 },
-        class_name: "dwd",
-        element_name: "CLOUDINESS SUMMARY",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 232 },
-        class_name: "dwd",
-        element_name: "VALIDATION FLAG FOR IASI LEVEL1 PRODUCT",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 233 },
-        class_name: "dwd",
-        element_name: "VALIDATION FLAG OF AMSU-A LEVEL 1 DATA FLOW",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 234 },
-        class_name: "dwd",
-        element_name: "CLOUDS TESTS EXECUTED AND RESULTS",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 235 },
-        class_name: "dwd",
-        element_name: "RETRIEVAL INITIALISATION",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 236 },
-        class_name: "dwd",
-        element_name: "CONVERGENCE OF THE ITERATIVE RETRIEVAL",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 237 },
-        class_name: "dwd",
-        element_name: "VALIDATION FLAG OF MHS LEVEL 1 DATA FLOW",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 238 },
-        class_name: "dwd",
-        element_name: "VALIDATION FLAG OF NWP FORECAST",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 239 },
-        class_name: "dwd",
-        element_name: "INDICATION OF SUPER-ADIABATIC AND SUPER-SATURATION IN FINAL RETRIEVAL",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 240 },
-        class_name: "dwd",
-        element_name: "NUMBER OF ITERATIONS USED FOR RETRIEVAL",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 242 },
-        class_name: "dwd",
-        element_name: "GENERAL RETRIEVAL QUALITY FLAG",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 243 },
-        class_name: "dwd",
-        element_name: "RETRIEVAL FLAG",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 244 },
-        class_name: "dwd",
-        element_name: "NUMBER OF VECTORS DESCRIBING THE CHAR. MATRICES",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 245 },
-        class_name: "dwd",
-        element_name: "NUMBER OF LAYERS ACTUALLY RETRIEVED",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 246 },
-        class_name: "dwd",
-        element_name: "NUMBER OF CO PROFILES RETRIEVED IN SCANLINE",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 247 },
-        class_name: "dwd",
-        element_name: "AIR PARTIAL COLUMNS ON EACH RETRIEVED LAYER",
-        unit: "Molecul cm-2",
-        scale: -20,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 248 },
-        class_name: "dwd",
-        element_name: "A-PRIORI PARTIAL COLUMNS FOR CO EN EACH RETRIEVED LAYER",
-        unit: "Molecul cm-2",
-        scale: -13,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 249 },
-        class_name: "dwd",
-        element_name: "SCAL. VEC. MULT. A-PRI. CO VEC. DEF. RETR. CO VEC.",
-        unit: "Numeric",
-        scale: 5,
-        reference_value: 0,
-        bits: 26,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 250 },
-        class_name: "dwd",
-        element_name: "MAIN EIGENVALUES OF THE SENSITIVITY MATRIX",
-        unit: "Numeric",
-        scale: 6,
-        reference_value: 0,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 40, y: 251 },
-        class_name: "dwd",
-        element_name: "MAIN EIGENVECTORS OF THE SENSITIVITY MATRIX",
-        unit: "Numeric",
-        scale: 6,
-        reference_value: -1000000000,
-        bits: 31,
-    },
-    TableBEntry {
-        xy: XY { x: 55, y: 3 },
-        class_name: "dwd",
-        element_name: "DATA EXTRACTION INDICATOR",
-        unit: "Flag table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 62, y: 190 },
-        class_name: "dwd",
-        element_name: "STATISTICS APPLICATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 62, y: 191 },
-        class_name: "dwd",
-        element_name: "TYPE OF STATISTICS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 63, y: 190 },
-        class_name: "dwd",
-        element_name: "APPLICATION DERIVING SUBSTITUTE OR ALTERNATIVE VALUE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 8,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 241 },
-        class_name: "dwd",
-        element_name: "Valeur minimale de la vitesse radiale en m/s",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -700,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 242 },
-        class_name: "dwd",
-        element_name: "Valeur maximale de la vitesse radiale en m/s",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -700,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 49, y: 243 },
-        class_name: "dwd",
-        element_name: "Valeur de la composante sud/nord du vecteur advection en m/s",
-        unit: "m/s",
-        scale: 1,
-        reference_value: -600,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 222 },
-        class_name: "dwd",
-        element_name: "Numero identification objet",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 1, y: 223 },
-        class_name: "dwd",
-        element_name: "Identificateur des cartes de risque pluviometrique",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 4, y: 204 },
-        class_name: "dwd",
-        element_name: "Duree de cumul effective hors donnees manquantes",
-        unit: "min",
-        scale: 0,
-        reference_value: -4320,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 205 },
-        class_name: "dwd",
-        element_name: "Nature objet nuage",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 210 },
-        class_name: "dwd",
-        element_name: "Quantile retenu au sein d'un voisinage",
-        unit: "%",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 220 },
-        class_name: "dwd",
-        element_name: "Facteur de correction de lame d'eau calcule par pixel (pluviometre/radar)",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 221 },
-        class_name: "dwd",
-        element_name: "Type de facteur correctif (pluviometre/radar) applique a la lame d'eau",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 25, y: 239 },
-        class_name: "dwd",
-        element_name: "Facteur mensuel de correction globale de la lame d'eau (pluviometre/radar)",
-        unit: "Numeric",
-        scale: 2,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 8, y: 199 },
-        class_name: "dwd",
-        element_name: "EXTENDED TIME SIGNIFICANCE",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 11, y: 209 },
-        class_name: "dwd",
-        element_name: "WIND DIRECTION (ESTIMATED)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 6,
-    },
-    TableBEntry {
-        xy: XY { x: 12, y: 212 },
-        class_name: "dwd",
-        element_name: "DRY BULB TT 2M,DEVIAT.FROM NORM.(HI.ACC)",
-        unit: "K",
-        scale: 2,
-        reference_value: -1000,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 13, y: 237 },
-        class_name: "dwd",
-        element_name: "SNOW COVER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 3,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 205 },
-        class_name: "dwd",
-        element_name: "Corrected diffuse solar radiation (high accuracy), integrated over period specified",
-        unit: "J m-2",
-        scale: -1,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 14, y: 206 },
-        class_name: "dwd",
-        element_name: "Calculated total sunshine",
-        unit: "min",
-        scale: 0,
-        reference_value: 0,
-        bits: 11,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 195 },
-        class_name: "dwd",
-        element_name: "Path delay due to neutral atmosphere",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 196 },
-        class_name: "dwd",
-        element_name: "Estimated error in neutral atmosphere path delay",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 197 },
-        class_name: "dwd",
-        element_name: "Zenith path delay due to neutral atmosphere",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 198 },
-        class_name: "dwd",
-        element_name: "Estimated error in neutral atmosphere zenith path delay",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 12,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 199 },
-        class_name: "dwd",
-        element_name: "Wet path delay due to neutral atmosphere",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 18,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 200 },
-        class_name: "dwd",
-        element_name: "Path integrated water vapour",
-        unit: "kg m-2",
-        scale: 1,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 201 },
-        class_name: "dwd",
-        element_name: "GNSS derived neutral atmosphere gradient",
-        unit: "m",
-        scale: 5,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 203 },
-        class_name: "dwd",
-        element_name: "GNSS multi-path delay",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 204 },
-        class_name: "dwd",
-        element_name: "GNSS hydrostatic mapping function",
-        unit: "Numeric",
-        scale: 3,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 205 },
-        class_name: "dwd",
-        element_name: "GNSS wet mapping function",
-        unit: "Numeric",
-        scale: 3,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 206 },
-        class_name: "dwd",
-        element_name: "GNSS gradient mapping function",
-        unit: "Numeric",
-        scale: 3,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 207 },
-        class_name: "dwd",
-        element_name: "Zenith path delay due to neutral hydrostatic atmosphere",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 15, y: 208 },
-        class_name: "dwd",
-        element_name: "Path delay due to neutral hydrostatic atmosphere",
-        unit: "m",
-        scale: 4,
-        reference_value: 0,
-        bits: 20,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 216 },
-        class_name: "dwd",
-        element_name: "DIRECTION MAIN OCC.OF WEATHER PHEN. TAB.",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 227 },
-        class_name: "dwd",
-        element_name: "AMPLITUDE OF LIGHTNING STRIKE",
-        unit: "A",
-        scale: -2,
-        reference_value: -16384,
-        bits: 15,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 228 },
-        class_name: "dwd",
-        element_name: "LIGHTNING STRIKE CLASSIFICATION",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 229 },
-        class_name: "dwd",
-        element_name: "LIGHTNING DETECTION ERROR",
-        unit: "m",
-        scale: 0,
-        reference_value: 0,
-        bits: 16,
-    },
-    TableBEntry {
-        xy: XY { x: 20, y: 240 },
-        class_name: "dwd",
-        element_name: "HORIZONTAL VISIBILITY (ESTIMATED)",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 4,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 202 },
-        class_name: "dwd",
-        element_name: "SAMPLE VOLUME",
-        unit: "m3",
-        scale: 2,
-        reference_value: 0,
-        bits: 27,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 203 },
-        class_name: "dwd",
-        element_name: "DETECTOR IDENTIFIER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 204 },
-        class_name: "dwd",
-        element_name: "GEOMETRY IDENTIFIER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 205 },
-        class_name: "dwd",
-        element_name: "TYP OF FILTER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 206 },
-        class_name: "dwd",
-        element_name: "PUMP IDENTIFIER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 207 },
-        class_name: "dwd",
-        element_name: "REPORT STATUS",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 2,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 208 },
-        class_name: "dwd",
-        element_name: "SAMPLE TYP IDENTIFIER",
-        unit: "Code table",
-        scale: 0,
-        reference_value: 0,
-        bits: 7,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 209 },
-        class_name: "dwd",
-        element_name: "BASE OF LIMIT OF DETECTION",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 210 },
-        class_name: "dwd",
-        element_name: "EXPONENT OF LIMIT OF DETECTION",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: -15,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 211 },
-        class_name: "dwd",
-        element_name: "CHEMICAL RECOVERY",
-        unit: "%",
-        scale: 2,
-        reference_value: 0,
-        bits: 14,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 212 },
-        class_name: "dwd",
-        element_name: "LIVE TIME",
-        unit: "s",
-        scale: 0,
-        reference_value: 0,
-        bits: 24,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 213 },
-        class_name: "dwd",
-        element_name: "BASE OF DECISION THRESHOLD",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: 0,
-        bits: 10,
-    },
-    TableBEntry {
-        xy: XY { x: 24, y: 214 },
-        class_name: "dwd",
-        element_name: "EXPONENT OF DECISION THRESHOLD",
-        unit: "Numeric",
-        scale: 0,
-        reference_value: -15,
-        bits: 5,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 203 },
-        class_name: "dwd",
-        element_name: "BLOCK OF LAST CHANGE USER",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 204 },
-        class_name: "dwd",
-        element_name: "BLOCK OF LAST CHANGE COMMENT",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 205 },
-        class_name: "dwd",
-        element_name: "BLOCK OF PRECIPITATION IN KWV",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 206 },
-        class_name: "dwd",
-        element_name: "BLOCK OF PRECIPITATION ACCRETION IN KWV",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-    TableBEntry {
-        xy: XY { x: 56, y: 207 },
-        class_name: "dwd",
-        element_name: "BLOCK OF OTHER WEATHER PHENOMENA IN KWV",
-        unit: "CCITT IA5",
-        scale: 0,
-        reference_value: 0,
-        bits: 64,
-    },
-];
 
-pub static DWD_BUFR_TABLE_D: [TableDEntry; 441] = [
-    TableDEntry {
-        xy: XY { x: 4, y: 35 },
-        category: "DWD sequences",
-        title: "All sky radiance data",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 153 },
-            Descriptor { f: 0, x: 2, y: 154 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 8, y: 1 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 8, y: 1 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 8, y: 1 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 8, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 82 },
-        category: "DWD sequences",
-        title: "Sequence for representation of synoptic reports from a fixed land station suitable for SYNOP data in compliance with reporting practices in RA II) (see Note 7",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 90 },
-            Descriptor { f: 3, x: 2, y: 31 },
-            Descriptor { f: 3, x: 2, y: 35 },
-            Descriptor { f: 3, x: 2, y: 36 },
-            Descriptor { f: 3, x: 2, y: 47 },
-            Descriptor { f: 0, x: 8, y: 2 },
-            Descriptor { f: 3, x: 2, y: 48 },
-            Descriptor { f: 3, x: 2, y: 37 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 121,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 122,
-            },
-            Descriptor { f: 3, x: 2, y: 43 },
-            Descriptor { f: 3, x: 2, y: 44 },
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor { f: 3, x: 2, y: 45 },
-            Descriptor { f: 3, x: 2, y: 46 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 30 },
-        category: "DWD sequences",
-        title: "Ozone sonde flight data) (see Note 1",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 15, y: 4 },
-            Descriptor { f: 0, x: 15, y: 5 },
-            Descriptor { f: 1, x: 4, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 4, y: 15 },
-            Descriptor { f: 0, x: 8, y: 6 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 15, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 27 },
-        category: "DWD sequences",
-        title: "All sky radiance product main sequence",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 71 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 30, y: 21 },
-            Descriptor { f: 0, x: 30, y: 22 },
-            Descriptor { f: 0, x: 10, y: 2 },
-            Descriptor { f: 3, x: 4, y: 36 },
-            Descriptor { f: 0, x: 2, y: 152 },
-            Descriptor { f: 0, x: 2, y: 167 },
-            Descriptor { f: 1, x: 1, y: 11 },
-            Descriptor { f: 3, x: 4, y: 35 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 63, y: 225 },
-        category: "DWD sequences",
-        title: "SEQUENCE OF NCEP 007 SUBCENTRE 003",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor {
-                f: 3,
-                x: 63,
-                y: 226,
-            },
-            Descriptor {
-                f: 3,
-                x: 63,
-                y: 227,
-            },
-            Descriptor {
-                f: 3,
-                x: 63,
-                y: 228,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 192 },
-        category: "DWD sequences",
-        title: "DECODING DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 54,
-                y: 201,
-            },
-            Descriptor {
-                f: 0,
-                x: 54,
-                y: 202,
-            },
-            Descriptor {
-                f: 0,
-                x: 54,
-                y: 203,
-            },
-            Descriptor {
-                f: 0,
-                x: 54,
-                y: 204,
-            },
-            Descriptor {
-                f: 0,
-                x: 54,
-                y: 205,
-            },
-            Descriptor {
-                f: 0,
-                x: 54,
-                y: 211,
-            },
-            Descriptor {
-                f: 0,
-                x: 54,
-                y: 212,
-            },
-            Descriptor {
-                f: 0,
-                x: 54,
-                y: 213,
-            },
-            Descriptor { f: 0, x: 2, y: 192 },
-            Descriptor { f: 0, x: 2, y: 193 },
-            Descriptor { f: 0, x: 2, y: 201 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 193 },
-        category: "DWD sequences",
-        title: "DECODING DATA/LAT.+LONG.+DATE+TIME WITH ERROR FLAGS",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 192 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 194 },
-        category: "DWD sequences",
-        title: "WMO BLOCK/STATION NUMBER/HEIGHTS",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 1 },
-            Descriptor { f: 0, x: 7, y: 1 },
-            Descriptor { f: 0, x: 10, y: 1 },
-            Descriptor { f: 0, x: 2, y: 221 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 201 },
-        category: "DWD sequences",
-        title: "TECHNICAL DATA: SURFACE OBSERVATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 1 },
-            Descriptor { f: 0, x: 2, y: 2 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 202 },
-        category: "DWD sequences",
-        title: "TECHNICAL DATA: UPPER AIR OBSERVATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 3 },
-            Descriptor { f: 0, x: 2, y: 11 },
-            Descriptor { f: 0, x: 2, y: 13 },
-            Descriptor { f: 0, x: 2, y: 14 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 203 },
-        category: "DWD sequences",
-        title: "TECHNICAL DATA: AIRCRAFT OBSERVATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 61 },
-            Descriptor { f: 0, x: 8, y: 4 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 204 },
-        category: "DWD sequences",
-        title: "TECHNICAL DATA: SATELLITE OBSERVATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 21 },
-            Descriptor { f: 0, x: 2, y: 22 },
-            Descriptor { f: 0, x: 2, y: 23 },
-            Descriptor { f: 0, x: 2, y: 24 },
-            Descriptor { f: 0, x: 2, y: 25 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 205 },
-        category: "DWD sequences",
-        title: "DATE/TIME OF END OF FORCAST PERIOD",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 206 },
-        category: "DWD sequences",
-        title: "DATE/TIME OF START OF OBSERVATION FOR RADIOACTIVITY DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 207 },
-        category: "DWD sequences",
-        title: "DATE/TIME OF END OF OBSERVATION FOR RADIOACTIVITY DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 211 },
-        category: "DWD sequences",
-        title: "REPORT HEAD",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 193 },
-            Descriptor { f: 0, x: 1, y: 3 },
-            Descriptor { f: 3, x: 1, y: 194 },
-            Descriptor { f: 0, x: 1, y: 192 },
-            Descriptor { f: 3, x: 1, y: 3 },
-            Descriptor { f: 0, x: 1, y: 5 },
-            Descriptor { f: 0, x: 1, y: 6 },
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 1, y: 31 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 254 },
-        category: "DWD sequences",
-        title: "WIND PROFILER STATION DATA/TIME OF OBSERVATION, TEST FORMAT",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 1 },
-            Descriptor { f: 0, x: 2, y: 3 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 3, x: 1, y: 24 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 192 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: MSL PRESSURE/TENDENCY",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor { f: 0, x: 10, y: 61 },
-            Descriptor { f: 0, x: 10, y: 63 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 193 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: PRESSURE(STAT.)/PRESS.LEVEL/GEOPOTENTIAL",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 10, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 194 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: WIND DIRECTION/SPEED",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 11, y: 11 },
-            Descriptor { f: 0, x: 11, y: 12 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 195 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: GUSTS/MAX.MEAN WIND SPEED DUR.PERIOD FOR W1W2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 0, x: 11, y: 42 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 196 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: GUSTS/MAXIMUM MEAN WIND SPEED DURING PAST HOUR",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 0, x: 11, y: 42 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 197 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: GUSTS/MAXIMUM MEAN WIND SPEED DURING PAST DAY",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 0, x: 11, y: 42 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 201 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: TEMPERATURE/DEWPOINT/RELATIVE HUMIDITY",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 12, y: 6 },
-            Descriptor { f: 0, x: 13, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 202 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: EXTREME TEMPERATURES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 12, y: 11 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 12, y: 12 },
-            Descriptor { f: 0, x: 12, y: 13 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 203 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: PRECIPITATION                                 IPRECI",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 13, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 204 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: PRECIPITATION/SNOW/STATE OF GROUND",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 3 },
-            Descriptor { f: 3, x: 2, y: 203 },
-            Descriptor { f: 0, x: 13, y: 13 },
-            Descriptor { f: 0, x: 20, y: 62 },
-            Descriptor { f: 0, x: 20, y: 62 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 205 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: PRECIPITATION (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 4, y: 194 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 206 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: DUR.OF TIME/FRESH SNOW (SYN.,SECT.5,GERM.NAT.)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 13, y: 12 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 207 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: PRECIPITATION (CLIMATOL.REP., GERMAN NATIONAL)IPRECK",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 13, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 208 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: SNOW (CLIMATOLOGICAL REPORT, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 13, y: 12 },
-            Descriptor { f: 0, x: 13, y: 13 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 211 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: VISIBILITY/WEATHER",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 20, y: 1 },
-            Descriptor { f: 0, x: 20, y: 3 },
-            Descriptor { f: 0, x: 20, y: 4 },
-            Descriptor { f: 0, x: 20, y: 5 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 212 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: CLOUD",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 20, y: 10 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 13 },
-            Descriptor { f: 0, x: 20, y: 12 },
-            Descriptor { f: 0, x: 20, y: 12 },
-            Descriptor { f: 0, x: 20, y: 12 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 213 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: CLOUD LAYERS                                  ICLOUD",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 2 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 12 },
-            Descriptor { f: 0, x: 20, y: 13 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 221 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: SYNOP/SHIP SECTION 1 (EXC.GR.6), 3 (GR.58, 59)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 2, y: 192 },
-            Descriptor { f: 3, x: 2, y: 193 },
-            Descriptor { f: 0, x: 10, y: 62 },
-            Descriptor { f: 3, x: 2, y: 3 },
-            Descriptor { f: 3, x: 2, y: 212 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 223 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: SYNOP SECT.1(GR.6),3(GR.1,2,3,4,6,7,8,911,912)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 2, y: 195 },
-            Descriptor { f: 3, x: 2, y: 202 },
-            Descriptor { f: 3, x: 2, y: 204 },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor { f: 3, x: 2, y: 5 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 224 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: SYNOP SECT.3(GR. 50,..,53,55 INCL.ADD.GROUPS)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 13, y: 32 },
-            Descriptor { f: 0, x: 2, y: 4 },
-            Descriptor { f: 3, x: 2, y: 214 },
-            Descriptor { f: 3, x: 2, y: 215 },
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor { f: 3, x: 2, y: 216 },
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor { f: 3, x: 2, y: 217 },
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor { f: 3, x: 2, y: 218 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 225 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: SYNOP, PART OF SECTION 5 (GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 12, y: 61 },
-            Descriptor {
-                f: 0,
-                x: 51,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 51,
-                y: 193,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 203,
-            },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 193,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 194,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 195,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 196,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 226 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: SOIL TEMPERATURES                             ITSOI",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 61 },
-            Descriptor { f: 0, x: 12, y: 30 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 231 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: METAR/WEHI (METEOROLOGICAL PART)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 10, y: 52 },
-            Descriptor { f: 3, x: 2, y: 194 },
-            Descriptor { f: 3, x: 2, y: 201 },
-            Descriptor { f: 3, x: 2, y: 211 },
-            Descriptor { f: 0, x: 20, y: 10 },
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor { f: 3, x: 2, y: 213 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 235 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: MEAN VALUES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 10, y: 3 },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 13, y: 4 },
-            Descriptor { f: 0, x: 22, y: 42 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 236 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: ACCUMULATED VALUES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 192,
-            },
-            Descriptor { f: 0, x: 14, y: 32 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 237 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: CLIMAT OR CLIMAT SHIP (MONTHLY VALUES)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 2, y: 235 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 2, y: 236 },
-            Descriptor { f: 0, x: 4, y: 201 },
-            Descriptor {
-                f: 0,
-                x: 14,
-                y: 192,
-            },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 238 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: CLIMAT OR CLIMAT SHIP (NORMAL VALUES)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 21 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 2, y: 235 },
-            Descriptor { f: 0, x: 4, y: 21 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 2, y: 236 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 240 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: SUNSHINE (GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 14, y: 31 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 241 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: TEMPERAT., HUMIDITY (GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 12, y: 11 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 12, y: 12 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 12, y: 5 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 201,
-            },
-            Descriptor { f: 0, x: 13, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 242 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: OBSERVED PHENOMENA (GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 201 },
-            Descriptor { f: 0, x: 20, y: 1 },
-            Descriptor { f: 0, x: 20, y: 10 },
-            Descriptor { f: 0, x: 20, y: 62 },
-            Descriptor { f: 0, x: 20, y: 62 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 202,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 203,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 204,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 205,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 206,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 243 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: SNOW SAMPLER DATA, PAST DAY (GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 201,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 202,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 203,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 244 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: EILKLIMA MEAN AND ADJACENT EXTREME VALUES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 211,
-            },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 0, x: 12, y: 11 },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor { f: 0, x: 4, y: 193 },
-            Descriptor { f: 0, x: 12, y: 12 },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor { f: 0, x: 4, y: 193 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 245 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: EILKLIMA (STATISTICS ON SPEC.WEATHER PHENOMENA)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 202 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 246 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: EILKLIMA (ACCUM.AND ADJACENT EXTREME VALUES)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 193,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 194,
-            },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor { f: 0, x: 4, y: 193 },
-            Descriptor { f: 0, x: 14, y: 32 },
-            Descriptor {
-                f: 0,
-                x: 14,
-                y: 192,
-            },
-            Descriptor { f: 1, x: 1, y: 14 },
-            Descriptor { f: 3, x: 2, y: 245 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 192 },
-        category: "DWD sequences",
-        title: "VERT.SOUND.DATA: GEOP./PRESS./SIGNIFIC./WIND/TEMP./DEW PT.  IHPWTD",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 3 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 8, y: 1 },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 12, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 193 },
-        category: "DWD sequences",
-        title: "VERT.SOUND.DATA: INCLUDING SHEAR WIND                       IHPWSH",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 3, y: 192 },
-            Descriptor { f: 0, x: 11, y: 61 },
-            Descriptor { f: 0, x: 11, y: 62 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 201 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF GEOPOTENTIAL",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 53, y: 1 },
-            Descriptor { f: 0, x: 53, y: 11 },
-            Descriptor { f: 0, x: 53, y: 21 },
-            Descriptor { f: 0, x: 53, y: 31 },
-            Descriptor { f: 0, x: 53, y: 41 },
-            Descriptor { f: 0, x: 53, y: 51 },
-            Descriptor { f: 0, x: 53, y: 61 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 202 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF ZONAL COMPONENT (U)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 53, y: 2 },
-            Descriptor { f: 0, x: 53, y: 12 },
-            Descriptor { f: 0, x: 53, y: 22 },
-            Descriptor { f: 0, x: 53, y: 32 },
-            Descriptor { f: 0, x: 53, y: 42 },
-            Descriptor { f: 0, x: 53, y: 52 },
-            Descriptor { f: 0, x: 53, y: 62 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 203 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF MERIDIONAL COMPONENT (V)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 53, y: 3 },
-            Descriptor { f: 0, x: 53, y: 13 },
-            Descriptor { f: 0, x: 53, y: 23 },
-            Descriptor { f: 0, x: 53, y: 33 },
-            Descriptor { f: 0, x: 53, y: 43 },
-            Descriptor { f: 0, x: 53, y: 53 },
-            Descriptor { f: 0, x: 53, y: 63 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 204 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF RELATIVE HUMIDITY",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 53, y: 4 },
-            Descriptor { f: 0, x: 53, y: 14 },
-            Descriptor { f: 0, x: 53, y: 24 },
-            Descriptor { f: 0, x: 53, y: 34 },
-            Descriptor { f: 0, x: 53, y: 44 },
-            Descriptor { f: 0, x: 53, y: 54 },
-            Descriptor { f: 0, x: 53, y: 64 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 205 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF THICKNES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 53, y: 5 },
-            Descriptor { f: 0, x: 53, y: 15 },
-            Descriptor { f: 0, x: 53, y: 25 },
-            Descriptor { f: 0, x: 53, y: 35 },
-            Descriptor { f: 0, x: 53, y: 45 },
-            Descriptor { f: 0, x: 53, y: 55 },
-            Descriptor { f: 0, x: 53, y: 65 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 254 },
-        category: "DWD sequences",
-        title: "WIND PROFILER WIND DATA WITH QUALITY INFORMATION,TEST FORMAT",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 3 },
-            Descriptor { f: 0, x: 8, y: 1 },
-            Descriptor { f: 2, x: 4, y: 7 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 4, y: 192 },
-        category: "DWD sequences",
-        title: "SATELLITE DATA: CLOUD/PRESSURE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 20, y: 10 },
-            Descriptor { f: 0, x: 10, y: 4 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 192 },
-        category: "DWD sequences",
-        title: "OCEANOGRAPHIC DATA: SYNOP/SHIP SECTION 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 12, y: 5 },
-            Descriptor { f: 0, x: 22, y: 42 },
-            Descriptor { f: 0, x: 22, y: 11 },
-            Descriptor { f: 0, x: 22, y: 21 },
-            Descriptor { f: 0, x: 22, y: 12 },
-            Descriptor { f: 0, x: 22, y: 22 },
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor { f: 3, x: 2, y: 23 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 192 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT: SYNOP/SHIP, SECTIONS 1 TO 4",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 208 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 2, y: 221 },
-            Descriptor { f: 3, x: 6, y: 192 },
-            Descriptor { f: 3, x: 2, y: 223 },
-            Descriptor { f: 3, x: 2, y: 224 },
-            Descriptor { f: 3, x: 2, y: 210 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 193 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT: METAR/WEHI",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 201 },
-            Descriptor { f: 3, x: 2, y: 231 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 194 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT: SYNOP SECTION 5(EXC.CLIMATOL.), 6",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 2, y: 196 },
-            Descriptor { f: 3, x: 2, y: 225 },
-            Descriptor { f: 3, x: 2, y: 205 },
-            Descriptor { f: 3, x: 2, y: 206 },
-            Descriptor { f: 3, x: 2, y: 228 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 211 },
-        category: "DWD sequences",
-        title: "CLIMAT REPORT: CLIMAT/CLIMAT SHIP",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 2, y: 237 },
-            Descriptor { f: 3, x: 2, y: 238 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 212 },
-        category: "DWD sequences",
-        title: "CLIMAT REPORT: SYNOP SECTION 5, SUBSECTIONS 80000, 90000",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 2, y: 197 },
-            Descriptor { f: 3, x: 2, y: 241 },
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor { f: 3, x: 2, y: 207 },
-            Descriptor { f: 3, x: 2, y: 208 },
-            Descriptor { f: 3, x: 2, y: 243 },
-            Descriptor { f: 3, x: 2, y: 242 },
-            Descriptor { f: 3, x: 2, y: 240 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 213 },
-        category: "DWD sequences",
-        title: "CLIMAT REPORT: EILKLIMA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 2, y: 244 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 2, y: 246 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 192 },
-        category: "DWD sequences",
-        title: "VERTICAL SOUNDING REPORT: PILOT/TEMP (SHIP) PART A",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 202 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 1, x: 1, y: 16 },
-            Descriptor { f: 3, x: 3, y: 193 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 193 },
-        category: "DWD sequences",
-        title: "VERTICAL SOUNDING REPORT: PILOT/TEMP (SHIP) PART B/D",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 3, x: 1, y: 202 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 192 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 194 },
-        category: "DWD sequences",
-        title: "VERTICAL SOUNDING REPORT: PILOT/TEMP (SHIP) PART C",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 202 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 1, x: 1, y: 11 },
-            Descriptor { f: 3, x: 3, y: 193 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 201 },
-        category: "DWD sequences",
-        title: "VERTICAL SOUNDING REPORT: ALL PILOT/TEMP (SHIP) DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 3, x: 1, y: 202 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 3, x: 3, y: 193 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 211 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF UPPER AIR REPORT FROM ANALYSIS/FORECAST, LEVELIDVTLV",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 3, y: 192 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor { f: 3, x: 3, y: 201 },
-            Descriptor { f: 3, x: 3, y: 202 },
-            Descriptor { f: 3, x: 3, y: 203 },
-            Descriptor { f: 3, x: 3, y: 204 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 212 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF UPPER AIR REPORT FROM ANALYSIS/FORECAST",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 202 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 9, y: 211 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 192 },
-        category: "DWD sequences",
-        title: "SATEL.VERTIC.SOUND.DATA: SATEM SURFACE TEMPERATURE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 1 },
-            Descriptor { f: 0, x: 12, y: 1 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 193 },
-        category: "DWD sequences",
-        title: "SATEL.VERTIC.SOUND.DATA: SATEM TROPOPAUSE DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 1 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 2, y: 222 },
-            Descriptor { f: 2, x: 4, y: 7 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 194 },
-        category: "DWD sequences",
-        title: "SATEL.VERTIC.SOUND.DATA: SATEM LEVEL VALUES                 ISAMLV",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 3, y: 21 },
-            Descriptor { f: 0, x: 10, y: 3 },
-            Descriptor { f: 0, x: 13, y: 16 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 201 },
-        category: "DWD sequences",
-        title: "SATEL.VERTIC.SOUND.REPORT: SATEM",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 204 },
-            Descriptor { f: 3, x: 4, y: 192 },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 192,
-            },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 193,
-            },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 194,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 211 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF SATEM REPORT FROM ANALYSIS/FORECAST, LEVEL    IDVSLV",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 194,
-            },
-            Descriptor { f: 3, x: 3, y: 204 },
-            Descriptor { f: 3, x: 3, y: 205 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 212 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF SATEM REPORT FROM ANALYSIS/FORECAST",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 204 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 211,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 11, y: 192 },
-        category: "DWD sequences",
-        title: "SINGLE LEVEL REPORT: CODAR/AIREP/ASDAR/SATOB(SECTION 2 OR 3)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 203 },
-            Descriptor { f: 3, x: 1, y: 204 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 3, y: 192 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 192 },
-        category: "DWD sequences",
-        title: "SATEL.SINGLE LEVEL REPORT: SATOB SECTION 4 (SURF.TEMPERAT.)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 204 },
-            Descriptor { f: 3, x: 4, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 193 },
-        category: "DWD sequences",
-        title: "SATEL.SINGLE LEVEL REPORT: SATOB SECTION 5, 6, 7",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 204 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 10, y: 2 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 0, x: 20, y: 10 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 13, y: 193 },
-        category: "DWD sequences",
-        title: "RUN-LENGTH ENCODED PICTURE DATA ...(SECOND PART OF 3 13 043)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 9, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 1, x: 4, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 6, y: 12 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 11 },
-            Descriptor { f: 0, x: 30, y: 1 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 30, y: 1 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 13, y: 201 },
-        category: "DWD sequences",
-        title: "RADIOACTIVITY OBSERVATION (GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 206 },
-            Descriptor { f: 3, x: 1, y: 207 },
-            Descriptor {
-                f: 3,
-                x: 13,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 13, y: 253 },
-        category: "DWD sequences",
-        title: "WIND PROFILER OBSERV.(WITH QUALITY INFORMAT.), TEST FORMAT A",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 254 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 254 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 13, y: 254 },
-        category: "DWD sequences",
-        title: "WIND PROFILER OBSERVATION, TEST FORMAT B",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 254 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 192 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: CONSTANT VALUES",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 221,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 221,
-            },
-            Descriptor {
-                f: 0,
-                x: 15,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 15,
-                y: 193,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 55,
-                y: 201,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 193 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: VALUES WITH NO FORECAST DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 10,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 55,
-                y: 202,
-            },
-            Descriptor {
-                f: 0,
-                x: 55,
-                y: 203,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 194 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: SURFACE PRESSURE/WIND/TEMPERATURE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor { f: 0, x: 11, y: 42 },
-            Descriptor {
-                f: 0,
-                x: 11,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 11,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 12, y: 6 },
-            Descriptor { f: 0, x: 12, y: 11 },
-            Descriptor { f: 0, x: 12, y: 12 },
-            Descriptor { f: 0, x: 12, y: 61 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 222,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 223,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 195 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: SURFACE HUMIDITY/PRECIPITATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 130 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 222,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 223,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 224,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 225,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 226,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 227,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 228,
-            },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 196 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: SURFACE RADIATION/CLOUD",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 0, x: 14, y: 12 },
-            Descriptor { f: 0, x: 14, y: 14 },
-            Descriptor {
-                f: 0,
-                x: 14,
-                y: 201,
-            },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor {
-                f: 0,
-                x: 14,
-                y: 202,
-            },
-            Descriptor { f: 0, x: 20, y: 10 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 202,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 229,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 197 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: VARIOUS OTHER SURFACE DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 2, y: 130 },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 193,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 194,
-            },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 195,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 196,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 197,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 198,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 199,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 198 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA IN A SPECIFIED PRESSURE LAYER",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 10, y: 3 },
-            Descriptor { f: 0, x: 11, y: 3 },
-            Descriptor { f: 0, x: 11, y: 4 },
-            Descriptor { f: 0, x: 11, y: 5 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 13, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 199 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA IN SPECIFIED PRESSURE LAYERS",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 198,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 198,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 198,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 198,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 198,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 198,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 200 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA OF ONE TIME STEP                       IMGRAM",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 194,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 195,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 196,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 197,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 203,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 199,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 201 },
-        category: "DWD sequences",
-        title: "METEOGRAM REPORT",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 192,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 4, y: 15 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 200,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 192 },
-        category: "DWD sequences",
-        title: "SURFACE STATION FORECAST: DATA OF ONE TIME STEP, SHORT RANGEIFOREC",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 205 },
-            Descriptor { f: 0, x: 20, y: 10 },
-            Descriptor { f: 3, x: 2, y: 194 },
-            Descriptor { f: 0, x: 20, y: 4 },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 3, x: 2, y: 203 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 12, y: 11 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 12, y: 12 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 201 },
-        category: "DWD sequences",
-        title: "SURFACE STATION FORECAST: REPORT, SHORT RANGE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor {
-                f: 3,
-                x: 53,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 54, y: 192 },
-        category: "DWD sequences",
-        title: "SUPPLEMENTARY INFORMATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 2, x: 5, y: 8 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 54, y: 193 },
-        category: "DWD sequences",
-        title: "SUPPLEMENTARY INFORMATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 2, x: 5, y: 8 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 192 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL DATA: AGRO (SOIL HUMIDITY)               IUSOIA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor {
-                f: 0,
-                x: 55,
-                y: 192,
-            },
-            Descriptor { f: 0, x: 7, y: 61 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 211,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 193 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL DATA: AGRO (SOIL TEMPERATURE DATA)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 7, y: 61 },
-            Descriptor { f: 0, x: 12, y: 30 },
-            Descriptor { f: 0, x: 7, y: 61 },
-            Descriptor { f: 0, x: 12, y: 30 },
-            Descriptor { f: 0, x: 7, y: 61 },
-            Descriptor { f: 0, x: 12, y: 30 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 194 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL DATA: AGRO (NORMAL METEOROLOGICAL DATA)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 12, y: 11 },
-            Descriptor { f: 0, x: 12, y: 12 },
-            Descriptor { f: 0, x: 12, y: 13 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 14, y: 31 },
-            Descriptor { f: 0, x: 14, y: 21 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 12, y: 4 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 195 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL DATA: AGRO (OTHER SPECIAL DATA)          IAGRO",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 193,
-            },
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 194,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 196 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL DATA: PHAEN DATA                         IPHAEN",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 55,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 55,
-                y: 193,
-            },
-            Descriptor {
-                f: 0,
-                x: 55,
-                y: 194,
-            },
-            Descriptor {
-                f: 0,
-                x: 55,
-                y: 195,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 197 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL DATA: PHYTOPAT DATA                      IPHYTO",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 211,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 201 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL REPORT: AGRO",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 1, x: 1, y: 48 },
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 192,
-            },
-            Descriptor { f: 1, x: 1, y: 7 },
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 195,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 202 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL REPORT: PHAEN",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 1, x: 1, y: 16 },
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 196,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 203 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL REPORT: PHYTOPAT",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 1, x: 1, y: 168 },
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 197,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 200 },
-        category: "DWD sequences",
-        title: "TECHNICAL DATA: ACTUAL HOUR/MINUTE OF OBSERVATION/LAUNCHING",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 214 },
-            Descriptor { f: 0, x: 4, y: 215 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 214 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: TOTAL SUNSHINE, PAST DAY",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 14, y: 31 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 215 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: TOTAL SUNSHINE, PAST HOUR",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 14, y: 31 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 216 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: GLOBAL RADIATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 14, y: 21 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 217 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: DIFFUSE SOLAR RADIATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 14, y: 23 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 218 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: LONG WAVE RADIATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 14, y: 2 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 227 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: CLASSES OF CLOUD BASES",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 197,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 198,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 228 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: SOIL TEMPERATURES/CLASSES OF CLOUD BASES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 7 },
-            Descriptor { f: 3, x: 2, y: 226 },
-            Descriptor { f: 1, x: 1, y: 8 },
-            Descriptor { f: 3, x: 2, y: 227 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 199,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 18, y: 193 },
-        category: "DWD sequences",
-        title: "RADIOACTIVITY DATA (GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 193,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 194,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 195,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 196,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 197,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 198,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 199,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 200,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 201,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 18, y: 201 },
-        category: "DWD sequences",
-        title: "RADIOACTIVITY OBSERVATION (GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 206 },
-            Descriptor { f: 3, x: 1, y: 207 },
-            Descriptor {
-                f: 3,
-                x: 18,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 192 },
-        category: "DWD sequences",
-        title: "ANTENNA CHARACTERISTICS (* SECOND PART OF WMO DEFINITION *)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 132 },
-            Descriptor { f: 0, x: 2, y: 133 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 51, y: 192 },
-        category: "DWD sequences",
-        title: "TRAJECTORY: LATITUDE/LONGITUDE/PRESSURE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 5, y: 2 },
-            Descriptor { f: 0, x: 6, y: 2 },
-            Descriptor { f: 0, x: 7, y: 4 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 51, y: 201 },
-        category: "DWD sequences",
-        title: "TRAJECTORY",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 0, x: 4, y: 15 },
-            Descriptor { f: 2, x: 5, y: 15 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 51,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 202 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: BASE AND TOP OF CONVECTIVE CLOUD",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 10, y: 4 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 205 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: SURF.PRESSURE/WIND/TEMPERATURE,SHORT VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor {
-                f: 0,
-                x: 11,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 11,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 12, y: 6 },
-            Descriptor { f: 0, x: 12, y: 11 },
-            Descriptor { f: 0, x: 12, y: 12 },
-            Descriptor { f: 0, x: 12, y: 61 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 222,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 206 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: SURFACE HUMIDITY/PRECIPITATION,SHORT VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 130 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 223,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 225,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 226,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 227,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 228,
-            },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 207 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: SURF.RADIATION/CLOUD/OTHERS, SHORT VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 0, x: 14, y: 12 },
-            Descriptor { f: 0, x: 14, y: 14 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 202,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 229,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 197,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 198,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 203,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 208 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA IN SPECIF.PRESSURE LAYER, SHORT VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 11, y: 3 },
-            Descriptor { f: 0, x: 11, y: 4 },
-            Descriptor { f: 0, x: 11, y: 5 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 13, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 209 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA IN SPECIF.PRESSURE LAYERS,SHORT VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 208,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 208,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 208,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 208,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 208,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 208,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 210 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA OF ONE TIME STEP, SHORT VERSION        IMGRAM",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 205,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 206,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 207,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 209,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 211 },
-        category: "DWD sequences",
-        title: "METEOGRAM REPORT, SHORT VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 55,
-                y: 201,
-            },
-            Descriptor { f: 0, x: 4, y: 15 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 210,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 193 },
-        category: "DWD sequences",
-        title: "SURFACE STATION FORECAST: DATA OF ONE TIME STEP,MEDIUM RANGE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 23 },
-            Descriptor { f: 0, x: 12, y: 11 },
-            Descriptor { f: 0, x: 12, y: 12 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 198,
-            },
-            Descriptor {
-                f: 0,
-                x: 14,
-                y: 198,
-            },
-            Descriptor { f: 3, x: 2, y: 194 },
-            Descriptor {
-                f: 0,
-                x: 11,
-                y: 198,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 202 },
-        category: "DWD sequences",
-        title: "SURFACE STATION FORECAST: REPORT, MEDIUM RANGE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 1, x: 1, y: 8 },
-            Descriptor {
-                f: 3,
-                x: 53,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 219 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: PRESENT WEATHER",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 221,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 222,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 223,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 220 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: RECENT WEATHER",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 221,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 222,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 223,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 229 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: WEATHER PHENOMENA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 3 },
-            Descriptor { f: 3, x: 2, y: 219 },
-            Descriptor { f: 1, x: 1, y: 3 },
-            Descriptor { f: 3, x: 2, y: 220 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 230 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: CLOUD INFORMATION IN METAR",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 13 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 232 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: METAR (METEOROLOGICAL PART),(FROM 1 JULY 1993)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 10, y: 52 },
-            Descriptor { f: 3, x: 2, y: 194 },
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 12, y: 6 },
-            Descriptor { f: 0, x: 20, y: 1 },
-            Descriptor { f: 0, x: 20, y: 3 },
-            Descriptor { f: 3, x: 2, y: 229 },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor { f: 3, x: 2, y: 230 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 195 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT: METAR (FROM 1 JULY 1993)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 201 },
-            Descriptor { f: 3, x: 2, y: 232 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 198 },
-        category: "DWD sequences",
-        title: "TECHNICAL DATA: METHOD OF MEASUREM.OF WET BULB/SEA SURF.TEMP",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 38 },
-            Descriptor { f: 0, x: 2, y: 39 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 199 },
-        category: "DWD sequences",
-        title: "TECHNICAL DATA: OBS.METHOD+HOUR OF READING OF EXTR.TEMPERATU",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 51 },
-            Descriptor { f: 0, x: 26, y: 1 },
-            Descriptor { f: 0, x: 26, y: 2 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 220 },
-        category: "DWD sequences",
-        title: "ERS PRODUCT HEADER, PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 3, x: 1, y: 23 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 221 },
-        category: "DWD sequences",
-        title: "RADAR PARAMETERS, PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 2, y: 127 },
-            Descriptor { f: 0, x: 1, y: 13 },
-            Descriptor { f: 2, x: 2, y: 126 },
-            Descriptor { f: 0, x: 7, y: 1 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 25, y: 10 },
-            Descriptor { f: 0, x: 21, y: 64 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 214 },
-        category: "DWD sequences",
-        title: "CLIMAT REPORT: CLIMAT, SECTION 1 TO 4 (FROM 2 NOV 1994)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor { f: 3, x: 1, y: 199 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 201,
-            },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 202,
-            },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 1, x: 2, y: 24 },
-            Descriptor { f: 0, x: 8, y: 52 },
-            Descriptor { f: 0, x: 8, y: 22 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 198,
-            },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 199,
-            },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 215 },
-        category: "DWD sequences",
-        title: "CLIMAT REPORT: CLIMAT, SECTION 5 (FROM 2 NOV 1994)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 211,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 1, x: 2, y: 5 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 192,
-            },
-            Descriptor { f: 0, x: 4, y: 202 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 11, y: 200 },
-        category: "DWD sequences",
-        title: "ACARS SUPPLEMENTAL REPORTED VARIABLES, PART 2 OF 311004",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 0, x: 11, y: 76 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 0, x: 33, y: 25 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 0, x: 33, y: 26 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 200 },
-        category: "DWD sequences",
-        title: "RADAR ALTIMETER PRODUCT, PART 2 OF 312022",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 21, y: 73 },
-            Descriptor { f: 3, x: 12, y: 42 },
-            Descriptor { f: 0, x: 21, y: 62 },
-            Descriptor { f: 0, x: 15, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 13, y: 192 },
-        category: "DWD sequences",
-        title: "RUN-LENGTH ENCODED ROW FOR PIXEL VALUE (SEC. P. OF 3 13 041)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 10, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 1, x: 4, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 6, y: 12 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 12 },
-            Descriptor { f: 0, x: 30, y: 1 },
-            Descriptor { f: 0, x: 6, y: 12 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 30, y: 1 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 192 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: MEAN VALUES (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 2, y: 51 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 193 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: ACCUMULATED VALUES (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 14, y: 32 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 194 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: MISSING DAYS, ACCUM.VALUES (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 51 },
-            Descriptor { f: 0, x: 8, y: 20 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 195 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: OTHER MONTHLY VALUES (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 193,
-            },
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 194,
-            },
-            Descriptor { f: 0, x: 13, y: 51 },
-            Descriptor { f: 0, x: 14, y: 33 },
-            Descriptor { f: 0, x: 4, y: 53 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 196 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: MEAN NORMAL VALUES (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 2, y: 51 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 197 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: MISSING DAYS, ACCUM.NORM.VALUES (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 51 },
-            Descriptor { f: 0, x: 8, y: 20 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 198 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: EXTREME VALUES, TEMPERATURE (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 12, y: 52 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 12, y: 53 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 12, y: 16 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 12, y: 17 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 199 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: EXTREME VALUES, PRECIPIT.+WIND (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 13, y: 52 },
-            Descriptor { f: 0, x: 2, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 11, y: 41 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 201 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: MONTHLY VALUES (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 192,
-            },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 195,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 202 },
-        category: "DWD sequences",
-        title: "CLIMAT DATA: NORMAL VALUES (FROM 2 NOV 94)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 196,
-            },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 193,
-            },
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 197,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 217 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: ADDITIONAL ONE LAYER DATA, LONG VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 20, y: 53 },
-            Descriptor { f: 0, x: 20, y: 52 },
-            Descriptor { f: 0, x: 20, y: 51 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 200,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 204,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 205,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 206,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 200,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 207,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 208,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 209,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 218 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA IN A SPEC.PRESSURE LAYER, LONG VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 10, y: 3 },
-            Descriptor { f: 0, x: 11, y: 3 },
-            Descriptor { f: 0, x: 11, y: 4 },
-            Descriptor { f: 2, x: 2, y: 130 },
-            Descriptor { f: 0, x: 11, y: 5 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 13, y: 1 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 230,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 219 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA IN SPECIF.PRESSURE LAYERS, LONG VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 220 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA OF ONE TIME STEP, LONG VERSION         IMGRAM",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 194,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 195,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 196,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 197,
-            },
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 0, x: 14, y: 12 },
-            Descriptor { f: 0, x: 14, y: 14 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 217,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 219,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 219,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 218,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 221 },
-        category: "DWD sequences",
-        title: "METEOGRAM REPORT, LONG VERSION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 192,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 4, y: 15 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 220,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 225 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: NEW REFERENCE VALUES, DM4MO, LM",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 3, y: 15 },
-            Descriptor { f: 0, x: 14, y: 12 },
-            Descriptor { f: 0, x: 14, y: 14 },
-            Descriptor {
-                f: 0,
-                x: 14,
-                y: 201,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 200,
-            },
-            Descriptor { f: 2, x: 3, y: 255 },
-            Descriptor { f: 2, x: 3, y: 20 },
-            Descriptor { f: 0, x: 11, y: 5 },
-            Descriptor { f: 2, x: 3, y: 255 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 226 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: ADDITIONAL ONE LAYER DATA (1), DM4MO, LM",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 201,
-            },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 202,
-            },
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 0, x: 14, y: 12 },
-            Descriptor { f: 0, x: 14, y: 14 },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 200,
-            },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 20, y: 53 },
-            Descriptor { f: 0, x: 20, y: 52 },
-            Descriptor { f: 0, x: 20, y: 51 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 200,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 227 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: ADDITIONAL ONE LAYER DATA (2), DM4MO, LM",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 204,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 205,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 206,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 207,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 208,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 209,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 228 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA IN A SPEC.PRESSURE LAYER, DM4MO, LM",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 10, y: 3 },
-            Descriptor { f: 0, x: 11, y: 3 },
-            Descriptor { f: 0, x: 11, y: 4 },
-            Descriptor { f: 2, x: 2, y: 130 },
-            Descriptor { f: 0, x: 11, y: 5 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 13, y: 1 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 230,
-            },
-            Descriptor { f: 0, x: 20, y: 10 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 229 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA IN SPECIF.PRESSURE LAYERS, DM4MO, LM",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 228,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 230 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: DATA OF ONE TIME STEP, DM4MO, LM            IMGRAM",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 194,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 195,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 196,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 197,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 203,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 226,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 227,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 229,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 229,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 229,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 229,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 231 },
-        category: "DWD sequences",
-        title: "METEOGRAM REPORT, DM4MO, LM",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 211 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 192,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 193,
-            },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 225,
-            },
-            Descriptor { f: 0, x: 4, y: 15 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 52,
-                y: 230,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 4, y: 200 },
-        category: "DWD sequences",
-        title: "GOES-I/M INFO, PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 6, y: 4 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-            Descriptor { f: 1, x: 3, y: 10 },
-            Descriptor { f: 0, x: 2, y: 163 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 12, y: 1 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 196 },
-        category: "DWD sequences",
-        title: "MAIN PART OF DATA FOR REPRESENTATION OF METAR/SPECI (PART 2)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 6 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 12, y: 3 },
-            Descriptor { f: 0, x: 10, y: 52 },
-            Descriptor { f: 0, x: 20, y: 9 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 197 },
-        category: "DWD sequences",
-        title: "TREND-TYPE LANDING FORECAST (PART 2)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 20, y: 9 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 20, y: 1 },
-            Descriptor { f: 3, x: 7, y: 14 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 221 },
-        category: "DWD sequences",
-        title: "ATOVS FIELD OF VIEW VARIABLES, PART 2 OF 310011",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 48 },
-            Descriptor { f: 0, x: 5, y: 40 },
-            Descriptor { f: 0, x: 25, y: 75 },
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 0, x: 5, y: 41 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 0, x: 25, y: 70 },
-            Descriptor { f: 0, x: 33, y: 30 },
-            Descriptor { f: 0, x: 33, y: 31 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 222 },
-        category: "DWD sequences",
-        title: "ATOVS FIELD OF VIEW VARIABLES, PART 3 OF 310011",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 2, x: 2, y: 126 },
-            Descriptor { f: 0, x: 7, y: 1 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 7, y: 25 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 223 },
-        category: "DWD sequences",
-        title: "ATOVS FIELD OF VIEW VARIABLES, PART 4 OF 310011",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 5, y: 22 },
-            Descriptor { f: 0, x: 33, y: 33 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 231 },
-        category: "DWD sequences",
-        title: "AVHRR (GAC) REPORT, PART 2 OF 310013",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 1, x: 6, y: 3 },
-            Descriptor { f: 0, x: 2, y: 150 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 0, x: 8, y: 72 },
-            Descriptor { f: 0, x: 14, y: 27 },
-            Descriptor { f: 0, x: 8, y: 72 },
-            Descriptor { f: 0, x: 14, y: 27 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 232 },
-        category: "DWD sequences",
-        title: "AVHRR (GAC) REPORT, PART 3 OF 310013",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 132 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 2, y: 150 },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 233,
-            },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 233,
-            },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 233 },
-        category: "DWD sequences",
-        title: "AVHRR (GAC) REPORT, PART 4 OF 310013",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 0, x: 8, y: 72 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 8, y: 72 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 2, y: 150 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 0, x: 8, y: 72 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 8, y: 72 },
-            Descriptor { f: 0, x: 12, y: 63 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 210 },
-        category: "DWD sequences",
-        title: "WAVE SCATTEROMETER ENHANCED PRODUCT ..., PART 2 OF 312025",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 6, y: 12 },
-            Descriptor { f: 2, x: 1, y: 129 },
-            Descriptor { f: 0, x: 6, y: 30 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 1, x: 2, y: 12 },
-            Descriptor { f: 0, x: 5, y: 30 },
-            Descriptor { f: 0, x: 21, y: 75 },
-            Descriptor { f: 0, x: 21, y: 66 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 16, y: 192 },
-        category: "DWD sequences",
-        title: "CLOUD (PART 2)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 12 },
-            Descriptor { f: 0, x: 8, y: 7 },
-            Descriptor { f: 0, x: 8, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 16, y: 200 },
-        category: "DWD sequences",
-        title: "VOLCANO ERUPTING (PART 2)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 0, x: 20, y: 90 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 8, y: 7 },
-            Descriptor { f: 0, x: 8, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 52, y: 203 },
-        category: "DWD sequences",
-        title: "METEOGRAM DATA: CLOUD AND WEATHER",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 231,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 232,
-            },
-            Descriptor { f: 0, x: 20, y: 4 },
-            Descriptor { f: 0, x: 20, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 195 },
-        category: "DWD sequences",
-        title: "DATE, TIME, LAT, LONG, HEIGHT OR ALTITUDE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 7, y: 2 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 196 },
-        category: "DWD sequences",
-        title: "DATE, TIME, LAT, LONG",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 3, x: 1, y: 21 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 208 },
-        category: "DWD sequences",
-        title: "TECHNICAL DATA: SYNOPTIC SURFACE OBSERVATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 198 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 3, x: 1, y: 201 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 212 },
-        category: "DWD sequences",
-        title: "QUALITY DATA/LAT.+LONG.+DATE+TIME WITH ERROR FLAGS",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 192 },
-            Descriptor { f: 0, x: 2, y: 193 },
-            Descriptor { f: 0, x: 2, y: 201 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 213 },
-        category: "DWD sequences",
-        title: "DWD REPORT HEAD, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 212 },
-            Descriptor { f: 0, x: 1, y: 3 },
-            Descriptor { f: 3, x: 1, y: 194 },
-            Descriptor { f: 0, x: 1, y: 192 },
-            Descriptor { f: 3, x: 1, y: 3 },
-            Descriptor { f: 0, x: 1, y: 5 },
-            Descriptor { f: 0, x: 1, y: 6 },
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 1, y: 31 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 223 },
-        category: "DWD sequences",
-        title: "RADIOSONDE FULL HEADER INFORMATION, PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 25, y: 61 },
-            Descriptor { f: 0, x: 25, y: 68 },
-            Descriptor { f: 0, x: 1, y: 82 },
-            Descriptor { f: 0, x: 1, y: 83 },
-            Descriptor { f: 0, x: 1, y: 81 },
-            Descriptor { f: 0, x: 2, y: 67 },
-            Descriptor { f: 0, x: 2, y: 66 },
-            Descriptor { f: 0, x: 2, y: 14 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 224 },
-        category: "DWD sequences",
-        title: "RADIOSONDE FULL HEADER INFORMATION, PART 3",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 25, y: 67 },
-            Descriptor { f: 0, x: 25, y: 65 },
-            Descriptor { f: 0, x: 25, y: 66 },
-            Descriptor { f: 0, x: 2, y: 95 },
-            Descriptor { f: 0, x: 2, y: 96 },
-            Descriptor { f: 0, x: 2, y: 97 },
-            Descriptor { f: 0, x: 2, y: 16 },
-            Descriptor { f: 0, x: 2, y: 83 },
-            Descriptor { f: 0, x: 2, y: 80 },
-            Descriptor { f: 0, x: 2, y: 81 },
-            Descriptor { f: 0, x: 1, y: 93 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 225 },
-        category: "DWD sequences",
-        title: "RADIOSONDE FULL HEADER INFORMATION, PART 4",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 84 },
-            Descriptor { f: 0, x: 2, y: 85 },
-            Descriptor { f: 0, x: 2, y: 86 },
-            Descriptor { f: 0, x: 2, y: 82 },
-            Descriptor { f: 0, x: 8, y: 41 },
-            Descriptor { f: 3, x: 1, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 231 },
-        category: "DWD sequences",
-        title: "TECHNICAL DATA: SPECIAL VERIFICATION DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 231 },
-            Descriptor { f: 0, x: 5, y: 231 },
-            Descriptor { f: 0, x: 6, y: 231 },
-            Descriptor { f: 0, x: 5, y: 232 },
-            Descriptor { f: 0, x: 6, y: 232 },
-            Descriptor { f: 0, x: 7, y: 231 },
-            Descriptor { f: 0, x: 8, y: 231 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 198 },
-        category: "DWD sequences",
-        title: "SHIP INSTANTANEOUS DATA FROM VOS - PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 3, x: 2, y: 4 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 2, y: 5 },
-            Descriptor { f: 0, x: 8, y: 2 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 3, x: 2, y: 55 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 199 },
-        category: "DWD sequences",
-        title: "SHIP INSTANTANEOUS DATA FROM VOS - PART 3",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 3, x: 2, y: 56 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 3, x: 2, y: 21 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 3, x: 2, y: 24 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 210 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: CLOUD LAYERS WITH BASE BELOW STATION LEVEL",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 2 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 12 },
-            Descriptor { f: 0, x: 20, y: 14 },
-            Descriptor { f: 0, x: 20, y: 17 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 250 },
-        category: "DWD sequences",
-        title: "RADIOSONDE SURFACE OBSERVATION - PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 41 },
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 2, y: 115 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 2, y: 115 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 2, y: 115 },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 251 },
-        category: "DWD sequences",
-        title: "RADIOSONDE SURFACE OBSERVATION - PART 3",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 115 },
-            Descriptor { f: 1, x: 2, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-            Descriptor { f: 0, x: 4, y: 24 },
-            Descriptor { f: 0, x: 2, y: 115 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 103,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 102,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 252 },
-        category: "DWD sequences",
-        title: "RADIOSONDE SURFACE OBSERVATION - PART 4",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 3 },
-            Descriptor { f: 0, x: 20, y: 12 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 13 },
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor { f: 0, x: 20, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 194 },
-        category: "DWD sequences",
-        title: "PART OF RADIOSONDE DURATION OF FLIGHT AND TERMINATION INFO",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 25, y: 69 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 200 },
-        category: "DWD sequences",
-        title: "SURF.SYNOPT.REP.: SYNOP/SHIP, SECT.1 TO 4, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 208 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 2, y: 221 },
-            Descriptor { f: 3, x: 6, y: 192 },
-            Descriptor { f: 3, x: 2, y: 223 },
-            Descriptor { f: 3, x: 2, y: 224 },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor { f: 3, x: 2, y: 210 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 201 },
-        category: "DWD sequences",
-        title: "SURF.SYNOPT.REP.: METAR, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 201 },
-            Descriptor { f: 3, x: 2, y: 232 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 202 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT:SYNOP SECT.5,PART 1,FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 12, y: 61 },
-            Descriptor { f: 3, x: 2, y: 205 },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 12, y: 4 },
-            Descriptor { f: 0, x: 11, y: 12 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 14, y: 31 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 203,
-            },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 203 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT:SYNOP SECT.5,PART 2,FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 11, y: 12 },
-            Descriptor { f: 3, x: 2, y: 206 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 194,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 195,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 196,
-            },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 20, y: 13 },
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 0, x: 11, y: 42 },
-            Descriptor { f: 1, x: 1, y: 7 },
-            Descriptor { f: 3, x: 2, y: 226 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 204 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT:SYNOP SECT.5,PART 3,FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 203,
-            },
-            Descriptor { f: 3, x: 2, y: 240 },
-            Descriptor { f: 3, x: 2, y: 197 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 203,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 204,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 205,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 206,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 205 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT:SYNOP SECT.5,PART 4,FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 12, y: 11 },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 12, y: 12 },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 194,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 195,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 234,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 235,
-            },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 236,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 206 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT: SYNOP SECTION 5, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 7, y: 202 },
-            Descriptor { f: 3, x: 7, y: 203 },
-            Descriptor { f: 3, x: 7, y: 204 },
-            Descriptor { f: 3, x: 7, y: 205 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 216 },
-        category: "DWD sequences",
-        title: "CLIMAT REPORT: CLIMAT SHIP, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 2, y: 237 },
-            Descriptor { f: 3, x: 2, y: 238 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 217 },
-        category: "DWD sequences",
-        title: "CLIMAT REPORT: CLIMAT,SECTION 1-4, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 199 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 201,
-            },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 202,
-            },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 1, x: 2, y: 24 },
-            Descriptor { f: 0, x: 8, y: 52 },
-            Descriptor { f: 0, x: 8, y: 22 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 198,
-            },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 199,
-            },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 218 },
-        category: "DWD sequences",
-        title: "CLIMAT REPORT: CLIMAT,SECTION 5, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 211,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 1, x: 2, y: 5 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 192,
-            },
-            Descriptor { f: 0, x: 4, y: 202 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 222 },
-        category: "DWD sequences",
-        title: "GROUND-BASED GNSS DATA (PART 2 OF 307022)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 6, y: 25 },
-            Descriptor { f: 0, x: 2, y: 20 },
-            Descriptor { f: 0, x: 1, y: 50 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 7, y: 21 },
-            Descriptor { f: 0, x: 15, y: 31 },
-            Descriptor { f: 0, x: 15, y: 32 },
-            Descriptor { f: 0, x: 8, y: 60 },
-            Descriptor { f: 0, x: 15, y: 33 },
-            Descriptor { f: 0, x: 15, y: 34 },
-            Descriptor { f: 0, x: 8, y: 60 },
-            Descriptor { f: 3, x: 7, y: 223 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 223 },
-        category: "DWD sequences",
-        title: "GROUND-BASED GNSS DATA (PART 3 OF 307022)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 15, y: 33 },
-            Descriptor { f: 0, x: 15, y: 34 },
-            Descriptor { f: 0, x: 15, y: 35 },
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 13, y: 16 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 15, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 8, y: 192 },
-        category: "DWD sequences",
-        title: "PAOB DATA, FROM  MAY 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 222 },
-        category: "DWD sequences",
-        title: "VERT.SOUNDING REP.: PILOT/TEMP(SHIP) PART A, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 3, x: 1, y: 202 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 1, x: 1, y: 16 },
-            Descriptor { f: 3, x: 3, y: 193 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 223 },
-        category: "DWD sequences",
-        title: "VERT.SOUNDING REP.:PILOT/TEMP(SHIP) PART B/D,FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 3, x: 1, y: 202 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 192 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 224 },
-        category: "DWD sequences",
-        title: "VERT.SOUNDING REP.:PILOT/TEMP (SHIP) PART C, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 3, x: 1, y: 202 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 1, x: 1, y: 11 },
-            Descriptor { f: 3, x: 3, y: 193 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 225 },
-        category: "DWD sequences",
-        title: "RAW PTU - PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-            Descriptor { f: 0, x: 13, y: 9 },
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-            Descriptor { f: 0, x: 2, y: 13 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 226 },
-        category: "DWD sequences",
-        title: "RAW GPS - PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-            Descriptor { f: 0, x: 7, y: 7 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 227 },
-        category: "DWD sequences",
-        title: "RAW GPS - PART 3",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 11, y: 3 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-            Descriptor { f: 0, x: 11, y: 4 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 228 },
-        category: "DWD sequences",
-        title: "PROCESSED PTU - PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 1, x: 4, y: 2 },
-            Descriptor { f: 0, x: 25, y: 69 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 229 },
-        category: "DWD sequences",
-        title: "PROCESSED PTU - PART 3",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 4, y: 2 },
-            Descriptor { f: 0, x: 2, y: 13 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 103,
-            },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-            Descriptor { f: 0, x: 10, y: 9 },
-            Descriptor { f: 0, x: 33, y: 35 },
-            Descriptor { f: 0, x: 33, y: 15 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 230 },
-        category: "DWD sequences",
-        title: "PART OF  STANDARD AND SIGNIFICANT LEVELS 309066",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 25, y: 69 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 195 },
-        category: "DWD sequences",
-        title: "SATELLITE: IDENTIFICATION/ZENITH ANGLE/HEIGHT/WIND SEQUENCE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 72 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor { f: 0, x: 10, y: 2 },
-            Descriptor { f: 3, x: 3, y: 41 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 202 },
-        category: "DWD sequences",
-        title: "SATEL.VERTIC.SOUND.REPORT: SATEM, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 204 },
-            Descriptor { f: 3, x: 4, y: 192 },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 192,
-            },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 193,
-            },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 194,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 224 },
-        category: "DWD sequences",
-        title: "GEOSTATIONARY MULTI-CHANNEL SATELLITE DATA- PART 2 OF 310023",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 5, y: 2 },
-            Descriptor { f: 0, x: 2, y: 152 },
-            Descriptor { f: 0, x: 2, y: 24 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 1, x: 1, y: 12 },
-            Descriptor { f: 3, x: 4, y: 33 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 225 },
-        category: "DWD sequences",
-        title: "GEOSTATIONARY THREE-CHANNEL SATELLITE DATA- PART 2 OF 310024",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 5, y: 2 },
-            Descriptor { f: 0, x: 2, y: 152 },
-            Descriptor { f: 0, x: 2, y: 24 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 1, x: 1, y: 3 },
-            Descriptor { f: 3, x: 4, y: 33 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 236 },
-        category: "DWD sequences",
-        title: "HSB REPORT - PART 2 OF 310050",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 10, y: 52 },
-            Descriptor { f: 1, x: 1, y: 5 },
-            Descriptor { f: 3, x: 10, y: 53 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 237 },
-        category: "DWD sequences",
-        title: "FOR AIRS REPORT - PART 2 OF 310051",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 25 },
-            Descriptor { f: 0, x: 5, y: 22 },
-            Descriptor { f: 1, x: 2, y: 9 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 238 },
-        category: "DWD sequences",
-        title: "CANCEL SCALE AND DATE WIDTH - PART 2 OF 310054",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 240 },
-        category: "DWD sequences",
-        title: "SSMIS - PART 1 (ID, SCAN START, TIME)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 241 },
-        category: "DWD sequences",
-        title: "SSMIS - PART 2 (MILLISEC,SCAN NO,SCENE NO,LALO)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 1, y: 132 },
-            Descriptor { f: 0, x: 5, y: 41 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 1, y: 129 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 3, x: 1, y: 23 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 242 },
-        category: "DWD sequences",
-        title: "SSMIS - PART 3 (FLAGS, .. ,EPHEMERIS DATA)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 13, y: 40 },
-            Descriptor { f: 0, x: 20, y: 29 },
-            Descriptor { f: 1, x: 4, y: 24 },
-            Descriptor { f: 0, x: 5, y: 42 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 163,
-            },
-            Descriptor { f: 0, x: 21, y: 83 },
-            Descriptor { f: 0, x: 21, y: 84 },
-            Descriptor { f: 1, x: 1, y: 3 },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 245,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 243 },
-        category: "DWD sequences",
-        title: "SSMIS - PART 4 (ORBIT)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 0, x: 5, y: 40 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 244 },
-        category: "DWD sequences",
-        title: "SSMIS - PART 5",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 3 },
-            Descriptor { f: 0, x: 12, y: 70 },
-            Descriptor { f: 0, x: 25, y: 54 },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor { f: 0, x: 25, y: 55 },
-            Descriptor { f: 0, x: 8, y: 7 },
-            Descriptor { f: 1, x: 3, y: 28 },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 0, x: 2, y: 111 },
-            Descriptor { f: 0, x: 5, y: 21 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 245 },
-        category: "DWD sequences",
-        title: "SSMIS - EPHEMERIS DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 2, x: 1, y: 142 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 26 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 7, y: 1 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 11, y: 193 },
-        category: "DWD sequences",
-        title: "SINGLE LEV.REP.:CODAR/AIREP/ASDAR/SATOB(SECT.2OR3),FR.3APR01",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 203 },
-            Descriptor { f: 3, x: 1, y: 204 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 3, y: 192 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor {
-                f: 3,
-                x: 54,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 11, y: 205 },
-        category: "DWD sequences",
-        title: "STANDARD AMDAR REPORT, PART 2 OF 311005",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-            Descriptor { f: 0, x: 33, y: 25 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 11, y: 210 },
-        category: "DWD sequences",
-        title: "EXTREM WIND DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 25 },
-            Descriptor { f: 0, x: 11, y: 16 },
-            Descriptor { f: 0, x: 11, y: 17 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 194 },
-        category: "DWD sequences",
-        title: "AATSR SEA SURFACE TEMPERATURES - PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 180,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 181,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 182,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 183,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 184,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 185,
-            },
-            Descriptor { f: 0, x: 2, y: 174 },
-            Descriptor { f: 0, x: 21, y: 86 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 186,
-            },
-            Descriptor { f: 0, x: 21, y: 87 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 187,
-            },
-            Descriptor { f: 0, x: 33, y: 43 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 201 },
-        category: "DWD sequences",
-        title: "IDENTIFICATION, ORBIT NO - RA2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 2, y: 19 },
-            Descriptor { f: 0, x: 1, y: 96 },
-            Descriptor { f: 0, x: 25, y: 61 },
-            Descriptor { f: 0, x: 5, y: 40 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 202 },
-        category: "DWD sequences",
-        title: "PROCESSING FLAGS AND QUALITIES, HARDWARE CONFIGURATIONS",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 120,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 121,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 124,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 125,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 122,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 123,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 203 },
-        category: "DWD sequences",
-        title: "RA2 INFORMATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 119 },
-            Descriptor { f: 0, x: 33, y: 47 },
-            Descriptor { f: 0, x: 10, y: 81 },
-            Descriptor { f: 0, x: 10, y: 82 },
-            Descriptor { f: 0, x: 10, y: 83 },
-            Descriptor { f: 0, x: 10, y: 84 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 204 },
-        category: "DWD sequences",
-        title: "RA2 PERCENTAGES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 116 },
-            Descriptor { f: 0, x: 2, y: 117 },
-            Descriptor { f: 0, x: 2, y: 118 },
-            Descriptor { f: 0, x: 2, y: 156 },
-            Descriptor { f: 0, x: 2, y: 157 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 205 },
-        category: "DWD sequences",
-        title: "RA2 INFORMATION KU AND S BAND",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 150,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 151,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 152,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 153,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 154,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 155,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 156,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 157,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 158,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 159,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 206 },
-        category: "DWD sequences",
-        title: "RA2 CORRECTED COEFFICIENTS",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 137,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 138,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 139,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 140,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 141,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 142,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 207 },
-        category: "DWD sequences",
-        title: "RA2 SEA SURFACE AND TIDE INFORMATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 10, y: 85 },
-            Descriptor { f: 0, x: 10, y: 86 },
-            Descriptor { f: 0, x: 10, y: 87 },
-            Descriptor { f: 0, x: 10, y: 88 },
-            Descriptor { f: 0, x: 10, y: 89 },
-            Descriptor { f: 0, x: 10, y: 90 },
-            Descriptor { f: 0, x: 10, y: 91 },
-            Descriptor { f: 0, x: 10, y: 92 },
-            Descriptor { f: 0, x: 10, y: 93 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 208 },
-        category: "DWD sequences",
-        title: "RA2 CORRECTIONS",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 126,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 127,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 128,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 129,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 130,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 131,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 132,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 133,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 134,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 135,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 136,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 137,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 209 },
-        category: "DWD sequences",
-        title: "RA2 DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 13, y: 96 },
-            Descriptor { f: 0, x: 13, y: 97 },
-            Descriptor { f: 0, x: 11, y: 95 },
-            Descriptor { f: 0, x: 11, y: 96 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 188,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 189,
-            },
-            Descriptor { f: 0, x: 2, y: 158 },
-            Descriptor { f: 0, x: 2, y: 159 },
-            Descriptor { f: 0, x: 33, y: 52 },
-            Descriptor { f: 0, x: 33, y: 53 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 143,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 144,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 211 },
-        category: "DWD sequences",
-        title: "OCEAN WAVE SPECTRA - PART 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 12 },
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 0, x: 1, y: 13 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 10, y: 32 },
-            Descriptor { f: 0, x: 10, y: 33 },
-            Descriptor { f: 0, x: 10, y: 34 },
-            Descriptor { f: 0, x: 7, y: 2 },
-            Descriptor { f: 0, x: 8, y: 12 },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 110,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 111,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 102,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 212 },
-        category: "DWD sequences",
-        title: "OCEAN WAVE SPECTRA - PART 3",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 104 },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 103,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 104,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 105,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 106,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 107,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 108,
-            },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 160,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 138,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 213 },
-        category: "DWD sequences",
-        title: "OCEAN WAVE SPECTRA - PART 4",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 130 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 22, y: 21 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 33, y: 48 },
-            Descriptor { f: 0, x: 33, y: 49 },
-            Descriptor { f: 0, x: 2, y: 26 },
-            Descriptor { f: 0, x: 2, y: 27 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 214 },
-        category: "DWD sequences",
-        title: "OCEAN WAVE SPECTRA - PART 5",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 130,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 131,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 132,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 133,
-            },
-            Descriptor { f: 0, x: 25, y: 14 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 215 },
-        category: "DWD sequences",
-        title: "OCEAN WAVE SPECTRA - PART 6",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 6, y: 36 },
-            Descriptor { f: 0, x: 5, y: 30 },
-            Descriptor { f: 1, x: 4, y: 24 },
-            Descriptor { f: 2, x: 1, y: 130 },
-            Descriptor { f: 0, x: 6, y: 30 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 161,
-            },
-            Descriptor { f: 0, x: 33, y: 44 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 216 },
-        category: "DWD sequences",
-        title: "ASAR - PART 2 OF 312051",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 1, y: 12 },
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 0, x: 1, y: 13 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 10, y: 32 },
-            Descriptor { f: 0, x: 10, y: 33 },
-            Descriptor { f: 0, x: 10, y: 34 },
-            Descriptor { f: 0, x: 7, y: 2 },
-            Descriptor { f: 0, x: 8, y: 12 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 217 },
-        category: "DWD sequences",
-        title: "ASAR - PART 3 OF 312051",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 110,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 111,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 102,
-            },
-            Descriptor { f: 0, x: 2, y: 104 },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 103,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 104,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 105,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 106,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 107,
-            },
-            Descriptor {
-                f: 0,
-                x: 25,
-                y: 108,
-            },
-            Descriptor { f: 0, x: 2, y: 111 },
-            Descriptor { f: 0, x: 2, y: 121 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 218 },
-        category: "DWD sequences",
-        title: "ASAR - PART 4 OF 312051",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 26 },
-            Descriptor { f: 0, x: 2, y: 27 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 130,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 131,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 132,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 133,
-            },
-            Descriptor { f: 0, x: 21, y: 64 },
-            Descriptor { f: 0, x: 25, y: 14 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 134,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 219 },
-        category: "DWD sequences",
-        title: "ASAR - PART 5 OF 312051",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 7, y: 18 },
-            Descriptor { f: 0, x: 5, y: 30 },
-            Descriptor { f: 1, x: 5, y: 24 },
-            Descriptor { f: 2, x: 1, y: 130 },
-            Descriptor { f: 0, x: 6, y: 30 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 135,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 136,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 222 },
-        category: "DWD sequences",
-        title: "SATEL.SINGLE LEVEL REP.:SATOB SECT.4(SURF.TEMPER.),FR.3APR01",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 204 },
-            Descriptor { f: 3, x: 4, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 223 },
-        category: "DWD sequences",
-        title: "SATEL.SINGLE LEVEL REP.:SATOB SECT.5,6,7,FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 204 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 10, y: 2 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 0, x: 20, y: 10 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 226 },
-        category: "DWD sequences",
-        title: "SATEL.SINGLE LEVEL OBSERV.: QUIKSCAT DATA (LAST PART)",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 110,
-            },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 21, y: 27 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 111,
-            },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 21, y: 27 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 112,
-            },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 21, y: 27 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 113,
-            },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 21, y: 27 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 227 },
-        category: "DWD sequences",
-        title: "SATEL.SINGLE LEVEL OBSERV.: SEAWINDS QUIKSCAT DATA, HEAD",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 46 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 3, x: 1, y: 23 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 228 },
-        category: "DWD sequences",
-        title: "SATEL.SINGLE LEVEL OBSERV.: SEAWINDS QUIKSCAT (LAST PART)",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 110,
-            },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 21, y: 28 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 111,
-            },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 21, y: 28 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 112,
-            },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 21, y: 28 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 113,
-            },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 3, x: 21, y: 28 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 230 },
-        category: "DWD sequences",
-        title: "SATEL.SINGLE LEVEL OBSERV.: WIND SPEED (PART 2)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 11, y: 53 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 104,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 250 },
-        category: "DWD sequences",
-        title: "MERIS - PART 2 OF 312050",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 7, y: 25 },
-            Descriptor { f: 0, x: 5, y: 22 },
-            Descriptor { f: 0, x: 10, y: 80 },
-            Descriptor { f: 0, x: 27, y: 80 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 13, y: 93 },
-            Descriptor { f: 0, x: 8, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 251 },
-        category: "DWD sequences",
-        title: "MERIS - PART 3 OF 312050",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 13, y: 95 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 16, y: 201 },
-        category: "DWD sequences",
-        title: "VOLCANIC ASH SIGMET - PART 2 OF 316034",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 16, y: 31 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 3, x: 16, y: 32 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 16, y: 33 },
-            Descriptor { f: 0, x: 8, y: 11 },
-            Descriptor { f: 0, x: 8, y: 79 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 16, y: 222 },
-        category: "DWD sequences",
-        title: "FORECAST DATA - PART 2 OF 316222",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 14 },
-            Descriptor { f: 0, x: 8, y: 5 },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 0, x: 19, y: 5 },
-            Descriptor { f: 0, x: 19, y: 6 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 11, y: 41 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 16, y: 223 },
-        category: "DWD sequences",
-        title: "FORECAST DATA - PART 3 OF 316222",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 75 },
-            Descriptor { f: 0, x: 11, y: 40 },
-            Descriptor { f: 0, x: 19, y: 8 },
-            Descriptor { f: 1, x: 5, y: 4 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 1, x: 2, y: 2 },
-            Descriptor { f: 0, x: 19, y: 3 },
-            Descriptor { f: 0, x: 19, y: 4 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 18, y: 202 },
-        category: "DWD sequences",
-        title: "RADIOACTIVITY OBSERVATION (GERMAN NATIONAL), FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 206 },
-            Descriptor { f: 3, x: 1, y: 207 },
-            Descriptor {
-                f: 3,
-                x: 18,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 221 },
-        category: "DWD sequences",
-        title: "BASIC INFORMATION ON WIND PROFILER/RASS (PART 2)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 25, y: 1 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 225 },
-        category: "DWD sequences",
-        title: "RASS-MODE: RAW-DATA RASS (PART 2)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 21, y: 92 },
-            Descriptor { f: 0, x: 21, y: 30 },
-            Descriptor { f: 0, x: 25, y: 92 },
-            Descriptor { f: 2, x: 1, y: 129 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 21, y: 17 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 227 },
-        category: "DWD sequences",
-        title: "RADAR REPORT SEQUENCES: TECHNICAL DATA (PART 2)",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 106,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 107,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 114,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 115,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 116,
-            },
-            Descriptor { f: 0, x: 8, y: 18 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 117,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 51, y: 202 },
-        category: "DWD sequences",
-        title: "TRAJECTORY, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 0, x: 4, y: 15 },
-            Descriptor { f: 2, x: 5, y: 15 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 51,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 1 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF SURF.SYNOPT.REP.FROM ANALYSIS/FORCAST,VALUES1",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor { f: 0, x: 10, y: 61 },
-            Descriptor { f: 3, x: 2, y: 193 },
-            Descriptor { f: 3, x: 2, y: 194 },
-            Descriptor { f: 3, x: 2, y: 201 },
-            Descriptor { f: 0, x: 20, y: 1 },
-            Descriptor { f: 0, x: 20, y: 3 },
-            Descriptor { f: 3, x: 2, y: 212 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 233,
-            },
-            Descriptor { f: 3, x: 2, y: 195 },
-            Descriptor { f: 3, x: 2, y: 202 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 2 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF SURF.SYNOPT.REP.FROM ANALYSIS/FORCAST,VALUES2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 53, y: 1 },
-            Descriptor { f: 1, x: 1, y: 3 },
-            Descriptor { f: 3, x: 2, y: 203 },
-            Descriptor { f: 0, x: 13, y: 13 },
-            Descriptor { f: 3, x: 2, y: 216 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 3 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF REPORT FROM ANALYSIS/FORCAST,VALUES3",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 102,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 103,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 104,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 106,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 4 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF SURF.SYNOPT.REP.FROM ANALYSIS/FORCAST,VALUES4",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 107,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 108,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 109,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 110,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 111,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 112,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 113,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 114,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 115,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 116,
-            },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 117,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 5 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF SURF.SYNOPT.REP.FROM ANALYSIS/FORCAST,VALUES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 192 },
-            Descriptor { f: 3, x: 53, y: 3 },
-            Descriptor { f: 3, x: 53, y: 4 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 6 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF REPORT FROM ANALYSIS/FORCAST,Q-FLAGS1",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 53, y: 62 },
-            Descriptor { f: 0, x: 53, y: 63 },
-            Descriptor { f: 0, x: 53, y: 64 },
-            Descriptor { f: 0, x: 53, y: 66 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 7 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF SURF.SYNOPT.REP.FROM ANALYSIS/FORCAST,Q-FLAG1",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 53, y: 67 },
-            Descriptor { f: 0, x: 53, y: 68 },
-            Descriptor { f: 0, x: 53, y: 69 },
-            Descriptor { f: 0, x: 53, y: 70 },
-            Descriptor { f: 0, x: 53, y: 71 },
-            Descriptor { f: 0, x: 53, y: 72 },
-            Descriptor { f: 0, x: 53, y: 73 },
-            Descriptor { f: 0, x: 53, y: 74 },
-            Descriptor { f: 0, x: 53, y: 75 },
-            Descriptor { f: 0, x: 53, y: 76 },
-            Descriptor { f: 0, x: 53, y: 77 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 8 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF SURFACE SYNOPTIC REPORT FROM ANALYSIS/FORCAST",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 208 },
-            Descriptor { f: 3, x: 1, y: 231 },
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 53, y: 2 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor { f: 1, x: 1, y: 10 },
-            Descriptor { f: 3, x: 53, y: 5 },
-            Descriptor { f: 3, x: 53, y: 6 },
-            Descriptor { f: 3, x: 53, y: 7 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 11 },
-        category: "DWD sequences",
-        title: "DEVIATION OF UPPER AIR REPORT FROM ANALYSIS/FORECAST, VALUES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 192 },
-            Descriptor {
-                f: 0,
-                x: 53,
-                y: 101,
-            },
-            Descriptor { f: 3, x: 53, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 12 },
-        category: "DWD sequences",
-        title: "DEVIATION OF UPPER AIR REPORT FROM ANALYSIS/FORECAST, LEVEL",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 4, y: 5 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 3, y: 192 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor { f: 1, x: 1, y: 10 },
-            Descriptor { f: 3, x: 53, y: 11 },
-            Descriptor { f: 0, x: 53, y: 61 },
-            Descriptor { f: 3, x: 53, y: 6 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 13 },
-        category: "DWD sequences",
-        title: "DEVIATIONS OF UPPER AIR REPORT FROM ANALYSIS/FORECAST",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 3, x: 1, y: 202 },
-            Descriptor { f: 3, x: 1, y: 231 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 53, y: 12 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 211 },
-        category: "DWD sequences",
-        title: "SURFACE STATION FORECAST:REPORT,SHORT RANGE,FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor {
-                f: 3,
-                x: 53,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 53, y: 212 },
-        category: "DWD sequences",
-        title: "SURFACE STATION FORECAST:REPORT,MEDIUM RANGE,FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 1, x: 1, y: 8 },
-            Descriptor {
-                f: 3,
-                x: 53,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 211 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL REPORT: AGRO, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 1, x: 1, y: 48 },
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 192,
-            },
-            Descriptor { f: 1, x: 1, y: 7 },
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 195,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 212 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL REPORT: PHAEN, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 1, x: 1, y: 16 },
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 196,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 55, y: 213 },
-        category: "DWD sequences",
-        title: "AGROMETEOROLOGICAL REPORT: PHYTOPAT, FROM 3 APR 2001",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 213 },
-            Descriptor { f: 1, x: 1, y: 168 },
-            Descriptor {
-                f: 3,
-                x: 55,
-                y: 197,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 192 },
-        category: "DWD sequences",
-        title: "CONTINUOUS WEATER TREND",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 193 },
-        category: "DWD sequences",
-        title: "NOTES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 194 },
-        category: "DWD sequences",
-        title: "DANGEROUS WEATHER",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 194,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 195 },
-        category: "DWD sequences",
-        title: "WINTER SPORTS OBSERVATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 195,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 196 },
-        category: "DWD sequences",
-        title: "ORNITHOLOGICAL OBSERVATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 196,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 197 },
-        category: "DWD sequences",
-        title: "WEATHER HISTORY",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 197,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 198 },
-        category: "DWD sequences",
-        title: "PRECIPITATION AND SNOW COVER",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 236,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 199 },
-        category: "DWD sequences",
-        title: "ADDITIONAL OBSERVATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 198,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 200 },
-        category: "DWD sequences",
-        title: "9SPSPSPSP",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 199,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 201 },
-        category: "DWD sequences",
-        title: "PHAENOLOGICAL REPORT",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 200,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 202 },
-        category: "DWD sequences",
-        title: "ADDITIONAL COMMENT",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 201,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 4, y: 230 },
-        category: "DWD sequences",
-        title: "macro for volumescan timestamp",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 0, x: 4, y: 6 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 193 },
-        category: "DWD sequences",
-        title: "PART 2 - ASCAT SIGMA-0 INFORMATION",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 158,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 159,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 160,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 161,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 162,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 163,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 164,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 165,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 166,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 194 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 5, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 2, x: 1, y: 132 },
-            Descriptor { f: 0, x: 30, y: 1 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 21, y: 36 },
-            Descriptor { f: 0, x: 21, y: 36 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 195 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 5, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 2, x: 1, y: 132 },
-            Descriptor { f: 0, x: 30, y: 1 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 13, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 196 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 3, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 2, y: 135 },
-            Descriptor { f: 0, x: 6, y: 194 },
-            Descriptor { f: 0, x: 6, y: 195 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 197 },
-        category: "DWD sequences",
-        title: "8 bit per pixel radar image (east-west view)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 10, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 5, y: 31 },
-            Descriptor { f: 1, x: 7, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 1, x: 2, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 31, y: 12 },
-            Descriptor { f: 0, x: 30, y: 2 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 30, y: 2 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 198 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor {
-                f: 0,
-                x: 31,
-                y: 192,
-            },
-            Descriptor { f: 0, x: 30, y: 1 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 199 },
-        category: "DWD sequences",
-        title: "Polar reflectivity data for a given elevation",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 10, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 0, x: 2, y: 134 },
-            Descriptor { f: 0, x: 2, y: 135 },
-            Descriptor { f: 3, x: 21, y: 6 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 201,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 202,
-            },
-            Descriptor { f: 0, x: 2, y: 193 },
-            Descriptor {
-                f: 0,
-                x: 30,
-                y: 194,
-            },
-            Descriptor {
-                f: 0,
-                x: 30,
-                y: 195,
-            },
-            Descriptor {
-                f: 3,
-                x: 21,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 200 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor {
-                f: 0,
-                x: 31,
-                y: 192,
-            },
-            Descriptor { f: 0, x: 30, y: 4 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 201 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor {
-                f: 0,
-                x: 31,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 204,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 202 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 220,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 221,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 222,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 223,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 210 },
-        category: "DWD sequences",
-        title: "Sweep of dBZ (DWD)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 18, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 26 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 2, y: 134 },
-            Descriptor { f: 0, x: 2, y: 135 },
-            Descriptor { f: 2, x: 1, y: 132 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 2, x: 3, y: 10 },
-            Descriptor { f: 0, x: 21, y: 1 },
-            Descriptor { f: 2, x: 3, y: 255 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 21, y: 1 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 3, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 211 },
-        category: "DWD sequences",
-        title: "Sweep of Wind/Shear/ZDR (DWD)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 10, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 26 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 2, y: 134 },
-            Descriptor { f: 0, x: 2, y: 135 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 21, y: 14 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 250 },
-        category: "DWD sequences",
-        title: "List of radars included in a composite",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 4, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 1, y: 1 },
-            Descriptor { f: 0, x: 1, y: 2 },
-            Descriptor { f: 0, x: 31, y: 31 },
-            Descriptor { f: 0, x: 33, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 197 },
-        category: "DWD sequences",
-        title: "SECOND (HIGH ACCURACY)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 237 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 1, y: 12 },
-            Descriptor { f: 0, x: 2, y: 21 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 3, x: 1, y: 23 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 238 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 104 },
-            Descriptor { f: 0, x: 2, y: 121 },
-            Descriptor { f: 0, x: 2, y: 113 },
-            Descriptor { f: 0, x: 2, y: 26 },
-            Descriptor { f: 0, x: 2, y: 27 },
-            Descriptor { f: 0, x: 2, y: 111 },
-            Descriptor { f: 0, x: 2, y: 192 },
-            Descriptor { f: 2, x: 2, y: 127 },
-            Descriptor { f: 0, x: 1, y: 13 },
-            Descriptor { f: 2, x: 2, y: 126 },
-            Descriptor { f: 0, x: 7, y: 1 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 25, y: 10 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 194,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 239 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 111 },
-            Descriptor { f: 0, x: 2, y: 112 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 193,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 195,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 240 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 22, y: 21 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 241 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 1, y: 141 },
-            Descriptor { f: 2, x: 2, y: 130 },
-            Descriptor { f: 0, x: 7, y: 1 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 242 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 207,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 208,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 209,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 210,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 211,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 212,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 200 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: TEMPERATURE",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 209 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: PRECIPITATION (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 26, y: 20 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 195 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 10, y: 3 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 194,
-            },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 214 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 3, y: 21 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 192,
-            },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 217 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 2, x: 4, y: 7 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 192,
-            },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 222 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 7, y: 21 },
-            Descriptor { f: 0, x: 7, y: 22 },
-            Descriptor { f: 0, x: 8, y: 12 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 252,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 4, y: 194 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 4, y: 195 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 20, y: 10 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 4, y: 228 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 5, y: 217 },
-            Descriptor { f: 0, x: 6, y: 217 },
-            Descriptor { f: 0, x: 7, y: 217 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 4, y: 229 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 208 },
-            Descriptor { f: 0, x: 1, y: 209 },
-            Descriptor { f: 0, x: 1, y: 210 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 193 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 30 },
-            Descriptor { f: 0, x: 22, y: 4 },
-            Descriptor { f: 0, x: 22, y: 31 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 194 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 2 },
-            Descriptor { f: 0, x: 11, y: 11 },
-            Descriptor { f: 0, x: 11, y: 12 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 195,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 195 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 32 },
-            Descriptor { f: 0, x: 2, y: 33 },
-            Descriptor { f: 2, x: 4, y: 2 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 2, x: 4, y: 1 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 1, x: 3, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 7, y: 62 },
-            Descriptor { f: 0, x: 22, y: 43 },
-            Descriptor { f: 0, x: 22, y: 62 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 196 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 6, y: 3 },
-            Descriptor { f: 3, x: 6, y: 193 },
-            Descriptor { f: 0, x: 22, y: 63 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 197 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 6, y: 194 },
-            Descriptor { f: 3, x: 6, y: 2 },
-            Descriptor { f: 0, x: 22, y: 63 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 198 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 196 },
-            Descriptor { f: 0, x: 22, y: 63 },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 230,
-            },
-            Descriptor { f: 0, x: 1, y: 221 },
-            Descriptor { f: 0, x: 5, y: 11 },
-            Descriptor { f: 0, x: 6, y: 11 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 0, x: 4, y: 15 },
-            Descriptor { f: 0, x: 1, y: 221 },
-            Descriptor { f: 0, x: 8, y: 202 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 5, y: 11 },
-            Descriptor { f: 0, x: 6, y: 11 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 0, x: 4, y: 15 },
-            Descriptor { f: 1, x: 6, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 11, y: 81 },
-            Descriptor { f: 0, x: 11, y: 82 },
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 233,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 231,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 232,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 200 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 32 },
-            Descriptor { f: 1, x: 2, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 7, y: 62 },
-            Descriptor { f: 0, x: 22, y: 43 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 198 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 31 },
-            Descriptor { f: 3, x: 2, y: 203 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 199 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 32 },
-            Descriptor { f: 3, x: 2, y: 203 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 210 },
-        category: "DWD sequences",
-        title: "QUALITY OF LIGHTNING NET",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 0, x: 4, y: 25 },
-            Descriptor { f: 0, x: 30, y: 21 },
-            Descriptor { f: 0, x: 30, y: 22 },
-            Descriptor { f: 0, x: 5, y: 12 },
-            Descriptor { f: 1, x: 5, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 6, y: 12 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 0,
-                x: 33,
-                y: 192,
-            },
-            Descriptor { f: 0, x: 6, y: 12 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 8, y: 193 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 34 },
-            Descriptor { f: 3, x: 2, y: 202 },
-            Descriptor { f: 0, x: 22, y: 43 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 8, y: 194 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 35 },
-            Descriptor { f: 3, x: 2, y: 202 },
-            Descriptor { f: 0, x: 22, y: 43 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 8, y: 195 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 36 },
-            Descriptor { f: 3, x: 2, y: 202 },
-            Descriptor { f: 0, x: 22, y: 43 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 8, y: 196 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 8, y: 195 },
-            Descriptor { f: 3, x: 2, y: 24 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 8, y: 197 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 10, y: 61 },
-            Descriptor { f: 0, x: 10, y: 63 },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 195,
-            },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 0, x: 22, y: 43 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 8, y: 198 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 2, x: 4, y: 2 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 2, x: 4, y: 1 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor { f: 2, x: 4, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 196 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 37 },
-            Descriptor { f: 3, x: 2, y: 4 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 204 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 197 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 38 },
-            Descriptor { f: 3, x: 2, y: 4 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 204 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 204 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 39 },
-            Descriptor { f: 3, x: 2, y: 4 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 204 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 208 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 40 },
-            Descriptor { f: 3, x: 2, y: 4 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 204 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 210 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 1 },
-            Descriptor { f: 0, x: 2, y: 11 },
-            Descriptor { f: 0, x: 2, y: 13 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 3, x: 1, y: 22 },
-            Descriptor { f: 3, x: 2, y: 4 },
-            Descriptor { f: 0, x: 2, y: 14 },
-            Descriptor { f: 0, x: 2, y: 3 },
-            Descriptor { f: 0, x: 4, y: 193 },
-            Descriptor { f: 0, x: 4, y: 194 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 205 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 213 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 205 },
-            Descriptor { f: 0, x: 2, y: 11 },
-            Descriptor { f: 0, x: 2, y: 13 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 3, x: 1, y: 24 },
-            Descriptor { f: 3, x: 2, y: 4 },
-            Descriptor { f: 0, x: 2, y: 14 },
-            Descriptor { f: 0, x: 2, y: 3 },
-            Descriptor { f: 0, x: 4, y: 193 },
-            Descriptor { f: 0, x: 4, y: 194 },
-            Descriptor { f: 0, x: 22, y: 43 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 205 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 12, y: 195 },
-        category: "DWD sequences",
-        title: "PART 2 - SCATTEROMETER SOIL MOISTURE DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 40, y: 3 },
-            Descriptor { f: 0, x: 40, y: 4 },
-            Descriptor { f: 0, x: 40, y: 5 },
-            Descriptor { f: 0, x: 40, y: 6 },
-            Descriptor { f: 0, x: 40, y: 7 },
-            Descriptor { f: 0, x: 20, y: 65 },
-            Descriptor { f: 0, x: 40, y: 8 },
-            Descriptor { f: 0, x: 40, y: 9 },
-            Descriptor { f: 0, x: 40, y: 10 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 15, y: 192 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 11 },
-            Descriptor { f: 3, x: 8, y: 198 },
-            Descriptor { f: 3, x: 6, y: 196 },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 228,
-            },
-            Descriptor { f: 3, x: 6, y: 192 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 15, y: 193 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 5 },
-            Descriptor { f: 3, x: 8, y: 198 },
-            Descriptor { f: 3, x: 6, y: 196 },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 228,
-            },
-            Descriptor { f: 3, x: 6, y: 192 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 15, y: 194 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 11 },
-            Descriptor { f: 3, x: 8, y: 198 },
-            Descriptor { f: 3, x: 6, y: 3 },
-            Descriptor { f: 0, x: 22, y: 63 },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 228,
-            },
-            Descriptor { f: 3, x: 6, y: 195 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 15, y: 195 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 15,
-                y: 194,
-            },
-            Descriptor { f: 0, x: 2, y: 40 },
-            Descriptor { f: 3, x: 6, y: 5 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 205 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 228,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 229,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 228,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 230,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 231,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 207 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 235,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 236,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 237,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 238,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 29, y: 192 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 0, x: 6, y: 198 },
-            Descriptor { f: 0, x: 5, y: 194 },
-            Descriptor {
-                f: 0,
-                x: 30,
-                y: 192,
-            },
-            Descriptor { f: 0, x: 5, y: 195 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 242 },
-        category: "DWD sequences",
-        title: "SWIS Data",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 101 },
-            Descriptor { f: 0, x: 1, y: 102 },
-            Descriptor { f: 0, x: 1, y: 18 },
-            Descriptor { f: 0, x: 1, y: 15 },
-            Descriptor { f: 0, x: 1, y: 241 },
-            Descriptor { f: 0, x: 1, y: 242 },
-            Descriptor { f: 0, x: 1, y: 243 },
-            Descriptor { f: 0, x: 2, y: 243 },
-            Descriptor { f: 0, x: 2, y: 244 },
-            Descriptor { f: 0, x: 2, y: 245 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 7, y: 30 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 103,
-            },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 20, y: 1 },
-            Descriptor { f: 1, x: 9, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 2, y: 242 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 241,
-            },
-            Descriptor { f: 1, x: 2, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 7, y: 61 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 242,
-            },
-            Descriptor { f: 0, x: 7, y: 61 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 241,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 241,
-            },
-            Descriptor { f: 0, x: 4, y: 25 },
-            Descriptor { f: 0, x: 20, y: 24 },
-            Descriptor { f: 0, x: 13, y: 55 },
-            Descriptor { f: 0, x: 20, y: 21 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 25 },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 25 },
-            Descriptor { f: 0, x: 11, y: 43 },
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 0, x: 33, y: 5 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 250 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 193 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 13 },
-            Descriptor { f: 3, x: 1, y: 21 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 249 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 252 },
-            Descriptor { f: 1, x: 4, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 2, y: 199 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 13, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 250 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 252 },
-            Descriptor { f: 0, x: 2, y: 23 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-            Descriptor { f: 0, x: 2, y: 197 },
-            Descriptor { f: 0, x: 2, y: 198 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 251 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 252 },
-            Descriptor { f: 1, x: 5, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 2, y: 254 },
-            Descriptor { f: 0, x: 2, y: 251 },
-            Descriptor { f: 0, x: 2, y: 197 },
-            Descriptor { f: 0, x: 2, y: 198 },
-            Descriptor { f: 0, x: 12, y: 63 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 3, y: 252 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 252 },
-            Descriptor { f: 1, x: 5, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 2, y: 254 },
-            Descriptor { f: 0, x: 2, y: 251 },
-            Descriptor { f: 0, x: 2, y: 197 },
-            Descriptor { f: 0, x: 2, y: 198 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 194,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 4, y: 250 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 231 },
-            Descriptor { f: 0, x: 2, y: 232 },
-            Descriptor { f: 0, x: 8, y: 12 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor { f: 0, x: 2, y: 57 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 24 },
-            Descriptor { f: 1, x: 10, y: 4 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 0, x: 11, y: 1 },
-            Descriptor { f: 0, x: 11, y: 2 },
-            Descriptor { f: 1, x: 3, y: 10 },
-            Descriptor { f: 0, x: 2, y: 231 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 12, y: 1 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 195 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 195 },
-            Descriptor { f: 3, x: 2, y: 4 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 3, y: 14 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 196 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 250 },
-            Descriptor { f: 3, x: 3, y: 249 },
-            Descriptor { f: 3, x: 3, y: 251 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 203 },
-        category: "DWD sequences",
-        title: "ODIM polar volume mandatory content",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 12, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 3,
-                x: 21,
-                y: 205,
-            },
-            Descriptor {
-                f: 0,
-                x: 30,
-                y: 196,
-            },
-            Descriptor { f: 0, x: 2, y: 135 },
-            Descriptor {
-                f: 0,
-                x: 30,
-                y: 194,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 201,
-            },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 203,
-            },
-            Descriptor {
-                f: 0,
-                x: 30,
-                y: 195,
-            },
-            Descriptor { f: 0, x: 2, y: 134 },
-            Descriptor { f: 1, x: 2, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 0,
-                x: 30,
-                y: 196,
-            },
-            Descriptor {
-                f: 3,
-                x: 21,
-                y: 206,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 204 },
-        category: "DWD sequences",
-        title: "ODIM additional station identifiers",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 2, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 1, y: 192 },
-            Descriptor { f: 0, x: 1, y: 193 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 206 },
-        category: "DWD sequences",
-        title: "ODIM compressed array",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 30,
-                y: 197,
-            },
-            Descriptor { f: 1, x: 3, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 30,
-                y: 198,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 226 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 10, y: 22 },
-            Descriptor { f: 0, x: 25, y: 60 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 33, y: 39 },
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 3, x: 4, y: 31 },
-            Descriptor { f: 0, x: 2, y: 20 },
-            Descriptor { f: 0, x: 1, y: 50 },
-            Descriptor { f: 2, x: 2, y: 127 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 3, x: 4, y: 31 },
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 16 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 0, x: 10, y: 35 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 10, y: 36 },
-            Descriptor { f: 1, x: 7, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 1, x: 3, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 2, y: 121 },
-            Descriptor { f: 0, x: 7, y: 40 },
-            Descriptor { f: 0, x: 15, y: 37 },
-            Descriptor { f: 1, x: 2, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 7, y: 7 },
-            Descriptor { f: 0, x: 15, y: 36 },
-            Descriptor { f: 1, x: 4, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 7, y: 9 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 13, y: 1 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 7, y: 9 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 33, y: 7 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 227 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 10, y: 22 },
-            Descriptor { f: 0, x: 25, y: 60 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 33, y: 39 },
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 3, x: 4, y: 31 },
-            Descriptor { f: 0, x: 2, y: 20 },
-            Descriptor { f: 0, x: 1, y: 50 },
-            Descriptor { f: 2, x: 2, y: 127 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 3, x: 4, y: 31 },
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 16 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 0, x: 10, y: 35 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 10, y: 36 },
-            Descriptor { f: 1, x: 7, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 1, x: 3, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 2, y: 121 },
-            Descriptor { f: 0, x: 7, y: 40 },
-            Descriptor { f: 0, x: 15, y: 37 },
-            Descriptor { f: 2, x: 22, y: 0 },
-            Descriptor { f: 2, x: 36, y: 0 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 31, y: 31 },
-            Descriptor { f: 0, x: 1, y: 31 },
-            Descriptor { f: 0, x: 1, y: 32 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 2, x: 24, y: 0 },
-            Descriptor { f: 2, x: 37, y: 0 },
-            Descriptor { f: 0, x: 1, y: 31 },
-            Descriptor { f: 0, x: 1, y: 32 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 2,
-                x: 24,
-                y: 255,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 228 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 10, y: 22 },
-            Descriptor { f: 0, x: 25, y: 60 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 33, y: 39 },
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 3, x: 4, y: 31 },
-            Descriptor { f: 0, x: 2, y: 20 },
-            Descriptor { f: 0, x: 1, y: 50 },
-            Descriptor { f: 2, x: 2, y: 127 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 3, x: 4, y: 31 },
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 16 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 0, x: 10, y: 35 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 10, y: 36 },
-            Descriptor { f: 1, x: 6, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 1, x: 2, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 7, y: 7 },
-            Descriptor { f: 0, x: 15, y: 36 },
-            Descriptor { f: 2, x: 22, y: 0 },
-            Descriptor { f: 2, x: 36, y: 0 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 31, y: 31 },
-            Descriptor { f: 0, x: 1, y: 31 },
-            Descriptor { f: 0, x: 1, y: 32 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 2, x: 24, y: 0 },
-            Descriptor { f: 2, x: 37, y: 0 },
-            Descriptor { f: 0, x: 1, y: 31 },
-            Descriptor { f: 0, x: 1, y: 32 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 2,
-                x: 24,
-                y: 255,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 10, y: 229 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 10, y: 22 },
-            Descriptor { f: 0, x: 25, y: 60 },
-            Descriptor { f: 0, x: 8, y: 21 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 33, y: 39 },
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 3, x: 4, y: 31 },
-            Descriptor { f: 0, x: 2, y: 20 },
-            Descriptor { f: 0, x: 1, y: 50 },
-            Descriptor { f: 2, x: 2, y: 127 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 3, x: 4, y: 31 },
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 0, x: 4, y: 16 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 3, x: 4, y: 30 },
-            Descriptor { f: 0, x: 10, y: 35 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 10, y: 36 },
-            Descriptor { f: 1, x: 8, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 1, x: 4, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 0, x: 7, y: 9 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 13, y: 1 },
-            Descriptor { f: 2, x: 22, y: 0 },
-            Descriptor { f: 2, x: 36, y: 0 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 31, y: 31 },
-            Descriptor { f: 0, x: 1, y: 31 },
-            Descriptor { f: 0, x: 1, y: 32 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor { f: 0, x: 33, y: 7 },
-            Descriptor { f: 2, x: 24, y: 0 },
-            Descriptor { f: 2, x: 37, y: 0 },
-            Descriptor { f: 0, x: 1, y: 31 },
-            Descriptor { f: 0, x: 1, y: 32 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 2,
-                x: 24,
-                y: 255,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 50, y: 199 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 5, y: 40 },
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 0, x: 5, y: 41 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 1, y: 132 },
-            Descriptor { f: 0, x: 25, y: 70 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 126 },
-            Descriptor { f: 0, x: 7, y: 1 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 7, y: 25 },
-            Descriptor { f: 0, x: 5, y: 22 },
-            Descriptor {
-                f: 3,
-                x: 50,
-                y: 207,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 50, y: 200 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 19 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 5, y: 43 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 50, y: 207 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 2, y: 9 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 50, y: 208 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[Descriptor { f: 0, x: 25, y: 50 }],
-    },
-    TableDEntry {
-        xy: XY { x: 50, y: 209 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 25, y: 76 },
-            Descriptor { f: 0, x: 25, y: 52 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 13, y: 210 },
-        category: "DWD sequences",
-        title: "Radar rainfall intensities",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 236,
-            },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 0,
-                x: 21,
-                y: 236,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 20 },
-        category: "DWD sequences",
-        title: "Tide report identification, water level checks, time period or displacement, time increment) (see Note 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 75 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 0, x: 22, y: 42 },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 120,
-            },
-            Descriptor {
-                f: 0,
-                x: 22,
-                y: 121,
-            },
-            Descriptor { f: 0, x: 4, y: 75 },
-            Descriptor { f: 0, x: 4, y: 65 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 6, y: 24 },
-        category: "DWD sequences",
-        title: "Tide elevation series) (see Note 3",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 6, y: 20 },
-            Descriptor { f: 1, x: 2, y: 6 },
-            Descriptor { f: 0, x: 22, y: 38 },
-            Descriptor { f: 0, x: 22, y: 39 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 40 },
-        category: "DWD sequences",
-        title: "Ozone sounding not coupled to a ground-based spectrophotometer) (see Note 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 75 },
-            Descriptor { f: 3, x: 1, y: 76 },
-            Descriptor { f: 3, x: 9, y: 30 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 41 },
-        category: "DWD sequences",
-        title: "Ozone sounding coupled to measurements from a Brewer ground-based spectrophotometer; the total ozone obtained from the Brewer is a single value) (see Note 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 7, y: 41 },
-            Descriptor { f: 3, x: 1, y: 75 },
-            Descriptor { f: 3, x: 1, y: 76 },
-            Descriptor { f: 3, x: 9, y: 30 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 42 },
-        category: "DWD sequences",
-        title: "Ozone sounding coupled to measurements from a Brewer ground-based spectrophotometer; the total ozone obtained from the Brewer is an averaged value) (see Note 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 7, y: 42 },
-            Descriptor { f: 3, x: 1, y: 75 },
-            Descriptor { f: 3, x: 1, y: 76 },
-            Descriptor { f: 3, x: 9, y: 30 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 43 },
-        category: "DWD sequences",
-        title: "Ozone sounding coupled to measurements from a Dobson ground-based spectrophotometer; the total ozone obtained from the Dobson is a single value) (see Note 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 7, y: 43 },
-            Descriptor { f: 3, x: 1, y: 75 },
-            Descriptor { f: 3, x: 1, y: 76 },
-            Descriptor { f: 3, x: 9, y: 30 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 9, y: 44 },
-        category: "DWD sequences",
-        title: "Ozone sounding coupled to measurements from a Dobson ground-based spectrophotometer; the total ozone obtained from the Dobson is an averaged value) (see Note 2",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 7, y: 44 },
-            Descriptor { f: 3, x: 1, y: 75 },
-            Descriptor { f: 3, x: 1, y: 76 },
-            Descriptor { f: 3, x: 9, y: 30 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 70 },
-        category: "DWD sequences",
-        title: "TEMPLATE SYNOP. REP. .. SUITABLE FOR SHIP OBS DATA FROM VOS",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 93 },
-            Descriptor { f: 3, x: 2, y: 62 },
-            Descriptor { f: 3, x: 2, y: 63 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 16, y: 41 },
-        category: "DWD sequences",
-        title: "SANDSTORM SIGMET",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 16, y: 30 },
-            Descriptor { f: 0, x: 8, y: 11 },
-            Descriptor { f: 0, x: 20, y: 24 },
-            Descriptor { f: 3, x: 16, y: 31 },
-            Descriptor { f: 0, x: 8, y: 11 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 16, y: 42 },
-        category: "DWD sequences",
-        title: "CANCELLATION OF SIGMET",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 16, y: 30 },
-            Descriptor { f: 0, x: 8, y: 79 },
-            Descriptor { f: 1, x: 2, y: 2 },
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 0, x: 1, y: 37 },
-            Descriptor { f: 0, x: 10, y: 64 },
-            Descriptor { f: 0, x: 8, y: 79 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 50, y: 254 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 196,
-            },
-            Descriptor { f: 1, x: 1, y: 6 },
-            Descriptor {
-                f: 3,
-                x: 10,
-                y: 197,
-            },
-            Descriptor { f: 0, x: 8, y: 70 },
-            Descriptor { f: 0, x: 1, y: 33 },
-            Descriptor { f: 0, x: 1, y: 34 },
-            Descriptor { f: 0, x: 8, y: 70 },
-            Descriptor { f: 0, x: 1, y: 33 },
-            Descriptor { f: 0, x: 1, y: 34 },
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 2, y: 19 },
-            Descriptor { f: 0, x: 5, y: 40 },
-            Descriptor { f: 0, x: 25, y: 75 },
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 0, x: 5, y: 41 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 0, x: 25, y: 70 },
-            Descriptor {
-                f: 0,
-                x: 33,
-                y: 210,
-            },
-            Descriptor {
-                f: 0,
-                x: 33,
-                y: 211,
-            },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 2, x: 2, y: 131 },
-            Descriptor { f: 2, x: 1, y: 138 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 2, x: 2, y: 126 },
-            Descriptor { f: 0, x: 7, y: 1 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 7, y: 25 },
-            Descriptor { f: 0, x: 5, y: 22 },
-            Descriptor { f: 0, x: 25, y: 81 },
-            Descriptor {
-                f: 0,
-                x: 33,
-                y: 213,
-            },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-            Descriptor { f: 0, x: 2, y: 150 },
-            Descriptor { f: 0, x: 25, y: 76 },
-            Descriptor { f: 0, x: 25, y: 77 },
-            Descriptor { f: 0, x: 25, y: 78 },
-            Descriptor {
-                f: 0,
-                x: 33,
-                y: 214,
-            },
-            Descriptor {
-                f: 0,
-                x: 33,
-                y: 215,
-            },
-            Descriptor { f: 0, x: 8, y: 65 },
-            Descriptor { f: 0, x: 13, y: 40 },
-            Descriptor { f: 2, x: 1, y: 132 },
-            Descriptor { f: 2, x: 2, y: 129 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 2, y: 19 },
-            Descriptor { f: 0, x: 5, y: 40 },
-            Descriptor { f: 0, x: 5, y: 41 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 0, x: 4, y: 7 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 0, x: 7, y: 2 },
-            Descriptor { f: 0, x: 7, y: 25 },
-            Descriptor { f: 0, x: 5, y: 22 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 8, y: 75 },
-            Descriptor {
-                f: 0,
-                x: 40,
-                y: 213,
-            },
-            Descriptor {
-                f: 0,
-                x: 40,
-                y: 214,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 155,
-            },
-            Descriptor { f: 2, x: 1, y: 129 },
-            Descriptor { f: 0, x: 13, y: 91 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 20, y: 34 },
-            Descriptor { f: 0, x: 22, y: 49 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 2, x: 1, y: 131 },
-            Descriptor { f: 0, x: 11, y: 98 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 2, x: 1, y: 130 },
-            Descriptor { f: 0, x: 13, y: 90 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 2, y: 19 },
-            Descriptor { f: 0, x: 8, y: 201 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 0, x: 7, y: 2 },
-            Descriptor { f: 0, x: 2, y: 224 },
-            Descriptor { f: 0, x: 2, y: 225 },
-            Descriptor { f: 0, x: 2, y: 226 },
-            Descriptor { f: 0, x: 5, y: 41 },
-            Descriptor { f: 0, x: 5, y: 233 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 0, x: 4, y: 7 },
-            Descriptor { f: 0, x: 8, y: 201 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 1, x: 7, y: 4 },
-            Descriptor { f: 0, x: 5, y: 42 },
-            Descriptor { f: 0, x: 2, y: 153 },
-            Descriptor {
-                f: 0,
-                x: 40,
-                y: 211,
-            },
-            Descriptor { f: 0, x: 2, y: 104 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor {
-                f: 0,
-                x: 40,
-                y: 212,
-            },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 2, y: 19 },
-            Descriptor { f: 0, x: 8, y: 201 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 0, x: 7, y: 2 },
-            Descriptor { f: 0, x: 2, y: 224 },
-            Descriptor { f: 0, x: 2, y: 225 },
-            Descriptor { f: 0, x: 2, y: 226 },
-            Descriptor { f: 0, x: 5, y: 41 },
-            Descriptor { f: 0, x: 5, y: 233 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 0, x: 4, y: 7 },
-            Descriptor { f: 0, x: 8, y: 201 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 1, x: 7, y: 9 },
-            Descriptor { f: 0, x: 5, y: 42 },
-            Descriptor { f: 0, x: 2, y: 153 },
-            Descriptor {
-                f: 0,
-                x: 40,
-                y: 211,
-            },
-            Descriptor { f: 0, x: 2, y: 104 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor {
-                f: 0,
-                x: 40,
-                y: 212,
-            },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 2, y: 19 },
-            Descriptor { f: 0, x: 5, y: 40 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 0, x: 4, y: 6 },
-            Descriptor { f: 2, x: 2, y: 126 },
-            Descriptor { f: 0, x: 7, y: 1 },
-            Descriptor { f: 2, x: 2, y: 0 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 0, x: 5, y: 41 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 0, x: 25, y: 70 },
-            Descriptor { f: 0, x: 7, y: 25 },
-            Descriptor { f: 0, x: 5, y: 22 },
-            Descriptor { f: 1, x: 2, y: 9 },
-            Descriptor { f: 0, x: 2, y: 151 },
-            Descriptor { f: 0, x: 12, y: 64 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 1, x: 5, y: 12 },
-            Descriptor { f: 0, x: 5, y: 42 },
-            Descriptor { f: 0, x: 2, y: 153 },
-            Descriptor { f: 0, x: 33, y: 32 },
-            Descriptor { f: 0, x: 2, y: 104 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 163,
-            },
-            Descriptor { f: 1, x: 9, y: 4 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 0, x: 5, y: 42 },
-            Descriptor { f: 0, x: 2, y: 153 },
-            Descriptor { f: 0, x: 33, y: 32 },
-            Descriptor { f: 0, x: 2, y: 104 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 163,
-            },
-            Descriptor { f: 1, x: 9, y: 4 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 0, x: 5, y: 42 },
-            Descriptor { f: 0, x: 2, y: 153 },
-            Descriptor { f: 0, x: 33, y: 32 },
-            Descriptor { f: 0, x: 2, y: 104 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 163,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 21, y: 208 },
-        category: "DWD sequences",
-        title: "",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 228,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 241,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 228,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 242,
-            },
-            Descriptor {
-                f: 0,
-                x: 49,
-                y: 231,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 1, y: 210 },
-        category: "DWD sequences",
-        title: "SATELLITE AND INSTRUMENTS IDENTIFICATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 1, y: 31 },
-            Descriptor { f: 0, x: 2, y: 19 },
-            Descriptor { f: 0, x: 2, y: 20 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 222 },
-        category: "DWD sequences",
-        title: "PART OF 302066",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 20, y: 25 },
-            Descriptor { f: 0, x: 20, y: 26 },
-            Descriptor { f: 0, x: 20, y: 27 },
-            Descriptor { f: 0, x: 20, y: 40 },
-            Descriptor { f: 0, x: 20, y: 66 },
-            Descriptor { f: 0, x: 20, y: 27 },
-            Descriptor { f: 0, x: 20, y: 21 },
-            Descriptor { f: 0, x: 20, y: 67 },
-            Descriptor { f: 0, x: 20, y: 27 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 247 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: MEAN TAEMPERATURE (DAY BEFORE)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 202,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 248 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: BOLTS COUNT",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor { f: 0, x: 13, y: 59 },
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor {
-                f: 0,
-                x: 51,
-                y: 192,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 2, y: 249 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: WIND DATA (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor { f: 0, x: 11, y: 42 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 207 },
-        category: "DWD sequences",
-        title: "SURFACE SYNOPTIC REPORT: SYNOP SECTION 5, FROM xxx",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 18 },
-            Descriptor { f: 3, x: 1, y: 90 },
-            Descriptor { f: 3, x: 1, y: 200 },
-            Descriptor { f: 2, x: 4, y: 4 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor { f: 3, x: 2, y: 200 },
-            Descriptor { f: 3, x: 2, y: 209 },
-            Descriptor { f: 3, x: 2, y: 247 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 11, y: 2 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 210,
-            },
-            Descriptor { f: 3, x: 2, y: 248 },
-            Descriptor { f: 3, x: 2, y: 206 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 211,
-            },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 212,
-            },
-            Descriptor { f: 3, x: 2, y: 249 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 213,
-            },
-            Descriptor { f: 1, x: 1, y: 7 },
-            Descriptor { f: 3, x: 7, y: 63 },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 214,
-            },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 215,
-            },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 216,
-            },
-            Descriptor {
-                f: 3,
-                x: 48,
-                y: 217,
-            },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 2, x: 5, y: 8 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 208 },
-        category: "DWD sequences",
-        title: "LIGHTNING DATA",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 5 },
-            Descriptor { f: 0, x: 4, y: 7 },
-            Descriptor { f: 0, x: 5, y: 1 },
-            Descriptor { f: 0, x: 6, y: 1 },
-            Descriptor { f: 0, x: 2, y: 184 },
-            Descriptor { f: 0, x: 2, y: 187 },
-            Descriptor { f: 0, x: 2, y: 189 },
-            Descriptor {
-                f: 0,
-                x: 10,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 20, y: 23 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 227,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 229,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 209 },
-        category: "DWD sequences",
-        title: "STATUS OF LIGHTNING DETECTION SENSOR",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 3, x: 1, y: 11 },
-            Descriptor { f: 3, x: 1, y: 12 },
-            Descriptor { f: 0, x: 4, y: 25 },
-            Descriptor { f: 0, x: 2, y: 184 },
-            Descriptor { f: 1, x: 3, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 1, y: 21 },
-            Descriptor { f: 0, x: 1, y: 15 },
-            Descriptor { f: 0, x: 33, y: 2 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 219 },
-        category: "DWD sequences",
-        title: "CLIMAT REPORT: CLIMAT,SECTION 5, FROM",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 18 },
-            Descriptor { f: 3, x: 1, y: 90 },
-            Descriptor { f: 0, x: 4, y: 23 },
-            Descriptor { f: 2, x: 4, y: 4 },
-            Descriptor { f: 0, x: 31, y: 21 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 212,
-            },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 0, x: 13, y: 3 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 1, x: 2, y: 6 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 192,
-            },
-            Descriptor { f: 0, x: 4, y: 202 },
-            Descriptor { f: 2, x: 4, y: 0 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 2, x: 5, y: 8 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 225 },
-        category: "DWD sequences",
-        title: "PART 2 OF 307080",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor { f: 3, x: 2, y: 45 },
-            Descriptor { f: 3, x: 2, y: 46 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 226 },
-        category: "DWD sequences",
-        title: "PART OF 307081 RA I",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 122,
-            },
-            Descriptor { f: 0, x: 13, y: 56 },
-            Descriptor { f: 0, x: 13, y: 57 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 101,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 102,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 103,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 104,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 105,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 106,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 107,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 108,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 227 },
-        category: "DWD sequences",
-        title: "PART OF 307082 RA II",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 121,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 122,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 228 },
-        category: "DWD sequences",
-        title: "PART OF 307084 RA IV",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 20, y: 55 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 2, x: 5, y: 1 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 230 },
-        category: "DWD sequences",
-        title: "PART OF 307090",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 2 },
-            Descriptor { f: 3, x: 2, y: 45 },
-            Descriptor { f: 3, x: 2, y: 46 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 231 },
-        category: "DWD sequences",
-        title: "PART OF 307071 - MONTHLY MEAN VALUES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 74 },
-            Descriptor { f: 0, x: 4, y: 23 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 10, y: 9 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 232 },
-        category: "DWD sequences",
-        title: "PART 3 OF 307071 - MONTHLY MEAN VALUES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 51 },
-            Descriptor { f: 0, x: 4, y: 51 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 118,
-            },
-            Descriptor { f: 0, x: 4, y: 52 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 119,
-            },
-            Descriptor { f: 0, x: 13, y: 4 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 151,
-            },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 1, x: 2, y: 5 },
-            Descriptor { f: 0, x: 8, y: 50 },
-            Descriptor { f: 0, x: 8, y: 20 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 233 },
-        category: "DWD sequences",
-        title: "PART OF 307071 - SUNSHINE DURATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 14, y: 32 },
-            Descriptor { f: 0, x: 14, y: 33 },
-            Descriptor { f: 0, x: 8, y: 50 },
-            Descriptor { f: 0, x: 8, y: 20 },
-            Descriptor { f: 1, x: 2, y: 18 },
-            Descriptor { f: 0, x: 8, y: 52 },
-            Descriptor { f: 0, x: 8, y: 22 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 234 },
-        category: "DWD sequences",
-        title: "PART OF 307071 - OCCURENCE OF EXTREME VALUES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 152,
-            },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 153,
-            },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 235 },
-        category: "DWD sequences",
-        title: "PART OF 307071 - OCCURENCE OF EXTREME VALUES II",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 2, y: 2 },
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 11, y: 46 },
-            Descriptor { f: 0, x: 8, y: 53 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 236 },
-        category: "DWD sequences",
-        title: "PART OF 307071 - PRECIPITATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 23 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 13, y: 60 },
-            Descriptor { f: 0, x: 13, y: 51 },
-            Descriptor { f: 0, x: 4, y: 53 },
-            Descriptor { f: 0, x: 8, y: 50 },
-            Descriptor { f: 0, x: 8, y: 20 },
-            Descriptor { f: 1, x: 2, y: 6 },
-            Descriptor { f: 0, x: 8, y: 52 },
-            Descriptor { f: 0, x: 8, y: 22 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 237 },
-        category: "DWD sequences",
-        title: "PART OF 307071 - OCCURENCE OF EXTREME PRECIPITATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 53 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 13, y: 52 },
-            Descriptor { f: 0, x: 7, y: 32 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 238 },
-        category: "DWD sequences",
-        title: "PART I OF 307072",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 74 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 10, y: 9 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 239 },
-        category: "DWD sequences",
-        title: "PART II OF 307072",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-            Descriptor { f: 0, x: 2, y: 51 },
-            Descriptor { f: 0, x: 4, y: 51 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 118,
-            },
-            Descriptor { f: 0, x: 4, y: 52 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 119,
-            },
-            Descriptor { f: 0, x: 13, y: 4 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 151,
-            },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 14, y: 32 },
-            Descriptor { f: 0, x: 8, y: 23 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 240 },
-        category: "DWD sequences",
-        title: "PART III OF 307072 - NORMALS OF PRECIPITATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 1 },
-            Descriptor { f: 0, x: 4, y: 2 },
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 22 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 0, x: 13, y: 60 },
-            Descriptor { f: 0, x: 4, y: 53 },
-            Descriptor { f: 0, x: 8, y: 23 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 7, y: 241 },
-        category: "DWD sequences",
-        title: "PART IV OF 307072 - MISSINGS",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 2, y: 8 },
-            Descriptor { f: 0, x: 8, y: 50 },
-            Descriptor { f: 0, x: 8, y: 20 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 8, y: 200 },
-        category: "DWD sequences",
-        title: "PART OF 308011/308012",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 23 },
-            Descriptor { f: 0, x: 10, y: 51 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 7, y: 33 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 101,
-            },
-            Descriptor { f: 0, x: 13, y: 4 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 7, y: 33 },
-            Descriptor { f: 3, x: 2, y: 56 },
-            Descriptor { f: 0, x: 8, y: 23 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 8, y: 201 },
-        category: "DWD sequences",
-        title: "PART OF 308011 - PRECIPITATION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 3 },
-            Descriptor { f: 0, x: 4, y: 4 },
-            Descriptor { f: 0, x: 4, y: 23 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 13, y: 60 },
-            Descriptor { f: 0, x: 13, y: 51 },
-            Descriptor { f: 0, x: 4, y: 53 },
-            Descriptor { f: 0, x: 7, y: 32 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 16, y: 202 },
-        category: "DWD sequences",
-        title: "PART OF 316036 - TROPICAL CYCLONE SIGMET",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 0 },
-            Descriptor { f: 3, x: 16, y: 32 },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor { f: 3, x: 16, y: 33 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 16, y: 205 },
-        category: "DWD sequences",
-        title: "PART OF 316050 - RADOB",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 19,
-                y: 100,
-            },
-            Descriptor { f: 0, x: 19, y: 5 },
-            Descriptor { f: 0, x: 19, y: 6 },
-            Descriptor {
-                f: 0,
-                x: 19,
-                y: 101,
-            },
-            Descriptor {
-                f: 0,
-                x: 19,
-                y: 102,
-            },
-            Descriptor {
-                f: 0,
-                x: 19,
-                y: 103,
-            },
-            Descriptor {
-                f: 0,
-                x: 19,
-                y: 104,
-            },
-            Descriptor {
-                f: 0,
-                x: 19,
-                y: 105,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 18, y: 194 },
-        category: "DWD sequences",
-        title: "EXTENDED RADIOACTIVITY DATA (GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 192,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 193,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 194,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 195,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 196,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 197,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 198,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 199,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 200,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 201,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 209,
-            },
-            Descriptor {
-                f: 0,
-                x: 24,
-                y: 210,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 40, y: 193 },
-        category: "DWD sequences",
-        title: "SATELLITE ANGLES AND NUMBERS",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor { f: 0, x: 5, y: 21 },
-            Descriptor { f: 0, x: 7, y: 25 },
-            Descriptor { f: 0, x: 5, y: 22 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 0, x: 5, y: 40 },
-            Descriptor { f: 2, x: 1, y: 133 },
-            Descriptor { f: 0, x: 5, y: 41 },
-            Descriptor { f: 2, x: 1, y: 0 },
-            Descriptor { f: 2, x: 1, y: 132 },
-            Descriptor { f: 0, x: 25, y: 70 },
-            Descriptor { f: 2, x: 1, y: 0 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 40, y: 194 },
-        category: "DWD sequences",
-        title: "IASI QUALITIES",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 33, y: 60 },
-            Descriptor { f: 0, x: 33, y: 61 },
-            Descriptor { f: 0, x: 33, y: 62 },
-            Descriptor { f: 0, x: 33, y: 63 },
-            Descriptor { f: 0, x: 33, y: 64 },
-            Descriptor { f: 0, x: 33, y: 65 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 40, y: 195 },
-        category: "DWD sequences",
-        title: "IASI LEVEL 1C REPETITIONS",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 10 },
-            Descriptor { f: 3, x: 40, y: 2 },
-            Descriptor { f: 1, x: 1, y: 87 },
-            Descriptor { f: 3, x: 40, y: 3 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 40, y: 196 },
-        category: "DWD sequences",
-        title: "AVHRR REPETITION",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 19 },
-            Descriptor { f: 0, x: 25, y: 51 },
-            Descriptor { f: 1, x: 1, y: 7 },
-            Descriptor { f: 3, x: 40, y: 4 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 210 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: SUNSHINE/RR/WW/FF (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor { f: 0, x: 14, y: 31 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 203,
-            },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 193,
-            },
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor {
-                f: 0,
-                x: 11,
-                y: 202,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 211 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: WEATHER BELOW STATION (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 194,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 195,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 216,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 212 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: CLOUDS (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 199,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 213 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: CLOUDCOVER MOUNTAIN (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 2, y: 3 },
-            Descriptor {
-                f: 0,
-                x: 52,
-                y: 210,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 230,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 214 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: PRECIPITATION (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 2, y: 175 },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 203,
-            },
-            Descriptor { f: 0, x: 2, y: 175 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 4, y: 31 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 203,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 215 },
-        category: "DWD sequences",
-        title: "SURFACE DATA:  (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 14, y: 31 },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 11, y: 41 },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 11, y: 42 },
-            Descriptor {
-                f: 0,
-                x: 13,
-                y: 203,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 204,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 205,
-            },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 206,
-            },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 12, y: 21 },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor { f: 0, x: 12, y: 22 },
-            Descriptor { f: 0, x: 7, y: 32 },
-            Descriptor { f: 0, x: 4, y: 192 },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 112,
-            },
-            Descriptor {
-                f: 0,
-                x: 12,
-                y: 195,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 216 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: MOR (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 234,
-            },
-            Descriptor { f: 0, x: 4, y: 32 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 235,
-            },
-            Descriptor { f: 1, x: 1, y: 4 },
-            Descriptor {
-                f: 0,
-                x: 20,
-                y: 237,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 48, y: 217 },
-        category: "DWD sequences",
-        title: "SURFACE DATA: 10MIN RR (SYNOP, SECT.5, GERMAN NATIONAL)",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 4, y: 25 },
-            Descriptor { f: 0, x: 4, y: 65 },
-            Descriptor { f: 1, x: 2, y: 6 },
-            Descriptor { f: 0, x: 13, y: 11 },
-            Descriptor { f: 0, x: 26, y: 20 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 191 },
-        category: "DWD sequences",
-        title: "CONTINUOUS WEATER TREND KWV",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 205,
-            },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 206,
-            },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 207,
-            },
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 2 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 193,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 203 },
-        category: "DWD sequences",
-        title: "LAST CHANGE USER",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 203,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 56, y: 204 },
-        category: "DWD sequences",
-        title: "LAST CHANGE COMMENT",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 1, x: 1, y: 0 },
-            Descriptor { f: 0, x: 31, y: 1 },
-            Descriptor {
-                f: 0,
-                x: 56,
-                y: 204,
-            },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 63, y: 226 },
-        category: "DWD sequences",
-        title: "PART 2 OF 363225",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 1, y: 7 },
-            Descriptor { f: 0, x: 5, y: 40 },
-            Descriptor { f: 0, x: 5, y: 40 },
-            Descriptor { f: 0, x: 25, y: 71 },
-            Descriptor { f: 3, x: 1, y: 23 },
-            Descriptor { f: 0, x: 5, y: 43 },
-            Descriptor { f: 0, x: 13, y: 38 },
-            Descriptor { f: 0, x: 8, y: 12 },
-            Descriptor { f: 0, x: 13, y: 39 },
-            Descriptor { f: 0, x: 8, y: 13 },
-            Descriptor { f: 0, x: 25, y: 45 },
-            Descriptor { f: 0, x: 25, y: 48 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 63, y: 227 },
-        category: "DWD sequences",
-        title: "PART 3 OF 363225",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 25, y: 49 },
-            Descriptor { f: 0, x: 2, y: 22 },
-            Descriptor { f: 0, x: 7, y: 22 },
-            Descriptor { f: 0, x: 7, y: 24 },
-            Descriptor { f: 0, x: 13, y: 16 },
-            Descriptor { f: 0, x: 15, y: 1 },
-            Descriptor { f: 0, x: 20, y: 11 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 10, y: 4 },
-            Descriptor { f: 0, x: 22, y: 43 },
-            Descriptor { f: 0, x: 12, y: 61 },
-            Descriptor { f: 0, x: 7, y: 1 },
-        ],
-    },
-    TableDEntry {
-        xy: XY { x: 63, y: 228 },
-        category: "DWD sequences",
-        title: "PART 4 OF 363225",
-        sub_title: "",
-        elements: &[
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 8, y: 3 },
-            Descriptor { f: 1, x: 2, y: 35 },
-            Descriptor { f: 0, x: 5, y: 42 },
-            Descriptor { f: 0, x: 12, y: 63 },
-            Descriptor { f: 1, x: 3, y: 40 },
-            Descriptor { f: 0, x: 7, y: 4 },
-            Descriptor { f: 0, x: 12, y: 1 },
-            Descriptor { f: 0, x: 13, y: 2 },
-        ],
-    },
-];
+// TABLE_VERSIONS, CODE_TABLES and FLAG_TABLES
+include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+
+/// DWD's local descriptors for a master and local table version.
+///
+/// [`TABLE_VERSIONS`] is sorted by the versions.
+#[derive(Debug)]
+pub struct TableVersion {
+    pub master: u8,
+    pub local: u8,
+    pub table_b: &'static [TableBEntry],
+    pub table_d: &'static [TableDEntry],
+}
+
+/// A code of a descriptor with the unit `Code table`.
+///
+/// Only the tables needed for weather reports are included (present and past weather, 020003-020005,
+/// and cloud cover, 020011) - all WMO tables would be a few MB.
+/// See `CODE_DESCRIPTORS` in `scripts/read-libdwd-tables.py` to add more.
+#[derive(Debug)]
+pub struct CodeTableEntry {
+    pub xy: XY,
+    pub code: u32,
+    pub meaning: &'static str,
+}
+
+/// A bit of a descriptor with the unit `Flag table`.
+///
+/// Bits are numbered from 1 (the most significant bit) like in the WMO tables.
+///
+/// Like [`CodeTableEntry`], only a few tables are included (the wind instrumentation, 002002).
+#[derive(Debug)]
+pub struct FlagTableEntry {
+    pub xy: XY,
+    pub bit: u8,
+    pub meaning: &'static str,
+}
+
+/// The index in [`TABLE_VERSIONS`] of the tables for a message with the `master` and `local`
+/// table versions from its identification section.
+///
+/// This is the newest version that isn't newer than the message or the oldest one if all are
+/// newer.
+pub fn table_version_index(master: u8, local: u8) -> usize {
+    version_index(&TABLE_VERSIONS, master, local)
+}
+
+fn version_index(versions: &[TableVersion], master: u8, local: u8) -> usize {
+    versions
+        .partition_point(|v| (v.master, v.local) <= (master, local))
+        .saturating_sub(1)
+}
+
+/// The meaning of `code` in the code table of `xy`.
+///
+/// Reserved codes, missing values and descriptors without an included table (see
+/// [`CodeTableEntry`]) don't have a meaning.
+pub fn code_meaning(xy: XY, code: u32) -> Option<&'static str> {
+    CODE_TABLES
+        .binary_search_by(|e| (e.xy.x, e.xy.y, e.code).cmp(&(xy.x, xy.y, code)))
+        .ok()
+        .map(|idx| CODE_TABLES[idx].meaning)
+}
+
+/// The meanings of the bits set in `value` for the flag table of `xy`.
+///
+/// `width` is the data width in bits of the descriptor. Descriptors without an included table
+/// (see [`FlagTableEntry`]) don't have any meanings.
+pub fn flag_meanings(xy: XY, value: u32, width: u16) -> impl Iterator<Item = &'static str> {
+    let start = FLAG_TABLES.partition_point(|e| (e.xy.x, e.xy.y) < (xy.x, xy.y));
+    FLAG_TABLES[start..]
+        .iter()
+        .take_while(move |e| e.xy == xy)
+        .filter(move |e| {
+            u16::from(e.bit) <= width && value & (1 << (width - u16::from(e.bit))) != 0
+        })
+        .map(|e| e.meaning)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        assert!(TABLE_VERSIONS.is_sorted_by_key(|v| (v.master, v.local)));
+        assert_eq!(table_version_index(0, 0), 0);
+        // the version of current SYNOP bulletins from DWD
+        let current = &TABLE_VERSIONS[table_version_index(31, 8)];
+        assert_eq!((current.master, current.local), (31, 8));
+        assert_eq!(
+            table_version_index(u8::MAX, u8::MAX),
+            TABLE_VERSIONS.len() - 1
+        );
+        assert!(CODE_TABLES.is_sorted_by_key(|e| (e.xy.x, e.xy.y, e.code)));
+        assert!(FLAG_TABLES.is_sorted_by_key(|e| (e.xy.x, e.xy.y, e.bit)));
+
+        let present_weather = XY { x: 20, y: 3 };
+        assert_eq!(
+            code_meaning(present_weather, 161),
+            Some("Rain, not freezing, slight")
+        );
+        assert_eq!(code_meaning(present_weather, 106), None);
+        assert_eq!(
+            flag_meanings(XY { x: 2, y: 2 }, 0b1100, 4).collect::<Vec<_>>(),
+            ["Certified instruments", "Originally measured in knots"]
+        );
+
+        // tables that aren't included
+        assert_eq!(code_meaning(XY { x: 8, y: 2 }, 1), None);
+        assert_eq!(code_meaning(XY { x: 20, y: 12 }, 0), None);
+        assert_eq!(flag_meanings(XY { x: 2, y: 1 }, 0b11, 2).count(), 0);
+    }
+
+    #[test]
+    fn newer_versions() {
+        let version = |master, local| TableVersion {
+            master,
+            local,
+            table_b: &[],
+            table_d: &[],
+        };
+        let versions = [version(0, 0), version(0, 3), version(26, 1)];
+
+        assert_eq!(version_index(&versions, 0, 0), 0);
+        assert_eq!(version_index(&versions, 0, 2), 0);
+        // a higher local version
+        assert_eq!(version_index(&versions, 0, 3), 1);
+        assert_eq!(version_index(&versions, 0, 200), 1);
+        // a higher master version
+        assert_eq!(version_index(&versions, 14, 0), 1);
+        assert_eq!(version_index(&versions, 26, 1), 2);
+        assert_eq!(version_index(&versions, 40, 0), 2);
+        // older than all versions
+        assert_eq!(version_index(&versions[1..], 0, 0), 0);
+    }
+}
@@ -0,0 +1,439 @@
+use std::slice;
+
+use dwd_bufr_tables::TABLE_VERSIONS;
+use tinybufr::{Descriptor, TableBEntry, Tables, Value, XY};
+
+use crate::{CHARACTER_UNIT, Element, Error, Node, Subset, VersionedTables};
+
+/// Writes uncompressed BUFR edition 4 messages (e.g. to create fixtures).
+///
+/// The subsets must have the shape [`Message::read`](crate::Message::read) produces: a
+/// [`Node::Sequence`] for each Table D descriptor, a [`Node::Replication`] for each replication
+/// and a [`Node::Data`] for each element. Delayed replication factors are the number of items.
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    pub centre: u16,
+    pub sub_centre: u16,
+    pub data_category: u8,
+    pub international_data_sub_category: u8,
+    pub local_data_sub_category: u8,
+    pub master_table_version: u8,
+    pub local_tables_version: u8,
+    pub typical_time: jiff::civil::DateTime,
+    pub descriptors: Vec<Descriptor>,
+}
+
+/// Offenbach
+const DWD_CENTRE: u16 = 78;
+/// Surface data - land
+const SURFACE_LAND: u8 = 0;
+
+impl Encoder {
+    /// An encoder for observations from DWD using the newest tables.
+    pub fn new(descriptors: Vec<Descriptor>, typical_time: jiff::civil::DateTime) -> Self {
+        let newest = TABLE_VERSIONS.last().expect("no table versions");
+        Self {
+            centre: DWD_CENTRE,
+            sub_centre: 0,
+            data_category: SURFACE_LAND,
+            international_data_sub_category: 0,
+            local_data_sub_category: 0,
+            master_table_version: newest.master,
+            local_tables_version: newest.local,
+            typical_time,
+            descriptors,
+        }
+    }
+
+    /// A subset with all values missing, to be filled with [`Subset::set`].
+    ///
+    /// Delayed replications get `replications` items.
+    pub fn missing_subset(
+        &self,
+        tables: &VersionedTables,
+        replications: usize,
+    ) -> Result<Subset, Error> {
+        let tables = tables.for_version(self.master_table_version, self.local_tables_version);
+        Ok(Subset {
+            nodes: missing_nodes(&self.descriptors, tables, replications)?,
+        })
+    }
+
+    /// Encodes a message from `BUFR` to `7777`.
+    pub fn encode(&self, tables: &VersionedTables, subsets: &[Subset]) -> Result<Vec<u8>, Error> {
+        let tables = tables.for_version(self.master_table_version, self.local_tables_version);
+        let mut data = DataWriter {
+            tables,
+            bits: BitWriter::default(),
+            width_offset: 0,
+            scale_offset: 0,
+        };
+        for subset in subsets {
+            data.width_offset = 0;
+            data.scale_offset = 0;
+            let mut nodes = subset.nodes.iter();
+            data.write_descriptors(&self.descriptors, &mut nodes)?;
+            expect_end(&mut nodes)?;
+        }
+
+        let time = self.typical_time;
+        let mut identification = vec![0];
+        identification.extend(self.centre.to_be_bytes());
+        identification.extend(self.sub_centre.to_be_bytes());
+        identification.extend([
+            0, // update sequence number
+            0, // no optional section
+            self.data_category,
+            self.international_data_sub_category,
+            self.local_data_sub_category,
+            self.master_table_version,
+            self.local_tables_version,
+        ]);
+        identification.extend((time.year() as u16).to_be_bytes());
+        identification
+            .extend([time.month(), time.day(), time.hour(), time.minute()].map(|v| v as u8));
+        identification.push(time.second() as u8);
+
+        let number_of_subsets =
+            u16::try_from(subsets.len()).map_err(|_| encode_error("too many subsets"))?;
+        let mut description = vec![0];
+        description.extend(number_of_subsets.to_be_bytes());
+        description.push(0b1000_0000); // observed, not compressed
+        for d in &self.descriptors {
+            let fxy = u16::from(d.f) << 14 | u16::from(d.x) << 8 | u16::from(d.y);
+            description.extend(fxy.to_be_bytes());
+        }
+
+        let mut data_section = vec![0];
+        data_section.extend(data.bits.finish());
+
+        let mut sections = Vec::new();
+        for body in [identification, description, data_section] {
+            push_section(&mut sections, &body)?;
+        }
+        let total = u32::try_from(8 + sections.len() + 4)
+            .ok()
+            .filter(|len| *len < 1 << 24)
+            .ok_or_else(|| encode_error("message too long"))?;
+
+        let mut message = b"BUFR".to_vec();
+        message.extend(&total.to_be_bytes()[1..]);
+        message.push(4);
+        message.extend(sections);
+        message.extend(b"7777");
+        Ok(message)
+    }
+}
+
+fn encode_error(msg: impl Into<String>) -> Error {
+    Error::Encode(msg.into())
+}
+
+fn push_section(out: &mut Vec<u8>, body: &[u8]) -> Result<(), Error> {
+    let len = u32::try_from(body.len() + 3)
+        .ok()
+        .filter(|len| *len < 1 << 24)
+        .ok_or_else(|| encode_error("section too long"))?;
+    out.extend(&len.to_be_bytes()[1..]);
+    out.extend(body);
+    Ok(())
+}
+
+fn missing_nodes(
+    descriptors: &[Descriptor],
+    tables: &Tables,
+    replications: usize,
+) -> Result<Vec<Node>, Error> {
+    let mut nodes = Vec::new();
+    let mut pos = 0;
+    while let Some(d) = descriptors.get(pos) {
+        pos += 1;
+        match d.f {
+            0 => nodes.push(Node::Data(Element {
+                xy: d.xy(),
+                value: Value::Missing,
+                entry: tables.table_b.get(&d.xy()).copied(),
+            })),
+            1 => {
+                let count = if d.y == 0 {
+                    pos += 1; // the delayed replication factor
+                    replications
+                } else {
+                    d.y.into()
+                };
+                let replicated = descriptors
+                    .get(pos..pos + usize::from(d.x))
+                    .ok_or_else(|| encode_error("replication out of bounds"))?;
+                pos += replicated.len();
+                nodes.push(Node::Replication {
+                    items: (0..count)
+                        .map(|_| missing_nodes(replicated, tables, replications))
+                        .collect::<Result<_, _>>()?,
+                });
+            }
+            2 => (),
+            _ => {
+                let entry = table_d(tables, d.xy())?;
+                nodes.push(Node::Sequence {
+                    xy: d.xy(),
+                    children: missing_nodes(entry.elements, tables, replications)?,
+                });
+            }
+        }
+    }
+    Ok(nodes)
+}
+
+fn table_d(tables: &Tables, xy: XY) -> Result<&'static tinybufr::TableDEntry, Error> {
+    tables
+        .table_d
+        .get(&xy)
+        .copied()
+        .ok_or_else(|| encode_error(format!("no Table D entry for 3{:02}{:03}", xy.x, xy.y)))
+}
+
+type Nodes<'a> = slice::Iter<'a, Node>;
+
+fn expect_end(nodes: &mut Nodes<'_>) -> Result<(), Error> {
+    match nodes.next() {
+        Some(_) => Err(encode_error("more nodes than descriptors")),
+        None => Ok(()),
+    }
+}
+
+/// Writes the values of subsets, tracking the operators like the reader does.
+struct DataWriter<'a> {
+    tables: &'a Tables,
+    bits: BitWriter,
+    width_offset: i16,
+    scale_offset: i16,
+}
+
+impl DataWriter<'_> {
+    fn write_descriptors(
+        &mut self,
+        descriptors: &[Descriptor],
+        nodes: &mut Nodes<'_>,
+    ) -> Result<(), Error> {
+        let mut pos = 0;
+        while let Some(d) = descriptors.get(pos) {
+            pos += 1;
+            match d.f {
+                0 => {
+                    let Some(Node::Data(element)) = nodes.next() else {
+                        return Err(mismatch(d));
+                    };
+                    if element.xy != d.xy() {
+                        return Err(mismatch(d));
+                    }
+                    self.write_element(element)?;
+                }
+                1 => {
+                    let factor = if d.y == 0 {
+                        let factor = descriptors.get(pos).ok_or_else(|| mismatch(d))?;
+                        pos += 1;
+                        Some(match (factor.x, factor.y) {
+                            (31, 0) => 1,
+                            (31, 1 | 3) => 8,
+                            (31, 2) => 16,
+                            _ => return Err(mismatch(factor)),
+                        })
+                    } else {
+                        None
+                    };
+                    let replicated = descriptors
+                        .get(pos..pos + usize::from(d.x))
+                        .ok_or_else(|| mismatch(d))?;
+                    pos += replicated.len();
+
+                    let Some(Node::Replication { items }) = nodes.next() else {
+                        return Err(mismatch(d));
+                    };
+                    match factor {
+                        Some(width) if (items.len() as u64) < 1 << width => {
+                            self.bits.write(items.len() as u64, width)
+                        }
+                        None if items.len() == usize::from(d.y) => (),
+                        _ => return Err(mismatch(d)),
+                    }
+                    for item in items {
+                        let mut item = item.iter();
+                        self.write_descriptors(replicated, &mut item)?;
+                        expect_end(&mut item)?;
+                    }
+                }
+                2 => match (d.x, d.y) {
+                    (1, 0) => self.width_offset = 0,
+                    (1, y) => self.width_offset = i16::from(y) - 128,
+                    (2, 0) => self.scale_offset = 0,
+                    (2, y) => self.scale_offset = i16::from(y) - 128,
+                    _ => return Err(encode_error(format!("unsupported operator {d:?}"))),
+                },
+                _ => {
+                    let Some(Node::Sequence { xy, children }) = nodes.next() else {
+                        return Err(mismatch(d));
+                    };
+                    if *xy != d.xy() {
+                        return Err(mismatch(d));
+                    }
+                    let entry = table_d(self.tables, d.xy())?;
+                    let mut children = children.iter();
+                    self.write_descriptors(entry.elements, &mut children)?;
+                    expect_end(&mut children)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_element(&mut self, element: &Element) -> Result<(), Error> {
+        let entry: &TableBEntry = self
+            .tables
+            .table_b
+            .get(&element.xy)
+            .ok_or_else(|| encode_error(format!("no Table B entry for {:?}", element.xy)))?;
+        let width = u32::try_from(i32::from(entry.bits) + i32::from(self.width_offset))
+            .map_err(|_| encode_error("negative data width"))?;
+        let out_of_range = || {
+            encode_error(format!(
+                "{:?} is out of range for 0{:02}{:03}",
+                element.value, element.xy.x, element.xy.y
+            ))
+        };
+
+        if entry.unit == CHARACTER_UNIT {
+            // padded with spaces
+            if width % 8 != 0 {
+                return Err(encode_error(format!("invalid character width {width}")));
+            }
+            let bytes = (width / 8) as usize;
+            match &element.value {
+                Value::Missing => self.bits.write_bytes(&vec![0xff; bytes]),
+                Value::String(s) if s.len() <= bytes => {
+                    let mut padded = s.as_bytes().to_vec();
+                    padded.resize(bytes, b' ');
+                    self.bits.write_bytes(&padded);
+                }
+                _ => return Err(out_of_range()),
+            }
+            return Ok(());
+        }
+
+        let all_ones = (1u64 << width) - 1;
+        let (mantissa, exponent) = match element.value {
+            Value::Missing => {
+                self.bits.write(all_ones, width);
+                return Ok(());
+            }
+            Value::Integer(v) => (i64::from(v), 0),
+            Value::Decimal(v, s) => (i64::from(v), i32::from(s)),
+            Value::String(_) => return Err(out_of_range()),
+        };
+        // the value in units of 10^-scale
+        let exponent = exponent + i32::from(entry.scale) + i32::from(self.scale_offset);
+        let scaled = if exponent >= 0 {
+            mantissa.checked_mul(
+                10i64
+                    .checked_pow(exponent as u32)
+                    .ok_or_else(out_of_range)?,
+            )
+        } else {
+            10i64
+                .checked_pow(exponent.unsigned_abs())
+                .map(|div| (mantissa as f64 / div as f64).round() as i64)
+        };
+        let raw = scaled
+            .and_then(|v| v.checked_sub(entry.reference_value.into()))
+            .and_then(|raw| u64::try_from(raw).ok())
+            .filter(|raw| *raw < all_ones)
+            .ok_or_else(out_of_range)?;
+        self.bits.write(raw, width);
+        Ok(())
+    }
+}
+
+fn mismatch(d: &Descriptor) -> Error {
+    encode_error(format!("subset doesn't match descriptor {d:?}"))
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    /// Number of bits used in the last byte (0 if it's full)
+    used: u32,
+}
+
+impl BitWriter {
+    /// Writes the lowest `width` bits of `value` (most significant first).
+    fn write(&mut self, value: u64, width: u32) {
+        for bit in (0..width).rev() {
+            if self.used == 0 {
+                self.bytes.push(0);
+            }
+            if value >> bit & 1 != 0 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> self.used;
+            }
+            self.used = (self.used + 1) % 8;
+        }
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.write((*b).into(), 8);
+        }
+    }
+
+    /// The bytes with the last one padded with zeros.
+    fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tinybufr::Value;
+
+    use super::*;
+    use crate::{Message, Query};
+
+    const ICAO_LOCATION: XY = XY { x: 1, y: 192 };
+    const STATION_NAME: XY = XY { x: 1, y: 15 };
+    const HOUR: XY = XY { x: 4, y: 4 };
+
+    #[test]
+    fn round_trip_strings() {
+        let tables = VersionedTables::new();
+        let encoder = Encoder::new(
+            [ICAO_LOCATION, STATION_NAME, HOUR]
+                .map(|xy| Descriptor {
+                    f: 0,
+                    x: xy.x,
+                    y: xy.y,
+                })
+                .to_vec(),
+            jiff::civil::date(2026, 10, 18).at(12, 0, 0, 0),
+        );
+        let mut subset = encoder.missing_subset(&tables, 0).unwrap();
+        // 32 bits
+        assert!(subset.set(&Query::new(ICAO_LOCATION), 0, Value::String("EDDH".into())));
+        assert!(subset.set(
+            &Query::new(STATION_NAME),
+            0,
+            Value::String("Hamburg".into())
+        ));
+        assert!(subset.set(&Query::new(HOUR), 0, Value::Integer(12)));
+        let bytes = encoder.encode(&tables, &[subset]).unwrap();
+
+        let (message, rest) = Message::read(bytes.as_slice(), &tables).unwrap();
+        assert_eq!(rest, b"7777");
+        let subset = &message.subsets[0];
+        assert_eq!(subset.get(ICAO_LOCATION).unwrap().as_str(), Some("EDDH"));
+        assert_eq!(subset.get(STATION_NAME).unwrap().as_str(), Some("Hamburg"));
+        assert_eq!(subset.get(HOUR).unwrap().as_i32(), Some(12));
+
+        // numbers aren't characters
+        let mut subset = encoder.missing_subset(&tables, 0).unwrap();
+        assert!(subset.set(&Query::new(ICAO_LOCATION), 0, Value::Integer(1)));
+        assert!(encoder.encode(&tables, &[subset]).is_err());
+    }
+}
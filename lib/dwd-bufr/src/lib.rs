@@ -0,0 +1,344 @@
+use std::io::Read;
+
+use dwd_bufr_tables::{
+    TABLE_VERSIONS, TableVersion, code_meaning, flag_meanings, table_version_index,
+};
+use tinybufr::{DataEvent, DataReader, DataSpec, HeaderSections, TableBEntry, Tables, Value, XY};
+
+mod encode;
+mod query;
+
+pub use encode::Encoder;
+pub use query::{Condition, Match, Query};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("BUFR error: {0}")]
+    Bufr(#[from] tinybufr::Error),
+    #[error("unbalanced data events")]
+    Unbalanced,
+    #[error("invalid query: {0}")]
+    InvalidQuery(String),
+    #[error("cannot encode: {0}")]
+    Encode(String),
+}
+
+/// The unit of character (string) elements in Table B.
+const CHARACTER_UNIT: &str = "CCITT IA5";
+
+/// The standard tables extended by DWD's local descriptors, for each of the [`TABLE_VERSIONS`].
+pub struct VersionedTables {
+    tables: Vec<Tables>,
+}
+
+impl VersionedTables {
+    pub fn new() -> Self {
+        Self {
+            tables: TABLE_VERSIONS.iter().map(make_tables).collect(),
+        }
+    }
+
+    /// The tables for the versions in the identification section of a message.
+    pub fn get(&self, header: &HeaderSections) -> &Tables {
+        let id = &header.identification_section;
+        self.for_version(id.master_table_version, id.local_tables_version)
+    }
+
+    pub fn for_version(&self, master: u8, local: u8) -> &Tables {
+        &self.tables[table_version_index(master, local)]
+    }
+}
+
+impl Default for VersionedTables {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn make_tables(version: &'static TableVersion) -> Tables {
+    let mut tables = Tables::default();
+    for it in version.table_b {
+        tables.table_b.insert(it.xy, it);
+    }
+    for it in version.table_d {
+        tables.table_d.insert(it.xy, it);
+    }
+    tables
+}
+
+/// A decoded BUFR message.
+#[derive(Debug)]
+pub struct Message {
+    pub header: HeaderSections,
+    pub subsets: Vec<Subset>,
+}
+
+/// The descriptors of a single subset (e.g. one station).
+#[derive(Debug, Default)]
+pub struct Subset {
+    pub nodes: Vec<Node>,
+}
+
+#[derive(Debug)]
+pub enum Node {
+    Data(Element),
+    Sequence { xy: XY, children: Vec<Node> },
+    Replication { items: Vec<Vec<Node>> },
+}
+
+/// A single value together with its Table B entry.
+#[derive(Debug, Clone)]
+pub struct Element {
+    pub xy: XY,
+    pub value: Value,
+    pub entry: Option<&'static TableBEntry>,
+}
+
+impl Message {
+    /// Reads the message up to the end section (`7777`), which is left in the returned reader.
+    pub fn read<R: Read>(mut r: R, tables: &VersionedTables) -> Result<(Self, R), Error> {
+        let header = HeaderSections::read(&mut r)?;
+        let tables = tables.get(&header);
+        let data_spec = DataSpec::from_data_description(&header.data_description_section, tables)?;
+        let mut data_reader = DataReader::new(r, &data_spec)?;
+
+        let mut subsets = Vec::new();
+        let mut stack = Vec::new();
+        loop {
+            match data_reader.read_event()? {
+                DataEvent::SubsetStart(_) => stack.push(Frame::Nodes(None, Vec::new())),
+                DataEvent::SequenceStart { xy, .. } => {
+                    stack.push(Frame::Nodes(Some(xy), Vec::new()))
+                }
+                DataEvent::ReplicationItemStart => stack.push(Frame::Nodes(None, Vec::new())),
+                DataEvent::ReplicationStart { count, .. } => {
+                    stack.push(Frame::Replication(Vec::with_capacity(count.into())))
+                }
+                DataEvent::SubsetEnd => match stack.pop() {
+                    Some(Frame::Nodes(None, nodes)) if stack.is_empty() => {
+                        subsets.push(Subset { nodes })
+                    }
+                    _ => return Err(Error::Unbalanced),
+                },
+                DataEvent::SequenceEnd => match stack.pop() {
+                    Some(Frame::Nodes(Some(xy), children)) => {
+                        push_node(&mut stack, Node::Sequence { xy, children })?
+                    }
+                    _ => return Err(Error::Unbalanced),
+                },
+                DataEvent::ReplicationItemEnd => match (stack.pop(), stack.last_mut()) {
+                    (Some(Frame::Nodes(None, item)), Some(Frame::Replication(items))) => {
+                        items.push(item)
+                    }
+                    _ => return Err(Error::Unbalanced),
+                },
+                DataEvent::ReplicationEnd => match stack.pop() {
+                    Some(Frame::Replication(items)) => {
+                        push_node(&mut stack, Node::Replication { items })?
+                    }
+                    _ => return Err(Error::Unbalanced),
+                },
+                DataEvent::Data { xy, value, .. } => {
+                    let entry = tables.table_b.get(&xy).copied();
+                    push_node(
+                        &mut stack,
+                        Node::Data(Element {
+                            xy,
+                            value: short_string(entry, value),
+                            entry,
+                        }),
+                    )?
+                }
+                DataEvent::Eof => break,
+                _ => (),
+            }
+        }
+        if !stack.is_empty() {
+            return Err(Error::Unbalanced);
+        }
+
+        let r = data_reader.into_inner();
+        Ok((Self { header, subsets }, r))
+    }
+}
+
+/// tinybufr reads characters of up to 32 bits as numbers.
+fn short_string(entry: Option<&TableBEntry>, value: Value) -> Value {
+    match (entry, value) {
+        (Some(e), Value::Integer(raw)) if e.unit == CHARACTER_UNIT => {
+            let bytes = (raw as u32).to_be_bytes();
+            let len = usize::from(e.bits / 8).min(bytes.len());
+            Value::String(String::from_utf8_lossy(&bytes[bytes.len() - len..]).into_owned())
+        }
+        (_, value) => value,
+    }
+}
+
+enum Frame {
+    /// A subset, sequence (with its descriptor) or replication item
+    Nodes(Option<XY>, Vec<Node>),
+    Replication(Vec<Vec<Node>>),
+}
+
+fn push_node(stack: &mut [Frame], node: Node) -> Result<(), Error> {
+    match stack.last_mut() {
+        Some(Frame::Nodes(_, nodes)) => {
+            nodes.push(node);
+            Ok(())
+        }
+        _ => Err(Error::Unbalanced),
+    }
+}
+
+impl Subset {
+    /// The first element with the descriptor `xy`.
+    pub fn get(&self, xy: XY) -> Option<&Element> {
+        self.elements().find(|(e, _)| e.xy == xy).map(|(e, _)| e)
+    }
+
+    /// Sets the value of the `n`-th element matching `query`.
+    ///
+    /// Returns `false` if there's no such element.
+    pub fn set(&mut self, query: &Query, n: usize, value: Value) -> bool {
+        let Some(target) = self
+            .query(query)
+            .get(n)
+            .map(|m| std::ptr::from_ref(m.element))
+        else {
+            return false;
+        };
+        let mut elements = Vec::new();
+        collect_elements_mut(&mut self.nodes, &mut elements);
+        match elements.into_iter().find(|e| std::ptr::eq(&**e, target)) {
+            Some(element) => {
+                element.value = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// All elements in order and whether they're part of a replication.
+    pub fn elements(&self) -> impl Iterator<Item = (&Element, bool)> {
+        let mut out = Vec::new();
+        collect_elements(&self.nodes, false, &mut out);
+        out.into_iter()
+    }
+}
+
+fn collect_elements<'a>(nodes: &'a [Node], replicated: bool, out: &mut Vec<(&'a Element, bool)>) {
+    for node in nodes {
+        match node {
+            Node::Data(e) => out.push((e, replicated)),
+            Node::Sequence { children, .. } => collect_elements(children, replicated, out),
+            Node::Replication { items } => {
+                for item in items {
+                    collect_elements(item, true, out);
+                }
+            }
+        }
+    }
+}
+
+fn collect_elements_mut<'a>(nodes: &'a mut [Node], out: &mut Vec<&'a mut Element>) {
+    for node in nodes {
+        match node {
+            Node::Data(e) => out.push(e),
+            Node::Sequence { children, .. } => collect_elements_mut(children, out),
+            Node::Replication { items } => {
+                for item in items {
+                    collect_elements_mut(item, out);
+                }
+            }
+        }
+    }
+}
+
+impl Element {
+    /// The scaled value (in the unit of the Table B entry).
+    pub fn as_f32(&self) -> Option<f32> {
+        match self.value {
+            Value::Integer(i) => Some(i as f32),
+            Value::Decimal(v, s) => Some(v as f32 * 10f32.powi(s as i32)),
+            _ => None,
+        }
+    }
+
+    pub fn as_i32(&self) -> Option<i32> {
+        match self.value {
+            Value::Integer(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// The string value without the padding.
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.value {
+            Value::String(s) => Some(s.trim_ascii_end()),
+            _ => None,
+        }
+    }
+
+    pub fn is_missing(&self) -> bool {
+        matches!(self.value, Value::Missing)
+    }
+
+    pub fn unit(&self) -> Option<&'static str> {
+        self.entry.map(|e| e.unit)
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        self.entry.map(|e| e.element_name)
+    }
+
+    /// The meaning of the value in the code table or the meanings of the set bits in the flag
+    /// table of the descriptor.
+    pub fn meanings(&self) -> Vec<&'static str> {
+        let Some(value) = self.as_i32().and_then(|v| u32::try_from(v).ok()) else {
+            return Vec::new();
+        };
+        if let Some(meaning) = code_meaning(self.xy, value) {
+            return vec![meaning];
+        }
+        match self.entry {
+            Some(e) if e.unit.eq_ignore_ascii_case("flag table") => {
+                flag_meanings(self.xy, value, e.bits).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A SYNOP bulletin from DWD's open data server (see `testdata/LICENSE`)
+    const SYNOP: &[u8] = include_bytes!("../testdata/synop_ISGD01_EDZW_2025_08_07_11_10.bufr");
+
+    #[test]
+    fn current_dwd_tables() {
+        let (message, mut rest) = Message::read(SYNOP, &VersionedTables::new()).unwrap();
+        let id = &message.header.identification_section;
+        assert_eq!((id.master_table_version, id.local_tables_version), (31, 8));
+        let version = &TABLE_VERSIONS[table_version_index(31, 8)];
+        assert_eq!((version.master, version.local), (31, 8));
+
+        // the local descriptors have the right widths if the whole message is read
+        let mut end = Vec::new();
+        rest.read_to_end(&mut end).unwrap();
+        assert_eq!(end, b"7777");
+
+        let subset = &message.subsets[0];
+        assert_eq!(
+            subset.get(XY { x: 1, y: 128 }).and_then(Element::as_str),
+            Some("10020")
+        );
+        let temperature = Query::new(XY { x: 12, y: 101 }).under(XY { x: 7, y: 32 }, 2.0);
+        let temperature = subset.first(&temperature).and_then(|m| m.value()).unwrap();
+        assert!((temperature - 292.05).abs() < 1e-3);
+        // DWD's local 020238
+        let local = subset.get(XY { x: 20, y: 238 }).and_then(Element::as_i32);
+        assert_eq!(local, Some(31440));
+    }
+}
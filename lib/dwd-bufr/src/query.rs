@@ -0,0 +1,247 @@
+use std::{fmt, str::FromStr};
+
+use tinybufr::XY;
+
+use crate::{Element, Error, Subset};
+
+const MAX_CONDITIONS: usize = 2;
+
+/// Selects elements of a subset by their descriptor and the context they're reported in.
+///
+/// BUFR qualifies values by preceding elements - e.g. a temperature (`012101`) is measured at
+/// the height of the last sensor height (`007032`). The string form is
+/// `012101 under 007032=2.0 and 004024`, where a condition without a value matches any
+/// non-missing value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Query {
+    pub target: XY,
+    conditions: [Option<Condition>; MAX_CONDITIONS],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Condition {
+    pub xy: XY,
+    pub value: Option<f32>,
+}
+
+/// An element matched by a [`Query`].
+#[derive(Debug, Clone, Copy)]
+pub struct Match<'a> {
+    pub element: &'a Element,
+    /// Whether the element is part of a replication
+    pub replicated: bool,
+    context: [Option<&'a Element>; MAX_CONDITIONS],
+    query: &'a Query,
+}
+
+impl Query {
+    pub const fn new(target: XY) -> Self {
+        Self {
+            target,
+            conditions: [None; MAX_CONDITIONS],
+        }
+    }
+
+    /// Only match if the last `xy` before the target is `value`.
+    pub const fn under(self, xy: XY, value: f32) -> Self {
+        self.with(Condition {
+            xy,
+            value: Some(value),
+        })
+    }
+
+    /// Only match if the last `xy` before the target isn't missing.
+    pub const fn under_any(self, xy: XY) -> Self {
+        self.with(Condition { xy, value: None })
+    }
+
+    const fn with(mut self, condition: Condition) -> Self {
+        let mut i = 0;
+        while i < MAX_CONDITIONS {
+            if self.conditions[i].is_none() {
+                self.conditions[i] = Some(condition);
+                return self;
+            }
+            i += 1;
+        }
+        panic!("too many conditions");
+    }
+
+    pub fn conditions(&self) -> impl Iterator<Item = &Condition> {
+        self.conditions.iter().flatten()
+    }
+}
+
+impl Condition {
+    fn matches(&self, element: Option<&Element>) -> bool {
+        let Some(element) = element.filter(|e| !e.is_missing()) else {
+            return false;
+        };
+        match self.value {
+            Some(value) => element.as_f32().is_some_and(|v| (v - value).abs() < 1e-3),
+            None => true,
+        }
+    }
+}
+
+impl Subset {
+    /// All elements matching `query` in order.
+    pub fn query<'a>(&'a self, query: &'a Query) -> Vec<Match<'a>> {
+        let mut context = [None; MAX_CONDITIONS];
+        let mut matches = Vec::new();
+        for (element, replicated) in self.elements() {
+            if element.xy == query.target
+                && query
+                    .conditions
+                    .iter()
+                    .zip(&context)
+                    .all(|(c, e)| c.is_none_or(|c| c.matches(*e)))
+            {
+                matches.push(Match {
+                    element,
+                    replicated,
+                    context,
+                    query,
+                });
+            }
+            for (c, e) in query.conditions.iter().zip(&mut context) {
+                if c.is_some_and(|c| c.xy == element.xy) {
+                    *e = Some(element);
+                }
+            }
+        }
+        matches
+    }
+
+    /// The first element matching `query`.
+    pub fn first<'a>(&'a self, query: &'a Query) -> Option<Match<'a>> {
+        self.query(query).into_iter().next()
+    }
+}
+
+impl Match<'_> {
+    pub fn value(&self) -> Option<f32> {
+        self.element.as_f32()
+    }
+
+    /// The element of a condition this match was found under.
+    pub fn context(&self, xy: XY) -> Option<&Element> {
+        self.query
+            .conditions
+            .iter()
+            .zip(&self.context)
+            .find(|(c, _)| c.is_some_and(|c| c.xy == xy))
+            .and_then(|(_, e)| *e)
+    }
+}
+
+fn parse_xy(s: &str) -> Option<XY> {
+    let s = s.strip_prefix('0')?;
+    if s.len() != 5 || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(XY {
+        x: s[..2].parse().ok()?,
+        y: s[2..].parse().ok()?,
+    })
+}
+
+impl FromStr for Query {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidQuery(s.to_owned());
+        let (target, conditions) = match s.split_once(" under ") {
+            Some((target, conditions)) => (target, Some(conditions)),
+            None => (s, None),
+        };
+        let mut query = Query::new(parse_xy(target.trim()).ok_or_else(invalid)?);
+        for (i, condition) in conditions
+            .into_iter()
+            .flat_map(|c| c.split(" and "))
+            .enumerate()
+        {
+            if i >= MAX_CONDITIONS {
+                return Err(invalid());
+            }
+            let (xy, value) = match condition.split_once('=') {
+                Some((xy, value)) => (xy, Some(value.trim().parse().map_err(|_| invalid())?)),
+                None => (condition, None),
+            };
+            query.conditions[i] = Some(Condition {
+                xy: parse_xy(xy.trim()).ok_or_else(invalid)?,
+                value,
+            });
+        }
+        Ok(query)
+    }
+}
+
+struct Descriptor(XY);
+
+impl fmt::Display for Descriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0{:02}{:03}", self.0.x, self.0.y)
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Descriptor(self.target))?;
+        for (i, c) in self.conditions().enumerate() {
+            f.write_str(if i == 0 { " under " } else { " and " })?;
+            write!(f, "{}", Descriptor(c.xy))?;
+            if let Some(value) = c.value {
+                write!(f, "={value:?}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tinybufr::Value;
+
+    use super::*;
+    use crate::Node;
+
+    fn data(x: u8, y: u8, value: Value) -> Node {
+        Node::Data(Element {
+            xy: XY { x, y },
+            value,
+            entry: None,
+        })
+    }
+
+    #[test]
+    fn query_under_context() {
+        let subset = Subset {
+            nodes: vec![
+                data(7, 32, Value::Decimal(50, -2)),
+                data(12, 101, Value::Decimal(28000, -2)),
+                data(7, 32, Value::Decimal(200, -2)),
+                data(12, 101, Value::Decimal(28515, -2)),
+                data(7, 32, Value::Missing),
+                data(12, 101, Value::Decimal(28600, -2)),
+            ],
+        };
+        let query: Query = "012101 under 007032=2.0".parse().unwrap();
+        assert_eq!(query.to_string(), "012101 under 007032=2.0");
+
+        let matches = subset.query(&query);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].value(), Some(285.15));
+        assert_eq!(
+            matches[0]
+                .context(XY { x: 7, y: 32 })
+                .and_then(Element::as_f32),
+            Some(2.0)
+        );
+        assert_eq!(subset.query(&"012101".parse().unwrap()).len(), 3);
+        assert_eq!(
+            subset.query(&"012101 under 007032".parse().unwrap()).len(),
+            2
+        );
+    }
+}
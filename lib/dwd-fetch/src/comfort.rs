@@ -0,0 +1,127 @@
+//! Quantities derived from temperature, humidity and wind.
+//!
+//! Temperatures are in °C, humidities in % and wind speeds in km/h like in [`Datapoint`].
+
+use crate::Datapoint;
+
+/// Magnus coefficients over water (Sonntag 1990)
+const MAGNUS_A: f32 = 17.62;
+const MAGNUS_B: f32 = 243.12;
+
+/// Saturation vapour pressure in hPa.
+fn saturation_pressure(t: f32) -> f32 {
+    6.112 * (MAGNUS_A * t / (MAGNUS_B + t)).exp()
+}
+
+pub fn dew_point(t: f32, rh: f32) -> f32 {
+    let gamma = (rh / 100.0).ln() + MAGNUS_A * t / (MAGNUS_B + t);
+    MAGNUS_B * gamma / (MAGNUS_A - gamma)
+}
+
+pub fn relative_humidity(t: f32, dew_point: f32) -> f32 {
+    (100.0 * saturation_pressure(dew_point) / saturation_pressure(t)).min(100.0)
+}
+
+/// Water vapour in g/m³.
+pub fn absolute_humidity(t: f32, rh: f32) -> f32 {
+    // e / (R_w * T) with e in Pa
+    let vapour_pressure = saturation_pressure(t) * rh;
+    vapour_pressure / (461.5 * (t + 273.15)) * 1000.0
+}
+
+/// Wind chill (JAG/TI), only defined up to 10°C and above 5 km/h.
+pub fn wind_chill(t: f32, wind: f32) -> Option<f32> {
+    if t > 10.0 || wind <= 5.0 {
+        return None;
+    }
+    let v = wind.powf(0.16);
+    Some(13.12 + 0.6215 * t - 11.37 * v + 0.3965 * t * v)
+}
+
+/// Heat index (Rothfusz), only defined from 27°C and 40% humidity.
+pub fn heat_index(t: f32, rh: f32) -> Option<f32> {
+    if t < 27.0 || rh < 40.0 {
+        return None;
+    }
+    let f = t * 9.0 / 5.0 + 32.0;
+    let hi = -42.379 + 2.049_015_2 * f + 10.143_332 * rh
+        - 0.224_755_4 * f * rh
+        - 6.837_83e-3 * f * f
+        - 5.481_717e-2 * rh * rh
+        + 1.228_74e-3 * f * f * rh
+        + 8.528_2e-4 * f * rh * rh
+        - 1.99e-6 * f * f * rh * rh;
+    Some((hi - 32.0) * 5.0 / 9.0)
+}
+
+/// The wind chill or heat index if defined, otherwise the temperature itself.
+pub fn apparent_temperature(t: f32, rh: Option<f32>, wind: Option<f32>) -> f32 {
+    wind.and_then(|w| wind_chill(t, w))
+        .or_else(|| rh.and_then(|rh| heat_index(t, rh)))
+        .unwrap_or(t)
+}
+
+/// Upper bounds of the Beaufort forces 0 - 11 in km/h
+const BEAUFORT_LIMITS: [f32; 12] = [
+    1.8, 5.4, 11.9, 19.8, 28.4, 38.5, 49.7, 61.6, 74.5, 87.8, 102.2, 117.4,
+];
+
+pub fn beaufort(wind: f32) -> u8 {
+    BEAUFORT_LIMITS.partition_point(|limit| wind >= *limit) as u8
+}
+
+impl Datapoint {
+    /// The reported dew point or the one derived from the humidity.
+    pub fn dew_point_or_derived(&self) -> Option<f32> {
+        self.dew_point
+            .or_else(|| Some(dew_point(self.temperature?, self.relative_humidity?)))
+    }
+
+    /// The reported humidity or the one derived from the dew point.
+    pub fn relative_humidity_or_derived(&self) -> Option<f32> {
+        self.relative_humidity
+            .or_else(|| Some(relative_humidity(self.temperature?, self.dew_point?)))
+    }
+
+    /// In g/m³
+    pub fn absolute_humidity(&self) -> Option<f32> {
+        Some(absolute_humidity(
+            self.temperature?,
+            self.relative_humidity_or_derived()?,
+        ))
+    }
+
+    /// The "feels like" temperature.
+    pub fn apparent_temperature(&self) -> Option<f32> {
+        Some(apparent_temperature(
+            self.temperature?,
+            self.relative_humidity_or_derived(),
+            self.mean_wind,
+        ))
+    }
+
+    /// Beaufort force of the mean wind
+    pub fn beaufort(&self) -> Option<u8> {
+        self.mean_wind.map(beaufort)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_values() {
+        assert!((dew_point(20.0, 50.0) - 9.26).abs() < 0.05);
+        assert!((relative_humidity(20.0, 9.26) - 50.0).abs() < 0.2);
+        assert!((absolute_humidity(20.0, 50.0) - 8.65).abs() < 0.05);
+        // -10°C at 30 km/h is about -20°C
+        assert!((wind_chill(-10.0, 30.0).unwrap() + 19.5).abs() < 0.1);
+        // 32°C at 60% feels like about 37°C
+        assert!((heat_index(32.0, 60.0).unwrap() - 37.1).abs() < 0.5);
+        assert_eq!(apparent_temperature(15.0, Some(50.0), Some(20.0)), 15.0);
+        assert_eq!(beaufort(0.0), 0);
+        assert_eq!(beaufort(20.0), 4);
+        assert_eq!(beaufort(130.0), 12);
+    }
+}
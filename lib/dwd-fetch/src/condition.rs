@@ -0,0 +1,350 @@
+//! A condition independent of the source codes.
+//!
+//! The POI, MOSMIX and SYNOP codes all describe the weather differently, [`Condition`] maps them
+//! to one model that's used for icons and notifications. Texts come from [`crate::description`].
+
+/// The dominating phenomenon, ordered by severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConditionKind {
+    /// No significant weather, the observed cloud cover decides about the clouds if there is one
+    Clear,
+    /// The amount of clouds is the [`Intensity`]
+    Cloudy,
+    /// Includes mist and haze ([`Intensity::Light`])
+    Fog,
+    /// Squalls and gusts
+    Wind,
+    Drizzle,
+    Rain,
+    /// Rain and snow
+    Sleet,
+    Snow,
+    /// Includes ice pellets and graupel ([`Intensity::Light`])
+    Hail,
+    Thunder,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Intensity {
+    Light,
+    Moderate,
+    Heavy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Condition {
+    pub kind: ConditionKind,
+    pub intensity: Intensity,
+    /// Precipitation in showers (from convective clouds)
+    pub showery: bool,
+    /// Freezing precipitation or fog depositing rime
+    pub freezing: bool,
+}
+
+impl Condition {
+    pub const fn new(kind: ConditionKind, intensity: Intensity) -> Self {
+        Self {
+            kind,
+            intensity,
+            showery: false,
+            freezing: false,
+        }
+    }
+
+    pub const fn showers(kind: ConditionKind, intensity: Intensity) -> Self {
+        Self {
+            showery: true,
+            ..Self::new(kind, intensity)
+        }
+    }
+
+    pub const fn freezing(kind: ConditionKind, intensity: Intensity) -> Self {
+        Self {
+            freezing: true,
+            ..Self::new(kind, intensity)
+        }
+    }
+
+    /// Whether anything falls from the sky.
+    pub fn is_precipitation(&self) -> bool {
+        matches!(
+            self.kind,
+            ConditionKind::Drizzle
+                | ConditionKind::Rain
+                | ConditionKind::Sleet
+                | ConditionKind::Snow
+                | ConditionKind::Hail
+        )
+    }
+
+    /// Present weather of POI reports.
+    ///
+    /// https://www.dwd.de/DE/leistungen/opendata/help/schluessel_datenformate/csv/poi_present_weather_zuordnung_pdf.pdf
+    pub fn from_poi(pw: u16) -> Option<Self> {
+        use ConditionKind::*;
+        use Intensity::*;
+
+        Some(match pw {
+            1 => Self::new(Clear, Light),
+            2 => Self::new(Cloudy, Light),
+            3 => Self::new(Cloudy, Moderate),
+            4 => Self::new(Cloudy, Heavy),
+            5 => Self::new(Fog, Moderate),
+            6 => Self::freezing(Fog, Moderate),
+            7 => Self::new(Rain, Light),
+            8 => Self::new(Rain, Moderate),
+            9 => Self::new(Rain, Heavy),
+            10 => Self::freezing(Rain, Moderate),
+            11 => Self::freezing(Rain, Heavy),
+            12 => Self::new(Sleet, Moderate),
+            13 => Self::new(Sleet, Heavy),
+            14 => Self::new(Snow, Light),
+            15 => Self::new(Snow, Moderate),
+            16 => Self::new(Snow, Heavy),
+            17 => Self::new(Hail, Light),
+            18 => Self::showers(Rain, Moderate),
+            19 => Self::showers(Rain, Heavy),
+            20 => Self::showers(Sleet, Moderate),
+            21 => Self::showers(Sleet, Heavy),
+            22 => Self::showers(Snow, Moderate),
+            23 => Self::showers(Snow, Heavy),
+            24 => Self::showers(Hail, Light),
+            25 => Self::showers(Hail, Moderate),
+            26 => Self::new(Thunder, Light),
+            27 => Self::new(Thunder, Moderate),
+            28 => Self::new(Thunder, Heavy),
+            29 => Self::new(Thunder, Moderate),
+            30 => Self::new(Thunder, Heavy),
+            31 => Self::new(Wind, Moderate),
+            _ => return None,
+        })
+    }
+
+    /// Present weather `ww` (WMO code table 4677) as used by MOSMIX.
+    ///
+    /// MOSMIX uses 0-3 for the effective cloud cover.
+    pub fn from_ww(ww: u16) -> Option<Self> {
+        use ConditionKind::*;
+        use Intensity::*;
+
+        Some(match ww {
+            0 => Self::new(Clear, Light),
+            1 => Self::new(Cloudy, Light),
+            2 => Self::new(Cloudy, Moderate),
+            3 => Self::new(Cloudy, Heavy),
+            // haze, smoke and dust
+            4..=12 => Self::new(Fog, Light),
+            13 => Self::new(Thunder, Light),
+            14..=16 => Self::new(Cloudy, Heavy),
+            18 => Self::new(Wind, Moderate),
+            17 => Self::new(Thunder, Moderate),
+            19 => Self::new(Thunder, Heavy),
+            // during the preceding hour
+            20 => Self::new(Drizzle, Light),
+            21 => Self::new(Rain, Light),
+            22 => Self::new(Snow, Light),
+            23 => Self::new(Sleet, Light),
+            24 => Self::freezing(Rain, Light),
+            25 => Self::showers(Rain, Light),
+            26 => Self::showers(Snow, Light),
+            27 => Self::showers(Hail, Light),
+            28 => Self::new(Fog, Light),
+            29 => Self::new(Thunder, Light),
+            30..=35 => Self::new(Cloudy, Heavy),
+            36 | 38 => Self::new(Snow, Light),
+            37 | 39 => Self::new(Snow, Heavy),
+            40 | 41 => Self::new(Fog, Light),
+            42..=47 => Self::new(Fog, Moderate),
+            48 | 49 => Self::freezing(Fog, Moderate),
+            50 | 51 => Self::new(Drizzle, Light),
+            52 | 53 => Self::new(Drizzle, Moderate),
+            54 | 55 => Self::new(Drizzle, Heavy),
+            56 => Self::freezing(Drizzle, Light),
+            57 => Self::freezing(Drizzle, Heavy),
+            58 => Self::new(Rain, Light),
+            59 => Self::new(Rain, Moderate),
+            60 | 61 => Self::new(Rain, Light),
+            62 | 63 => Self::new(Rain, Moderate),
+            64 | 65 => Self::new(Rain, Heavy),
+            66 => Self::freezing(Rain, Light),
+            67 => Self::freezing(Rain, Heavy),
+            68 => Self::new(Sleet, Light),
+            69 => Self::new(Sleet, Heavy),
+            70 | 71 => Self::new(Snow, Light),
+            72 | 73 => Self::new(Snow, Moderate),
+            74 | 75 => Self::new(Snow, Heavy),
+            // diamond dust, snow grains and snow crystals
+            76..=78 => Self::new(Snow, Light),
+            79 => Self::new(Hail, Light),
+            80 => Self::showers(Rain, Light),
+            81 => Self::showers(Rain, Moderate),
+            82 => Self::showers(Rain, Heavy),
+            83 => Self::showers(Sleet, Light),
+            84 => Self::showers(Sleet, Heavy),
+            85 => Self::showers(Snow, Light),
+            86 => Self::showers(Snow, Heavy),
+            87 | 89 => Self::showers(Hail, Light),
+            88 | 90 => Self::showers(Hail, Moderate),
+            91 | 93 => Self::new(Thunder, Light),
+            92 | 94..=96 | 98 => Self::new(Thunder, Moderate),
+            97 | 99 => Self::new(Thunder, Heavy),
+            _ => return None,
+        })
+    }
+
+    /// Present weather from automatic stations `wawa` (WMO code table 4680), 100 is added to the
+    /// code as in 0 20 003.
+    pub fn from_wawa(wawa: u16) -> Option<Self> {
+        use ConditionKind::*;
+        use Intensity::*;
+
+        Some(match wawa {
+            100 => Self::new(Clear, Light),
+            101..=103 => Self::new(Cloudy, Moderate),
+            104 | 105 | 110 => Self::new(Fog, Light),
+            111 => Self::new(Snow, Light),
+            112 => Self::new(Thunder, Light),
+            118 => Self::new(Wind, Moderate),
+            // during the preceding hour
+            120 => Self::new(Fog, Light),
+            121 | 123 => Self::new(Rain, Light),
+            122 => Self::new(Drizzle, Light),
+            124 | 127..=129 => Self::new(Snow, Light),
+            125 => Self::freezing(Rain, Light),
+            126 => Self::new(Thunder, Light),
+            130 => Self::new(Fog, Light),
+            131..=134 => Self::new(Fog, Moderate),
+            135 => Self::freezing(Fog, Moderate),
+            140 => Self::new(Rain, Moderate),
+            141 | 143 => Self::new(Rain, Light),
+            142 | 144 => Self::new(Rain, Heavy),
+            145 => Self::new(Snow, Light),
+            146 => Self::new(Snow, Heavy),
+            147 => Self::freezing(Rain, Light),
+            148 => Self::freezing(Rain, Heavy),
+            150 | 152 => Self::new(Drizzle, Moderate),
+            151 => Self::new(Drizzle, Light),
+            153 => Self::new(Drizzle, Heavy),
+            154 => Self::freezing(Drizzle, Light),
+            155 => Self::freezing(Drizzle, Moderate),
+            156 => Self::freezing(Drizzle, Heavy),
+            157 => Self::new(Rain, Light),
+            158 => Self::new(Rain, Moderate),
+            160 | 162 => Self::new(Rain, Moderate),
+            161 => Self::new(Rain, Light),
+            163 => Self::new(Rain, Heavy),
+            164 => Self::freezing(Rain, Light),
+            165 => Self::freezing(Rain, Moderate),
+            166 => Self::freezing(Rain, Heavy),
+            167 => Self::new(Sleet, Light),
+            168 => Self::new(Sleet, Heavy),
+            170 | 172 => Self::new(Snow, Moderate),
+            171 | 177 | 178 => Self::new(Snow, Light),
+            173 => Self::new(Snow, Heavy),
+            174 => Self::new(Hail, Light),
+            175 => Self::new(Hail, Moderate),
+            176 => Self::new(Hail, Heavy),
+            180 | 182 => Self::showers(Rain, Moderate),
+            181 => Self::showers(Rain, Light),
+            183 | 184 => Self::showers(Rain, Heavy),
+            185 => Self::showers(Snow, Light),
+            186 => Self::showers(Snow, Moderate),
+            187 => Self::showers(Snow, Heavy),
+            189 => Self::showers(Hail, Moderate),
+            191 => Self::new(Thunder, Light),
+            190 | 192 | 193 => Self::new(Thunder, Moderate),
+            194..=196 | 199 => Self::new(Thunder, Heavy),
+            _ => return None,
+        })
+    }
+
+    /// Present weather 0 20 003 of SYNOP reports.
+    ///
+    /// Manned stations report `ww` (see [`synop_ww`]), automatic stations `wawa` (100-199). 508
+    /// means that there was nothing significant to report. Codes for the development of clouds
+    /// don't say how cloudy it is, so they're mapped to [`ConditionKind::Clear`] like 508.
+    pub fn from_synop(code: u16) -> Option<Self> {
+        let nothing_significant = Self::new(ConditionKind::Clear, Intensity::Light);
+        match (synop_ww(code), code) {
+            (Some(0..=3), _) | (None, 101..=103 | 508) => Some(nothing_significant),
+            (Some(ww), _) => Self::from_ww(ww),
+            (None, 100..=199) => Self::from_wawa(code),
+            _ => None,
+        }
+    }
+}
+
+/// The `ww` code of a present weather code 0 20 003 from a manned station.
+///
+/// These report `ww` (0-99) and some stations `ww` + 200.
+pub(crate) fn synop_ww(code: u16) -> Option<u16> {
+    match code {
+        0..=99 => Some(code),
+        200..=299 => Some(code - 200),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_agree() {
+        assert_eq!(Condition::from_poi(18), Condition::from_ww(81));
+        assert_eq!(Condition::from_ww(61), Condition::from_synop(161));
+        assert_eq!(Condition::from_poi(10), Condition::from_synop(165));
+        // codes above 99 aren't ww
+        assert_eq!(Condition::from_ww(150), None);
+        assert_ne!(Condition::from_synop(150), None);
+        assert_eq!(Condition::from_synop(509), None);
+    }
+
+    #[test]
+    fn synop_ww_plus_200() {
+        for code in 204..=299 {
+            assert_eq!(
+                Condition::from_synop(code),
+                Condition::from_ww(code - 200),
+                "{code}"
+            );
+        }
+        assert_eq!(
+            Condition::from_synop(261),
+            Some(Condition::new(ConditionKind::Rain, Intensity::Light))
+        );
+    }
+
+    #[test]
+    fn synop_cloud_development() {
+        let nothing_significant = Condition::from_synop(508);
+        for code in [0, 1, 2, 3, 101, 102, 103, 200, 203] {
+            assert_eq!(Condition::from_synop(code), nothing_significant, "{code}");
+        }
+        // MOSMIX uses the codes for the cloud cover
+        assert_eq!(
+            Condition::from_ww(3),
+            Some(Condition::new(ConditionKind::Cloudy, Intensity::Heavy))
+        );
+    }
+
+    #[test]
+    fn synop_texts_and_conditions_agree() {
+        for code in 0..1024 {
+            assert_eq!(
+                Condition::from_synop(code).is_some(),
+                crate::description::synop(code, crate::description::Language::English).is_some(),
+                "{code}"
+            );
+        }
+    }
+
+    #[test]
+    fn squalls_are_wind() {
+        let wind = Condition::new(ConditionKind::Wind, Intensity::Moderate);
+        assert_eq!(Condition::from_ww(18), Some(wind));
+        assert_eq!(Condition::from_synop(118), Some(wind));
+        assert_eq!(Condition::from_poi(31), Some(wind));
+        assert!(!wind.is_precipitation());
+    }
+}
@@ -0,0 +1,331 @@
+//! Texts for the weather codes of the different sources.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+pub enum Language {
+    #[serde(rename = "de")]
+    German,
+    #[default]
+    #[serde(rename = "en")]
+    English,
+}
+
+impl Language {
+    /// The language for a language subtag like `de`, English if there are no texts for it.
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "de" => Self::German,
+            _ => Self::English,
+        }
+    }
+}
+
+/// `(code, German, English)` sorted by code, the texts start with a capital letter like labels.
+type Table = [(u16, &'static str, &'static str)];
+
+fn lookup(table: &Table, code: u16, lang: Language) -> Option<&'static str> {
+    let idx = table.binary_search_by_key(&code, |(c, _, _)| *c).ok()?;
+    let (_, de, en) = table[idx];
+    Some(match lang {
+        Language::German => de,
+        Language::English => en,
+    })
+}
+
+/// Present weather of POI reports.
+pub fn poi(pw: u16, lang: Language) -> Option<&'static str> {
+    lookup(&POI, pw, lang)
+}
+
+/// Significant weather `ww` of MOSMIX, codes that MOSMIX doesn't use are described like SYNOP
+/// codes.
+pub fn mosmix(ww: u16, lang: Language) -> Option<&'static str> {
+    lookup(&MOSMIX, ww, lang).or_else(|| lookup(&WW, ww, lang))
+}
+
+/// Present weather 0 20 003 of SYNOP reports (`ww`, `wawa` + 100, `ww` + 200 and 508).
+pub fn synop(code: u16, lang: Language) -> Option<&'static str> {
+    match crate::condition::synop_ww(code) {
+        Some(ww) => lookup(&WW, ww, lang),
+        None => lookup(&WAWA, code, lang),
+    }
+}
+
+// https://www.dwd.de/DE/leistungen/opendata/help/schluessel_datenformate/csv/poi_present_weather_zuordnung_pdf.pdf
+#[rustfmt::skip]
+static POI: [(u16, &str, &str); 31] = [
+    (1, "Wolkenlos", "Clear"),
+    (2, "Heiter", "Mostly clear"),
+    (3, "Bewölkt", "Partly cloudy"),
+    (4, "Bedeckt", "Overcast"),
+    (5, "Nebel", "Fog"),
+    (6, "Gefrierender Nebel", "Freezing fog"),
+    (7, "Leichter Regen", "Light rain"),
+    (8, "Regen", "Rain"),
+    (9, "Kräftiger Regen", "Heavy rain"),
+    (10, "Gefrierender Regen", "Freezing rain"),
+    (11, "Kräftiger gefrierender Regen", "Heavy freezing rain"),
+    (12, "Schneeregen", "Sleet"),
+    (13, "Kräftiger Schneeregen", "Heavy sleet"),
+    (14, "Leichter Schneefall", "Light snow"),
+    (15, "Schneefall", "Snow"),
+    (16, "Kräftiger Schneefall", "Heavy snow"),
+    (17, "Eiskörner", "Ice pellets"),
+    (18, "Regenschauer", "Rain showers"),
+    (19, "Kräftiger Regenschauer", "Heavy rain showers"),
+    (20, "Schneeregenschauer", "Sleet showers"),
+    (21, "Kräftiger Schneeregenschauer", "Heavy sleet showers"),
+    (22, "Schneeschauer", "Snow showers"),
+    (23, "Kräftiger Schneeschauer", "Heavy snow showers"),
+    (24, "Graupelschauer", "Graupel showers"),
+    (25, "Kräftiger Graupelschauer", "Heavy graupel showers"),
+    (26, "Gewitter ohne Niederschlag", "Thunderstorm without precipitation"),
+    (27, "Gewitter", "Thunderstorm"),
+    (28, "Kräftiges Gewitter", "Heavy thunderstorm"),
+    (29, "Gewitter mit Hagel", "Thunderstorm with hail"),
+    (30, "Kräftiges Gewitter mit Hagel", "Heavy thunderstorm with hail"),
+    (31, "Böen", "Gusts"),
+];
+
+// https://www.dwd.de/DE/leistungen/opendata/help/schluessel_datenformate/kml/mosmix_element_weather_xls.xlsx
+#[rustfmt::skip]
+static MOSMIX: [(u16, &str, &str); 29] = [
+    (0, "Wolkenlos", "Clear"),
+    (1, "Heiter", "Mostly clear"),
+    (2, "Wolkig", "Partly cloudy"),
+    (3, "Bedeckt", "Overcast"),
+    (45, "Nebel", "Fog"),
+    (49, "Nebel mit Reifansatz", "Freezing fog"),
+    (51, "Leichter Sprühregen", "Light drizzle"),
+    (53, "Mäßiger Sprühregen", "Moderate drizzle"),
+    (55, "Starker Sprühregen", "Heavy drizzle"),
+    (56, "Leichter gefrierender Sprühregen", "Light freezing drizzle"),
+    (57, "Kräftiger gefrierender Sprühregen", "Heavy freezing drizzle"),
+    (61, "Leichter Regen", "Light rain"),
+    (63, "Mäßiger Regen", "Moderate rain"),
+    (65, "Starker Regen", "Heavy rain"),
+    (66, "Leichter gefrierender Regen", "Light freezing rain"),
+    (67, "Kräftiger gefrierender Regen", "Heavy freezing rain"),
+    (68, "Leichter Schneeregen", "Light sleet"),
+    (69, "Kräftiger Schneeregen", "Heavy sleet"),
+    (71, "Leichter Schneefall", "Light snow"),
+    (73, "Mäßiger Schneefall", "Moderate snow"),
+    (75, "Starker Schneefall", "Heavy snow"),
+    (80, "Leichter Regenschauer", "Light rain showers"),
+    (81, "Kräftiger Regenschauer", "Heavy rain showers"),
+    (82, "Äußerst heftiger Regenschauer", "Violent rain showers"),
+    (83, "Leichter Schneeregenschauer", "Light sleet showers"),
+    (84, "Kräftiger Schneeregenschauer", "Heavy sleet showers"),
+    (85, "Leichter Schneeschauer", "Light snow showers"),
+    (86, "Kräftiger Schneeschauer", "Heavy snow showers"),
+    (95, "Gewitter", "Thunderstorm"),
+];
+
+// WMO code table 4677
+#[rustfmt::skip]
+static WW: [(u16, &str, &str); 100] = [
+    (0, "Bewölkungsentwicklung nicht beobachtet", "Cloud development not observed"),
+    (1, "Bewölkung abnehmend", "Clouds dissolving"),
+    (2, "Bewölkung unverändert", "Sky unchanged"),
+    (3, "Bewölkung zunehmend", "Clouds developing"),
+    (4, "Sicht durch Rauch verringert", "Visibility reduced by smoke"),
+    (5, "Trockener Dunst", "Haze"),
+    (6, "Staubtrübung", "Widespread dust"),
+    (7, "Aufgewirbelter Staub oder Sand", "Raised dust or sand"),
+    (8, "Staub- oder Sandwirbel", "Dust or sand whirls"),
+    (9, "Staub- oder Sandsturm in Sicht", "Duststorm in sight"),
+    (10, "Feuchter Dunst", "Mist"),
+    (11, "Bodennebelschwaden", "Patches of shallow fog"),
+    (12, "Bodennebel", "Shallow fog"),
+    (13, "Wetterleuchten", "Lightning without thunder"),
+    (14, "Niederschlag in Sicht, nicht den Boden erreichend", "Precipitation not reaching the ground"),
+    (15, "Niederschlag in der Ferne", "Distant precipitation"),
+    (16, "Niederschlag in der Nähe", "Precipitation nearby"),
+    (17, "Gewitter ohne Niederschlag", "Thunderstorm without precipitation"),
+    (18, "Böen", "Squalls"),
+    (19, "Tromben", "Funnel clouds"),
+    (20, "Sprühregen in der letzten Stunde", "Drizzle in the past hour"),
+    (21, "Regen in der letzten Stunde", "Rain in the past hour"),
+    (22, "Schneefall in der letzten Stunde", "Snow in the past hour"),
+    (23, "Schneeregen in der letzten Stunde", "Sleet in the past hour"),
+    (24, "Gefrierender Regen in der letzten Stunde", "Freezing rain in the past hour"),
+    (25, "Regenschauer in der letzten Stunde", "Rain showers in the past hour"),
+    (26, "Schneeschauer in der letzten Stunde", "Snow showers in the past hour"),
+    (27, "Hagelschauer in der letzten Stunde", "Hail showers in the past hour"),
+    (28, "Nebel in der letzten Stunde", "Fog in the past hour"),
+    (29, "Gewitter in der letzten Stunde", "Thunderstorm in the past hour"),
+    (30, "Leichter Staubsturm, abnehmend", "Slight duststorm, decreasing"),
+    (31, "Leichter Staubsturm", "Slight duststorm"),
+    (32, "Leichter Staubsturm, zunehmend", "Slight duststorm, increasing"),
+    (33, "Schwerer Staubsturm, abnehmend", "Severe duststorm, decreasing"),
+    (34, "Schwerer Staubsturm", "Severe duststorm"),
+    (35, "Schwerer Staubsturm, zunehmend", "Severe duststorm, increasing"),
+    (36, "Leichtes Schneefegen", "Slight drifting snow"),
+    (37, "Starkes Schneefegen", "Heavy drifting snow"),
+    (38, "Leichtes Schneetreiben", "Slight blowing snow"),
+    (39, "Starkes Schneetreiben", "Heavy blowing snow"),
+    (40, "Nebel in der Ferne", "Fog at a distance"),
+    (41, "Nebelfelder", "Fog in patches"),
+    (42, "Nebel, Himmel erkennbar, dünner werdend", "Thinning fog, sky visible"),
+    (43, "Nebel, dünner werdend", "Thinning fog"),
+    (44, "Nebel, Himmel erkennbar", "Fog, sky visible"),
+    (45, "Nebel", "Fog"),
+    (46, "Nebel, Himmel erkennbar, dichter werdend", "Thickening fog, sky visible"),
+    (47, "Nebel, dichter werdend", "Thickening fog"),
+    (48, "Nebel mit Reifansatz, Himmel erkennbar", "Rime fog, sky visible"),
+    (49, "Nebel mit Reifansatz", "Rime fog"),
+    (50, "Zeitweise leichter Sprühregen", "Intermittent light drizzle"),
+    (51, "Leichter Sprühregen", "Light drizzle"),
+    (52, "Zeitweise mäßiger Sprühregen", "Intermittent moderate drizzle"),
+    (53, "Mäßiger Sprühregen", "Moderate drizzle"),
+    (54, "Zeitweise starker Sprühregen", "Intermittent heavy drizzle"),
+    (55, "Starker Sprühregen", "Heavy drizzle"),
+    (56, "Leichter gefrierender Sprühregen", "Light freezing drizzle"),
+    (57, "Kräftiger gefrierender Sprühregen", "Heavy freezing drizzle"),
+    (58, "Leichter Sprühregen mit Regen", "Light drizzle and rain"),
+    (59, "Kräftiger Sprühregen mit Regen", "Heavy drizzle and rain"),
+    (60, "Zeitweise leichter Regen", "Intermittent light rain"),
+    (61, "Leichter Regen", "Light rain"),
+    (62, "Zeitweise mäßiger Regen", "Intermittent moderate rain"),
+    (63, "Mäßiger Regen", "Moderate rain"),
+    (64, "Zeitweise starker Regen", "Intermittent heavy rain"),
+    (65, "Starker Regen", "Heavy rain"),
+    (66, "Leichter gefrierender Regen", "Light freezing rain"),
+    (67, "Kräftiger gefrierender Regen", "Heavy freezing rain"),
+    (68, "Leichter Schneeregen", "Light sleet"),
+    (69, "Kräftiger Schneeregen", "Heavy sleet"),
+    (70, "Zeitweise leichter Schneefall", "Intermittent light snow"),
+    (71, "Leichter Schneefall", "Light snow"),
+    (72, "Zeitweise mäßiger Schneefall", "Intermittent moderate snow"),
+    (73, "Mäßiger Schneefall", "Moderate snow"),
+    (74, "Zeitweise starker Schneefall", "Intermittent heavy snow"),
+    (75, "Starker Schneefall", "Heavy snow"),
+    (76, "Eisnadeln", "Diamond dust"),
+    (77, "Schneegriesel", "Snow grains"),
+    (78, "Schneekristalle", "Snow crystals"),
+    (79, "Eiskörner", "Ice pellets"),
+    (80, "Leichter Regenschauer", "Light rain showers"),
+    (81, "Kräftiger Regenschauer", "Heavy rain showers"),
+    (82, "Äußerst heftiger Regenschauer", "Violent rain showers"),
+    (83, "Leichter Schneeregenschauer", "Light sleet showers"),
+    (84, "Kräftiger Schneeregenschauer", "Heavy sleet showers"),
+    (85, "Leichter Schneeschauer", "Light snow showers"),
+    (86, "Kräftiger Schneeschauer", "Heavy snow showers"),
+    (87, "Leichter Graupelschauer", "Light graupel showers"),
+    (88, "Kräftiger Graupelschauer", "Heavy graupel showers"),
+    (89, "Leichter Hagelschauer", "Light hail showers"),
+    (90, "Kräftiger Hagelschauer", "Heavy hail showers"),
+    (91, "Leichter Regen nach Gewitter", "Light rain after a thunderstorm"),
+    (92, "Kräftiger Regen nach Gewitter", "Heavy rain after a thunderstorm"),
+    (93, "Leichter Schnee oder Hagel nach Gewitter", "Light snow or hail after a thunderstorm"),
+    (94, "Kräftiger Schnee oder Hagel nach Gewitter", "Heavy snow or hail after a thunderstorm"),
+    (95, "Gewitter", "Thunderstorm"),
+    (96, "Gewitter mit Hagel", "Thunderstorm with hail"),
+    (97, "Schweres Gewitter", "Heavy thunderstorm"),
+    (98, "Gewitter mit Staubsturm", "Thunderstorm with duststorm"),
+    (99, "Schweres Gewitter mit Hagel", "Heavy thunderstorm with hail"),
+];
+
+// WMO code table 4680 (+ 100) and 508
+#[rustfmt::skip]
+static WAWA: [(u16, &str, &str); 80] = [
+    (100, "Keine besonderen Wettererscheinungen", "No significant weather"),
+    (101, "Bewölkung abnehmend", "Clouds dissolving"),
+    (102, "Bewölkung unverändert", "Sky unchanged"),
+    (103, "Bewölkung zunehmend", "Clouds developing"),
+    (104, "Dunst oder Rauch", "Haze or smoke"),
+    (105, "Starker Dunst oder Rauch", "Dense haze or smoke"),
+    (110, "Feuchter Dunst", "Mist"),
+    (111, "Eisnadeln", "Diamond dust"),
+    (112, "Wetterleuchten", "Distant lightning"),
+    (118, "Böen", "Squalls"),
+    (120, "Nebel in der letzten Stunde", "Fog in the past hour"),
+    (121, "Niederschlag in der letzten Stunde", "Precipitation in the past hour"),
+    (122, "Sprühregen in der letzten Stunde", "Drizzle in the past hour"),
+    (123, "Regen in der letzten Stunde", "Rain in the past hour"),
+    (124, "Schneefall in der letzten Stunde", "Snow in the past hour"),
+    (125, "Gefrierender Niederschlag in der letzten Stunde", "Freezing precipitation in the past hour"),
+    (126, "Gewitter in der letzten Stunde", "Thunderstorm in the past hour"),
+    (127, "Schneefegen", "Drifting snow"),
+    (128, "Leichtes Schneetreiben", "Slight blowing snow"),
+    (129, "Starkes Schneetreiben", "Heavy blowing snow"),
+    (130, "Nebel", "Fog"),
+    (131, "Nebelfelder", "Fog in patches"),
+    (132, "Nebel, dünner werdend", "Thinning fog"),
+    (133, "Nebel", "Fog"),
+    (134, "Nebel, dichter werdend", "Thickening fog"),
+    (135, "Nebel mit Reifansatz", "Rime fog"),
+    (140, "Niederschlag", "Precipitation"),
+    (141, "Leichter Niederschlag", "Light precipitation"),
+    (142, "Starker Niederschlag", "Heavy precipitation"),
+    (143, "Leichter flüssiger Niederschlag", "Light liquid precipitation"),
+    (144, "Starker flüssiger Niederschlag", "Heavy liquid precipitation"),
+    (145, "Leichter fester Niederschlag", "Light solid precipitation"),
+    (146, "Starker fester Niederschlag", "Heavy solid precipitation"),
+    (147, "Leichter gefrierender Niederschlag", "Light freezing precipitation"),
+    (148, "Starker gefrierender Niederschlag", "Heavy freezing precipitation"),
+    (150, "Sprühregen", "Drizzle"),
+    (151, "Leichter Sprühregen", "Light drizzle"),
+    (152, "Mäßiger Sprühregen", "Moderate drizzle"),
+    (153, "Starker Sprühregen", "Heavy drizzle"),
+    (154, "Leichter gefrierender Sprühregen", "Light freezing drizzle"),
+    (155, "Mäßiger gefrierender Sprühregen", "Moderate freezing drizzle"),
+    (156, "Starker gefrierender Sprühregen", "Heavy freezing drizzle"),
+    (157, "Leichter Sprühregen mit Regen", "Light drizzle and rain"),
+    (158, "Kräftiger Sprühregen mit Regen", "Heavy drizzle and rain"),
+    (160, "Regen", "Rain"),
+    (161, "Leichter Regen", "Light rain"),
+    (162, "Mäßiger Regen", "Moderate rain"),
+    (163, "Starker Regen", "Heavy rain"),
+    (164, "Leichter gefrierender Regen", "Light freezing rain"),
+    (165, "Mäßiger gefrierender Regen", "Moderate freezing rain"),
+    (166, "Starker gefrierender Regen", "Heavy freezing rain"),
+    (167, "Leichter Schneeregen", "Light sleet"),
+    (168, "Kräftiger Schneeregen", "Heavy sleet"),
+    (170, "Schneefall", "Snow"),
+    (171, "Leichter Schneefall", "Light snow"),
+    (172, "Mäßiger Schneefall", "Moderate snow"),
+    (173, "Starker Schneefall", "Heavy snow"),
+    (174, "Leichte Eiskörner", "Light ice pellets"),
+    (175, "Mäßige Eiskörner", "Moderate ice pellets"),
+    (176, "Starke Eiskörner", "Heavy ice pellets"),
+    (177, "Schneegriesel", "Snow grains"),
+    (178, "Eiskristalle", "Ice crystals"),
+    (180, "Schauer", "Showers"),
+    (181, "Leichter Regenschauer", "Light rain showers"),
+    (182, "Mäßiger Regenschauer", "Moderate rain showers"),
+    (183, "Starker Regenschauer", "Heavy rain showers"),
+    (184, "Äußerst heftiger Regenschauer", "Violent rain showers"),
+    (185, "Leichter Schneeschauer", "Light snow showers"),
+    (186, "Mäßiger Schneeschauer", "Moderate snow showers"),
+    (187, "Starker Schneeschauer", "Heavy snow showers"),
+    (189, "Hagel", "Hail"),
+    (190, "Gewitter", "Thunderstorm"),
+    (191, "Gewitter ohne Niederschlag", "Thunderstorm without precipitation"),
+    (192, "Gewitter mit Schauern", "Thunderstorm with showers"),
+    (193, "Gewitter mit Hagel", "Thunderstorm with hail"),
+    (194, "Schweres Gewitter ohne Niederschlag", "Heavy thunderstorm without precipitation"),
+    (195, "Schweres Gewitter mit Schauern", "Heavy thunderstorm with showers"),
+    (196, "Schweres Gewitter mit Hagel", "Heavy thunderstorm with hail"),
+    (199, "Tornado", "Tornado"),
+    (508, "Keine besonderen Wettererscheinungen", "No significant weather"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_sorted() {
+        for table in [&POI[..], &MOSMIX, &WW, &WAWA] {
+            assert!(table.is_sorted_by(|a, b| a.0 < b.0));
+        }
+        assert_eq!(synop(185, Language::English), Some("Light snow showers"));
+        assert_eq!(synop(261, Language::German), Some("Leichter Regen"));
+        assert_eq!(mosmix(80, Language::German), Some("Leichter Regenschauer"));
+        assert_eq!(
+            mosmix(96, Language::English),
+            Some("Thunderstorm with hail")
+        );
+        assert_eq!(poi(31, Language::English), Some("Gusts"));
+    }
+}
@@ -0,0 +1,112 @@
+use std::io;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The request couldn't be sent or the response couldn't be read
+    #[error("Request failed: {0}")]
+    Network(#[from] ureq::Error),
+    #[cfg(feature = "async")]
+    #[error("Request failed: {0}")]
+    AsyncNetwork(#[from] reqwest::Error),
+    #[error("Failed to get {url} - got status {status}")]
+    Status { url: String, status: u16 },
+    /// The (zip/bzip2/tar) archive couldn't be unpacked
+    #[error("Failed to decompress: {0}")]
+    Decompression(#[source] BoxError),
+    /// A downloaded file is malformed
+    #[error("Invalid {what}: {source}")]
+    Format {
+        what: &'static str,
+        #[source]
+        source: BoxError,
+    },
+    /// A requested station is not part of the file (for SYNOP: none of the stations are)
+    #[error("Station {0} not in file")]
+    StationNotFound(String),
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Failed to decode cache: {0}")]
+    CacheDecode(#[from] bincode::error::DecodeError),
+    #[error("Outdated cache (version {0})")]
+    CacheVersion(u32),
+    #[error("Failed to encode cache: {0}")]
+    CacheEncode(#[from] bincode::error::EncodeError),
+    #[error("Worker thread panicked")]
+    Panicked,
+    #[cfg(feature = "async")]
+    #[error("Task failed: {0}")]
+    Task(#[from] tokio::task::JoinError),
+}
+
+impl Error {
+    /// Converts a failed request, unsuccessful statuses become [`Error::Status`].
+    pub(crate) fn request(url: &str, e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::StatusCode(status) => Self::Status {
+                url: url.to_owned(),
+                status,
+            },
+            e => Self::Network(e),
+        }
+    }
+
+    pub(crate) fn format(what: &'static str, source: impl Into<BoxError>) -> Self {
+        Self::Format {
+            what,
+            source: source.into(),
+        }
+    }
+
+    pub(crate) fn decompression(source: impl Into<BoxError>) -> Self {
+        Self::Decompression(source.into())
+    }
+}
+
+impl From<zip::result::ZipError> for Error {
+    fn from(e: zip::result::ZipError) -> Self {
+        Self::decompression(e)
+    }
+}
+
+impl From<quick_xml::Error> for Error {
+    fn from(e: quick_xml::Error) -> Self {
+        Self::format("KML", e)
+    }
+}
+
+impl From<jiff::Error> for Error {
+    fn from(e: jiff::Error) -> Self {
+        Self::format("timestamp", e)
+    }
+}
+
+impl From<dwd_gts::Error> for Error {
+    fn from(e: dwd_gts::Error) -> Self {
+        Self::format("GTS message", e)
+    }
+}
+
+impl From<dwd_bufr::Error> for Error {
+    fn from(e: dwd_bufr::Error) -> Self {
+        Self::format("BUFR", e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_error() {
+        let e = Error::request("https://example.com", ureq::Error::StatusCode(404));
+        assert!(
+            matches!(e, Error::Status { ref url, status: 404 } if url == "https://example.com")
+        );
+        let e = Error::request("https://example.com", ureq::Error::ConnectionFailed);
+        assert!(matches!(e, Error::Network(_)));
+    }
+}
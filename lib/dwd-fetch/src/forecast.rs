@@ -1,75 +1,183 @@
-use anyhow::{anyhow, bail};
+use itertools::Itertools;
 use quick_xml::events::Event;
 use std::{
+    collections::HashMap,
     io::{self, BufReader, Cursor},
-    ops::Deref,
     sync::RwLock,
+    time::Instant,
 };
 
-use crate::{Cache, Datapoint, PoiStation, WeatherCondition, get_etag};
+use crate::{Cache, Datapoint, Error, PoiStation, Result, WeatherCondition, download};
 
-pub fn get(station: PoiStation, cache: &RwLock<Cache>) -> anyhow::Result<bool> {
-    if !needs_fetch(station, MosmixType::L, &cache.read().unwrap())
-        && !needs_fetch(station, MosmixType::S, &cache.read().unwrap())
-    {
+/// Forecast series per station
+pub type StationForecasts = HashMap<PoiStation, Vec<Datapoint>>;
+
+pub fn get(station: PoiStation, cache: &RwLock<Cache>) -> Result<bool> {
+    get_all(&[(station, cache)])
+}
+
+/// Updates the forecast of multiple caches. MOSMIX_S is only downloaded once for all of them.
+#[tracing::instrument(name = "forecast", skip_all, fields(stations = targets.len()))]
+pub fn get_all(targets: &[(PoiStation, &RwLock<Cache>)]) -> Result<bool> {
+    let needs_any = targets.iter().any(|(station, cache)| {
+        let cache = cache.read().unwrap();
+        needs_fetch(*station, MosmixType::L, &cache) || needs_fetch(*station, MosmixType::S, &cache)
+    });
+    if !needs_any {
         return Ok(false);
     }
-    let (rs, rl) = std::thread::scope(|s| {
-        let hs = s.spawn(|| fetch(station, MosmixType::S));
-        let rl = fetch(station, MosmixType::L);
-        let rs = hs
-            .join()
-            .unwrap_or_else(|e| Err(anyhow!("Failed to join/spawn: {e:?}")));
-        (rs, rl)
+    let stations = unique_stations(targets);
+    let (rs, rls) = std::thread::scope(|s| {
+        let hs = s.spawn(|| fetch_s(&stations));
+        let hls = stations
+            .iter()
+            .map(|station| s.spawn(|| fetch(*station, MosmixType::L)))
+            .collect::<Vec<_>>();
+        let rls = hls
+            .into_iter()
+            .map(|h| h.join().unwrap_or_else(|_| Err(Error::Panicked)))
+            .collect::<Vec<_>>();
+        let rs = hs.join().unwrap_or_else(|_| Err(Error::Panicked));
+        (rs, rls)
     });
 
-    let mut cache = cache.write().unwrap();
-    let data = match (rs, rl) {
-        (Ok((s, ts)), Ok((l, tl))) => {
-            cache.forecast_s_etag = ts;
-            cache.forecast_l_etag = tl;
-            Datapoint::merge_series_vec(s, l)
-        }
-        (Err(_es), Ok((l, tl))) => {
-            cache.forecast_l_etag = tl;
-            l
-        }
-        (Ok((s, ts)), Err(_el)) => {
-            cache.forecast_s_etag = ts;
-            s
+    store(targets, &stations, rs, rls)
+}
+
+/// The stations of `targets` without duplicates, locations can share a station.
+pub(crate) fn unique_stations(targets: &[(PoiStation, &RwLock<Cache>)]) -> Vec<PoiStation> {
+    targets
+        .iter()
+        .map(|(station, _)| *station)
+        .unique()
+        .collect()
+}
+
+/// Writes the fetched S and L forecasts (`rls` is in the same order as `stations`) to the caches
+/// of all targets with that station.
+pub(crate) fn store(
+    targets: &[(PoiStation, &RwLock<Cache>)],
+    stations: &[PoiStation],
+    rs: Result<(StationForecasts, Option<String>)>,
+    rls: Vec<Result<(Vec<Datapoint>, Option<String>)>>,
+) -> Result<bool> {
+    let (mut s_data, ts) = match rs {
+        Ok((data, ts)) => (Some(data), ts),
+        Err(es) => {
+            tracing::warn!(%es, "Failed to fetch MOSMIX_S");
+            (None, None)
         }
-        (Err(es), Err(el)) => bail!("Failed to fetch both S ({es}) and L ({el})"),
     };
-    cache.forecast = data;
-    Ok(true)
-}
+    let mut last_err = None;
+    let mut updated = false;
+    for (station, rl) in stations.iter().zip(rls) {
+        let rs = match s_data.as_mut() {
+            Some(data) => take(data, *station),
+            None => Err(Error::StationNotFound(station.to_string())),
+        };
 
-fn fetch(station: PoiStation, ty: MosmixType) -> anyhow::Result<(Vec<Datapoint>, Option<String>)> {
-    let mut res = ureq::get(ty.url(station)).call()?;
-    if !res.status().is_success() {
-        bail!("Failed to get station - got status {:?}", res.status());
+        // the new etags or `None` if the forecast wasn't fetched
+        let (data, s_etag, l_etag) = match (rs, rl) {
+            (Ok(s), Ok((l, tl))) => (
+                Datapoint::merge_series_vec(s, l),
+                Some(ts.clone()),
+                Some(tl),
+            ),
+            (Err(es), Ok((l, tl))) => {
+                tracing::warn!(%station, %es, "Failed to fetch MOSMIX_S");
+                (l, None, Some(tl))
+            }
+            (Ok(s), Err(el)) => {
+                tracing::warn!(%station, %el, "Failed to fetch MOSMIX_L");
+                (s, Some(ts.clone()), None)
+            }
+            (Err(es), Err(el)) => {
+                tracing::warn!(%station, %es, "Failed to fetch MOSMIX_S");
+                tracing::warn!(%station, %el, "Failed to fetch MOSMIX_L");
+                last_err = Some(el);
+                continue;
+            }
+        };
+        for (_, cache) in targets.iter().filter(|(s, _)| s == station) {
+            let mut cache = cache.write().unwrap();
+            if let Some(ref etag) = s_etag {
+                cache.forecast_s_etag = etag.clone();
+            }
+            if let Some(ref etag) = l_etag {
+                cache.forecast_l_etag = etag.clone();
+            }
+            cache.forecast = data.clone();
+        }
+        updated = true;
+    }
+
+    match last_err {
+        Some(e) if !updated => Err(e),
+        _ => Ok(updated),
     }
-    let etag = get_etag(&res);
+}
+
+fn fetch(station: PoiStation, ty: MosmixType) -> Result<(Vec<Datapoint>, Option<String>)> {
+    let (mut data, etag) = fetch_stations(&[station], ty)?;
+    Ok((take(&mut data, station)?, etag))
+}
 
-    let bytes = res.body_mut().with_config().limit(1 << 28).read_to_vec()?;
+/// Removes the forecast of `station` from the parsed forecasts.
+pub(crate) fn take(
+    forecasts: &mut StationForecasts,
+    station: PoiStation,
+) -> Result<Vec<Datapoint>> {
+    forecasts
+        .remove(&station)
+        .ok_or_else(|| Error::StationNotFound(station.to_string()))
+}
+
+/// Fetches the MOSMIX_S forecast for all `stations` while only downloading and
+/// parsing the (large) all-stations file once.
+///
+/// Stations that aren't part of the file are missing in the returned map.
+pub fn fetch_s(stations: &[PoiStation]) -> Result<(StationForecasts, Option<String>)> {
+    fetch_stations(stations, MosmixType::S)
+}
+
+fn fetch_stations(
+    stations: &[PoiStation],
+    ty: MosmixType,
+) -> Result<(StationForecasts, Option<String>)> {
+    // MOSMIX_L is only available per station
+    debug_assert!(ty == MosmixType::S || stations.len() == 1);
+    let Some(first) = stations.first() else {
+        return Ok((HashMap::new(), None));
+    };
+    let (bytes, etag) = download(&ty.url(*first))?;
+    Ok((parse_kmz(bytes, stations)?, etag))
+}
+
+/// Parses the forecast of `stations` from a downloaded KMZ archive.
+pub(crate) fn parse_kmz(bytes: Vec<u8>, stations: &[PoiStation]) -> Result<StationForecasts> {
     let mut archive = zip::read::ZipArchive::new(Cursor::new(bytes))?;
     let file = archive.by_index(0)?;
-    let data = parse(BufReader::new(file), &station.to_string())?;
-
-    Ok((data, etag))
+    parse_stations(BufReader::new(file), stations)
 }
 
 fn needs_fetch(station: PoiStation, ty: MosmixType, cache: &Cache) -> bool {
     super::needs_fetch(&ty.url(station), ty.etag(cache).as_deref())
 }
 
-fn parse(reader: impl io::BufRead, target: &str) -> anyhow::Result<Vec<Datapoint>> {
+/// Parses the forecast of multiple stations from a MOSMIX KML file in a single pass.
+///
+/// Stations that aren't part of the file are missing in the returned map.
+pub fn parse_stations(
+    reader: impl io::BufRead,
+    targets: &[PoiStation],
+) -> Result<StationForecasts> {
+    let start = Instant::now();
     let mut buf = Vec::new();
     let mut inner_buf = Vec::new();
     let mut reader = quick_xml::Reader::from_reader(reader);
     reader.config_mut().trim_text(true);
 
-    let mut datapoints = Vec::new();
+    let mut timesteps = Vec::new();
     // first, get the timestamps and init the datapoints
     'outer: loop {
         buf.clear();
@@ -81,7 +189,7 @@ fn parse(reader: impl io::BufRead, target: &str) -> anyhow::Result<Vec<Datapoint
                         if let Some(timestamp) =
                             str::from_utf8(&text).ok().and_then(|s| s.parse().ok())
                         {
-                            datapoints.push(Datapoint::from_timestamp(timestamp, false));
+                            timesteps.push(Datapoint::from_timestamp(timestamp, false));
                         }
                     }
                     Event::End(e) if e.name().as_ref() == b"dwd:ForecastTimeSteps" => break 'outer,
@@ -94,17 +202,28 @@ fn parse(reader: impl io::BufRead, target: &str) -> anyhow::Result<Vec<Datapoint
         }
     }
 
-    // find the station
-    loop {
+    // find the stations
+    let mut pending = HashMap::<String, PoiStation>::from_iter(
+        targets
+            .iter()
+            .map(|station| (station.to_string(), *station)),
+    );
+    let mut stations = HashMap::with_capacity(pending.len());
+    while !pending.is_empty() {
         buf.clear();
         match reader.read_event_into(&mut buf)? {
             Event::Start(e) if e.name().as_ref() == b"kml:name" => {
                 buf.clear();
                 if let Event::Text(b) = reader.read_event_into(&mut buf)?
-                    && b.deref() == target.as_bytes()
+                    && let Some(station) = str::from_utf8(&b)
+                        .ok()
+                        .and_then(|name| pending.remove(name))
                 {
+                    let mut datapoints = timesteps.clone();
                     read_data(&mut reader, &mut datapoints)?;
-                    break;
+                    datapoints.sort_by_key(|it| it.timestamp);
+                    tracing::debug!(%station, "Found station");
+                    stations.insert(station, datapoints);
                 }
             }
             Event::Eof => break,
@@ -112,8 +231,13 @@ fn parse(reader: impl io::BufRead, target: &str) -> anyhow::Result<Vec<Datapoint
         }
     }
 
-    datapoints.sort_by_key(|it| it.timestamp);
-    Ok(datapoints)
+    tracing::debug!(
+        found = stations.len(),
+        missing = ?pending.values().collect::<Vec<_>>(),
+        elapsed = ?start.elapsed(),
+        "Parsed MOSMIX"
+    );
+    Ok(stations)
 }
 
 fn read_data(
@@ -146,14 +270,23 @@ fn read_data(
                         mosmix::TEMP_2M => parse_into(reader, &mut buf, datapoints, |d, v: f32| {
                             d.temperature = Some(v - 273.15)
                         }),
+                        mosmix::DEW_POINT => {
+                            parse_into(reader, &mut buf, datapoints, |d, v: f32| {
+                                d.dew_point = Some(v - 273.15)
+                            })
+                        }
                         mosmix::WIND_DIR => {
                             parse_into(reader, &mut buf, datapoints, |d, v| d.wind_dir = Some(v))
                         }
                         mosmix::WIND_SPEED => {
-                            parse_into(reader, &mut buf, datapoints, |d, v| d.mean_wind = Some(v))
+                            parse_into(reader, &mut buf, datapoints, |d, v: f32| {
+                                d.mean_wind = Some(v * 3.6)
+                            })
                         }
                         mosmix::WIND_GUSTS => {
-                            parse_into(reader, &mut buf, datapoints, |d, v| d.wind_gusts = Some(v))
+                            parse_into(reader, &mut buf, datapoints, |d, v: f32| {
+                                d.wind_gusts = Some(v * 3.6)
+                            })
                         }
                         _ => (),
                     }
@@ -233,13 +366,14 @@ mod mosmix {
     pub const PRECIPITATION_P: &[u8] = b"wwP"; // only mosmixL
     pub const EFFECTIVE_CLOUD_COVER: &[u8] = b"Neff";
     pub const TEMP_2M: &[u8] = b"TTT";
+    pub const DEW_POINT: &[u8] = b"Td";
     pub const WIND_DIR: &[u8] = b"DD";
     pub const WIND_SPEED: &[u8] = b"FF";
     pub const WIND_GUSTS: &[u8] = b"FX1";
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum MosmixType {
+pub(crate) enum MosmixType {
     L,
     S,
 }
@@ -259,3 +393,104 @@ impl MosmixType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn store_shared_station() {
+        let station = PoiStation(10382);
+        let (a, b, c) = (
+            RwLock::new(Cache::default()),
+            RwLock::new(Cache::default()),
+            RwLock::new(Cache::default()),
+        );
+        let targets = [(station, &a), (PoiStation(10384), &b), (station, &c)];
+        let stations = unique_stations(&targets);
+        assert_eq!(stations, [station, PoiStation(10384)]);
+
+        let point = Datapoint::from_timestamp(jiff::Timestamp::UNIX_EPOCH, false);
+        let s = HashMap::from([(station, vec![point.clone()])]);
+        let rls = vec![
+            Ok((vec![point], Some("l".to_owned()))),
+            Err(Error::StationNotFound("10384".to_owned())),
+        ];
+        assert!(store(&targets, &stations, Ok((s, Some("s".to_owned()))), rls).unwrap());
+
+        for cache in [&a, &c] {
+            let cache = cache.read().unwrap();
+            assert_eq!(cache.forecast.len(), 1);
+            assert_eq!(cache.forecast_s_etag.as_deref(), Some("s"));
+            assert_eq!(cache.forecast_l_etag.as_deref(), Some("l"));
+        }
+        assert!(b.read().unwrap().forecast.is_empty());
+    }
+
+    const KML: &str = r#"<?xml version="1.0" encoding="ISO-8859-1" standalone="yes"?>
+<kml:kml xmlns:dwd="https://opendata.dwd.de/weather/lib/pointforecast_dwd_extension_V1_0.xsd" xmlns:kml="http://www.opengis.net/kml/2.2">
+  <kml:Document>
+    <kml:ExtendedData>
+      <dwd:ProductDefinition>
+        <dwd:ForecastTimeSteps>
+          <dwd:TimeStep>2025-06-21T11:00:00.000Z</dwd:TimeStep>
+          <dwd:TimeStep>2025-06-21T10:00:00.000Z</dwd:TimeStep>
+        </dwd:ForecastTimeSteps>
+      </dwd:ProductDefinition>
+    </kml:ExtendedData>
+    <kml:Placemark>
+      <kml:name>10382</kml:name>
+      <kml:description>BERLIN-TEGEL</kml:description>
+      <kml:ExtendedData>
+        <dwd:Forecast dwd:elementName="TTT">
+          <dwd:value>     294.15     293.15</dwd:value>
+        </dwd:Forecast>
+        <dwd:Forecast dwd:elementName="ww">
+          <dwd:value>     61.00     -</dwd:value>
+        </dwd:Forecast>
+      </kml:ExtendedData>
+    </kml:Placemark>
+    <kml:Placemark>
+      <kml:name>10384</kml:name>
+      <kml:description>BERLIN-TEMPELHOF</kml:description>
+      <kml:ExtendedData>
+        <dwd:Forecast dwd:elementName="TTT">
+          <dwd:value>     296.15     295.15</dwd:value>
+        </dwd:Forecast>
+      </kml:ExtendedData>
+    </kml:Placemark>
+    <kml:Placemark>
+      <kml:name>10385</kml:name>
+      <kml:description>BERLIN-SCHOENEFELD</kml:description>
+      <kml:ExtendedData>
+        <dwd:Forecast dwd:elementName="TTT">
+          <dwd:value>     298.15     297.15</dwd:value>
+        </dwd:Forecast>
+      </kml:ExtendedData>
+    </kml:Placemark>
+  </kml:Document>
+</kml:kml>
+"#;
+
+    #[test]
+    fn parse_multiple_stations() {
+        let (tegel, schoenefeld, missing) = (PoiStation(10382), PoiStation(10385), PoiStation(1));
+        let mut forecasts = parse_stations(KML.as_bytes(), &[schoenefeld, missing, tegel]).unwrap();
+        assert_eq!(forecasts.len(), 2);
+
+        let tegel = take(&mut forecasts, tegel).unwrap();
+        // sorted by time
+        assert!(tegel[0].timestamp < tegel[1].timestamp);
+        assert!((tegel[0].temperature.unwrap() - 20.0).abs() < 1e-3);
+        assert!((tegel[1].temperature.unwrap() - 21.0).abs() < 1e-3);
+        assert_eq!(tegel[1].condition, WeatherCondition::Kml(61));
+        assert_eq!(tegel[0].condition, WeatherCondition::None);
+
+        let schoenefeld = take(&mut forecasts, schoenefeld).unwrap();
+        assert!((schoenefeld[0].temperature.unwrap() - 24.0).abs() < 1e-3);
+        assert!(matches!(
+            take(&mut forecasts, missing),
+            Err(Error::StationNotFound(s)) if s == "1"
+        ));
+    }
+}
@@ -1,22 +1,32 @@
 use std::{
     path::PathBuf,
-    sync::{Arc, LazyLock, RwLock},
+    sync::{Arc, RwLock},
+    time::Instant,
 };
 
-use anyhow::anyhow;
 use icons::IconSet;
 use itertools::Itertools;
 
-use crate::option_ext::OptionExt;
+use crate::{condition::Condition, description::Language, option_ext::OptionExt};
 
+pub use error::{Error, Result};
+
+pub mod comfort;
+pub mod condition;
+pub mod description;
+mod error;
 pub mod forecast;
 pub mod icons;
+pub mod moon;
+#[cfg(feature = "async")]
+pub mod nonblocking;
 mod option_ext;
 pub mod radar;
 pub mod report;
+pub mod sun;
 pub mod synoptic;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PoiStation(pub u16);
 
 impl std::fmt::Display for PoiStation {
@@ -25,6 +35,13 @@ impl std::fmt::Display for PoiStation {
     }
 }
 
+/// A position in ° (north and east are positive).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
 #[derive(bincode::Encode, bincode::Decode, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WeatherCondition {
     /// present weather
@@ -43,19 +60,45 @@ impl WeatherCondition {
     pub fn is_none(&self) -> bool {
         *self == WeatherCondition::None
     }
+
+    /// The condition independent of the source.
+    pub fn normalized(&self) -> Option<Condition> {
+        match *self {
+            WeatherCondition::Poi(pw) => Condition::from_poi(pw),
+            WeatherCondition::Kml(ww) => Condition::from_ww(ww),
+            WeatherCondition::Synop(code) => Condition::from_synop(code),
+            WeatherCondition::None => None,
+        }
+    }
+
+    /// The text of the source code (e.g. "light rain showers").
+    pub fn description(&self, lang: Language) -> Option<&'static str> {
+        match *self {
+            WeatherCondition::Poi(pw) => description::poi(pw, lang),
+            WeatherCondition::Kml(ww) => description::mosmix(ww, lang),
+            WeatherCondition::Synop(code) => description::synop(code, lang),
+            WeatherCondition::None => None,
+        }
+    }
 }
 
 #[derive(bincode::Encode, bincode::Decode, Debug, Clone, PartialEq)]
 pub struct Datapoint {
     #[bincode(with_serde)]
     pub timestamp: jiff::Timestamp,
-    #[bincode(with_serde)]
-    pub local_ts: jiff::Zoned,
     pub condition: WeatherCondition,
     /// In °C
     pub temperature: Option<f32>,
     /// In mm
     pub precipitation: Option<f32>,
+    /// In mm during the last 3h
+    pub precipitation_3h: Option<f32>,
+    /// In mm during the last 6h
+    pub precipitation_6h: Option<f32>,
+    /// In mm during the last 12h
+    pub precipitation_12h: Option<f32>,
+    /// In mm during the last 24h
+    pub precipitation_24h: Option<f32>,
     /// In %
     pub p_precipitation: Option<f32>,
     /// In %
@@ -68,43 +111,146 @@ pub struct Datapoint {
     pub wind_gusts: Option<f32>,
     /// In °
     pub wind_dir: Option<f32>,
+    /// In °C
+    pub dew_point: Option<f32>,
+    /// In °C, 5cm above ground
+    pub ground_temperature: Option<f32>,
+    /// In °C, minimum of the last 12h
+    pub min_temperature: Option<f32>,
+    /// In °C, maximum of the last 12h
+    pub max_temperature: Option<f32>,
+    /// In °C, minimum of the last 12h at 5cm above ground
+    pub ground_min_temperature: Option<f32>,
+    /// In hPa at station level
+    pub pressure: Option<f32>,
+    /// In hPa, reduced to mean sea level
+    pub pressure_msl: Option<f32>,
+    /// In hPa, change during the last 3h
+    pub pressure_tendency: Option<f32>,
+    /// In km
+    pub visibility: Option<f32>,
+    /// In m above ground
+    pub cloud_base: Option<f32>,
+    /// Observed cloud layers, the lowest first
+    pub cloud_layers: [CloudLayer; 4],
+    /// Past weather (W1), see WMO code table 4561
+    pub past_weather: Option<u16>,
+    /// In W/m² during the last hour
+    pub global_radiation: Option<f32>,
+    /// In W/m² during the last hour
+    pub diffuse_radiation: Option<f32>,
+    /// In min during the last hour
+    pub sunshine: Option<f32>,
+    /// In cm
+    pub snow_depth: Option<f32>,
 
     pub is_report: bool,
 }
 
+#[derive(bincode::Encode, bincode::Decode, Debug, Clone, Copy, Default, PartialEq)]
+pub struct CloudLayer {
+    /// In eighths
+    pub amount: Option<f32>,
+    /// In m above ground
+    pub height: Option<f32>,
+}
+
 #[derive(bincode::Encode, bincode::Decode, Debug, Clone, PartialEq)]
 pub struct RadarReading {
     #[bincode(with_serde)]
     pub timestamp: jiff::Timestamp,
-    #[bincode(with_serde)]
-    pub local_ts: jiff::Zoned,
     // In mm/h
     pub value: f32,
 }
 
+/// The latest observation of a SYNOP station
+#[derive(bincode::Encode, bincode::Decode, Debug, Clone, PartialEq)]
+pub struct StationObservation {
+    /// WIGOS local identifier as in [`Config::synop_stations`]
+    pub id: String,
+    pub name: Option<String>,
+    /// In °
+    pub latitude: Option<f32>,
+    /// In °
+    pub longitude: Option<f32>,
+    /// In m above mean sea level
+    pub elevation: Option<f32>,
+    /// Observed values (the timestamp is the observation time)
+    pub point: Datapoint,
+}
+
+impl StationObservation {
+    /// Merges the observations of multiple stations into one point.
+    /// Stations at the start take priority.
+    pub fn merge(observations: &[StationObservation]) -> Option<Datapoint> {
+        observations
+            .iter()
+            .fold(None, |r: Option<Datapoint>, v| match r {
+                Some(mut d) => {
+                    d.merge_from(&v.point);
+                    Some(d)
+                }
+                None => Some(v.point.clone()),
+            })
+    }
+
+    /// Merges `other` into `self`, preferring the values of the newer observation.
+    pub fn merge_from(&mut self, other: &StationObservation) {
+        if other.point.timestamp > self.point.timestamp {
+            let mut point = other.point.clone();
+            point.merge_from(&self.point);
+            self.point = point;
+        } else {
+            self.point.merge_from(&other.point);
+        }
+        self.name.or_assign(other.name.clone());
+        self.latitude.or_assign(other.latitude);
+        self.longitude.or_assign(other.longitude);
+        self.elevation.or_assign(other.elevation);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Config {
     pub poi_station: PoiStation,
+    pub coordinates: Coordinates,
     pub radar_coords: (usize, usize),
     pub synop_stations: Vec<String>,
 }
 
-pub static ZONE: LazyLock<jiff::tz::TimeZone> = LazyLock::new(jiff::tz::TimeZone::system);
-
 impl Datapoint {
     pub fn from_timestamp(timestamp: jiff::Timestamp, is_report: bool) -> Self {
         Self {
             timestamp,
-            local_ts: timestamp.to_zoned(ZONE.clone()),
             condition: WeatherCondition::None,
             temperature: None,
             precipitation: None,
+            precipitation_3h: None,
+            precipitation_6h: None,
+            precipitation_12h: None,
+            precipitation_24h: None,
             p_precipitation: None,
             cloud_cover: None,
             relative_humidity: None,
             mean_wind: None,
             wind_gusts: None,
             wind_dir: None,
+            dew_point: None,
+            ground_temperature: None,
+            min_temperature: None,
+            max_temperature: None,
+            ground_min_temperature: None,
+            pressure: None,
+            pressure_msl: None,
+            pressure_tendency: None,
+            visibility: None,
+            cloud_base: None,
+            cloud_layers: Default::default(),
+            past_weather: None,
+            global_radiation: None,
+            diffuse_radiation: None,
+            sunshine: None,
+            snow_depth: None,
             is_report,
         }
     }
@@ -115,12 +261,36 @@ impl Datapoint {
         }
         self.temperature.or_assign(other.temperature);
         self.precipitation.or_assign(other.precipitation);
+        self.precipitation_3h.or_assign(other.precipitation_3h);
+        self.precipitation_6h.or_assign(other.precipitation_6h);
+        self.precipitation_12h.or_assign(other.precipitation_12h);
+        self.precipitation_24h.or_assign(other.precipitation_24h);
         self.p_precipitation.or_assign(other.p_precipitation);
         self.cloud_cover.or_assign(other.cloud_cover);
         self.relative_humidity.or_assign(other.relative_humidity);
         self.mean_wind.or_assign(other.mean_wind);
         self.wind_gusts.or_assign(other.wind_gusts);
         self.wind_dir.or_assign(other.wind_dir);
+        self.dew_point.or_assign(other.dew_point);
+        self.ground_temperature.or_assign(other.ground_temperature);
+        self.min_temperature.or_assign(other.min_temperature);
+        self.max_temperature.or_assign(other.max_temperature);
+        self.ground_min_temperature
+            .or_assign(other.ground_min_temperature);
+        self.pressure.or_assign(other.pressure);
+        self.pressure_msl.or_assign(other.pressure_msl);
+        self.pressure_tendency.or_assign(other.pressure_tendency);
+        self.visibility.or_assign(other.visibility);
+        self.cloud_base.or_assign(other.cloud_base);
+        for (layer, other) in self.cloud_layers.iter_mut().zip(&other.cloud_layers) {
+            layer.amount.or_assign(other.amount);
+            layer.height.or_assign(other.height);
+        }
+        self.past_weather.or_assign(other.past_weather);
+        self.global_radiation.or_assign(other.global_radiation);
+        self.diffuse_radiation.or_assign(other.diffuse_radiation);
+        self.sunshine.or_assign(other.sunshine);
+        self.snow_depth.or_assign(other.snow_depth);
     }
 
     pub fn merge_series_ref(left: &[Datapoint], right: &[Datapoint]) -> Vec<Datapoint> {
@@ -152,18 +322,28 @@ impl Datapoint {
             .collect()
     }
 
-    pub fn icon<I: IconSet>(&self) -> Option<PathBuf> {
-        let is_night = self.local_ts.hour() < 7 || self.local_ts.hour() > 20;
-        match self.condition {
-            WeatherCondition::Poi(pw) => Some(I::present_weather_to_path(pw, is_night)),
-            WeatherCondition::Kml(sw) | WeatherCondition::Synop(sw) => Some(
-                I::significant_weather_to_path(sw, self.cloud_cover.unwrap_or_default(), is_night),
-            ),
-            WeatherCondition::None => None,
-        }
+    /// The text of the condition in `lang`.
+    pub fn description(&self, lang: Language) -> Option<&'static str> {
+        self.condition.description(lang)
+    }
+
+    /// The icon of the condition, `at` decides between day and night.
+    pub fn icon<I: IconSet>(&self, at: Coordinates) -> Option<PathBuf> {
+        let is_night = sun::is_night(self.timestamp, at);
+        let condition = self.condition.normalized()?;
+        Some(I::condition_to_path(condition, self.cloud_cover, is_night))
     }
 }
 
+/// Bumped when the meaning of cached values changes, older caches are discarded.
+///
+/// - 2: wind speeds from MOSMIX and SYNOP are stored in km/h instead of m/s
+/// - 3: more report columns (precipitation periods, radiation, past weather, cloud layers)
+/// - 4: no local timestamps, labels convert to the configured time zone
+///
+/// Caches without a version start with an `Option` tag (0 or 1).
+const CACHE_VERSION: u32 = 4;
+
 #[derive(bincode::Encode, bincode::Decode, Debug, Default)]
 pub struct Cache {
     pub(self) report_etag: Option<String>,
@@ -175,20 +355,25 @@ pub struct Cache {
     pub report: Vec<Datapoint>,
     pub forecast: Vec<Datapoint>,
     pub radar: Vec<RadarReading>,
-    pub observation: Option<Datapoint>,
+    /// Latest observation per SYNOP station, in the order of [`Config::synop_stations`]
+    pub observations: Vec<StationObservation>,
 }
 
 impl Cache {
-    pub fn from_file(name: &str) -> anyhow::Result<Self> {
-        bincode::decode_from_slice(&std::fs::read(name)?, bincode::config::standard())
-            .map_err(Into::into)
-            .map(|it| it.0)
+    pub fn from_file(name: &str) -> Result<Self> {
+        let data = std::fs::read(name)?;
+        let (version, read): (u32, _) =
+            bincode::decode_from_slice(&data, bincode::config::standard())?;
+        if version != CACHE_VERSION {
+            return Err(Error::CacheVersion(version));
+        }
+        Ok(bincode::decode_from_slice(&data[read..], bincode::config::standard())?.0)
     }
 
-    pub fn to_file(&self, name: &str) -> anyhow::Result<()> {
+    pub fn to_file(&self, name: &str) -> Result<()> {
         std::fs::write(
             name,
-            &bincode::encode_to_vec(self, bincode::config::standard())?,
+            &bincode::encode_to_vec((CACHE_VERSION, self), bincode::config::standard())?,
         )?;
         Ok(())
     }
@@ -197,7 +382,24 @@ impl Cache {
         Self::from_file(name).unwrap_or_default()
     }
 
-    pub fn refetch(c: &Arc<RwLock<Self>>, config: &Config) -> anyhow::Result<bool> {
+    /// The observations of all stations merged into one point
+    pub fn observation(&self) -> Option<Datapoint> {
+        StationObservation::merge(&self.observations)
+    }
+
+    pub fn refetch(c: &Arc<RwLock<Self>>, config: &Config) -> Result<bool> {
+        std::thread::scope(|s| {
+            let observations_t = s.spawn(|| Self::refetch_observations(c, config));
+            let forecast = forecast::get(config.poi_station, c)
+                .inspect_err(|e| tracing::warn!(%e, "Failed to fetch forecast"))
+                .unwrap_or(false);
+            let observations = observations_t.join().map_err(|_| Error::Panicked)??;
+            Ok(forecast || observations)
+        })
+    }
+
+    /// Refetches everything but the forecast
+    fn refetch_observations(c: &Arc<RwLock<Self>>, config: &Config) -> Result<bool> {
         std::thread::scope(|s| {
             let report_t = s.spawn({
                 let cache = c.clone();
@@ -209,30 +411,65 @@ impl Cache {
                 let coords = config.radar_coords;
                 move || radar::get(&cache, coords)
             });
-            let synop_t = s.spawn({
-                let cache = c.clone();
-                let stations = &config.synop_stations;
-                move || synoptic::get(&cache, stations)
-            });
-            let forecast = forecast::get(config.poi_station, c)
-                .inspect_err(|e| eprintln!("Failed to fetch forecast: {e}"))
+            let synop = synoptic::get(c, &config.synop_stations)
+                .inspect_err(|e| tracing::warn!(%e, "Failed to fetch synop"))
                 .unwrap_or(false);
             let report = report_t
                 .join()
-                .map_err(|_| anyhow!("Failed to join"))?
-                .inspect_err(|e| eprintln!("Failed to fetch report: {e}"))
+                .map_err(|_| Error::Panicked)?
+                .inspect_err(|e| tracing::warn!(%e, "Failed to fetch report"))
                 .unwrap_or(false);
             let radar = radar_t
                 .join()
-                .map_err(|_| anyhow!("Failed to join"))?
-                .inspect_err(|e| eprintln!("Failed to fetch radar: {e}"))
+                .map_err(|_| Error::Panicked)?
+                .inspect_err(|e| tracing::warn!(%e, "Failed to fetch radar"))
                 .unwrap_or(false);
-            let synop = synop_t
-                .join()
-                .map_err(|_| anyhow!("Failed to join"))?
-                .inspect_err(|e| eprintln!("Failed to fetch synop: {e}"))
+            Ok(report || radar || synop)
+        })
+    }
+}
+
+/// A named location (e.g. "home" or "office") with its own cache.
+#[derive(Debug, Clone)]
+pub struct Location {
+    pub name: String,
+    pub config: Config,
+    pub cache: Arc<RwLock<Cache>>,
+}
+
+impl Location {
+    pub fn new(name: impl Into<String>, config: Config, cache: Cache) -> Self {
+        Self {
+            name: name.into(),
+            config,
+            cache: Arc::new(RwLock::new(cache)),
+        }
+    }
+
+    pub fn find<'a>(locations: &'a [Location], name: &str) -> Option<&'a Location> {
+        locations.iter().find(|l| l.name == name)
+    }
+
+    /// Refetches the caches of all locations.
+    ///
+    /// The MOSMIX_S forecast contains all stations, so it's only downloaded once.
+    pub fn refetch_all(locations: &[Location]) -> Result<bool> {
+        std::thread::scope(|s| {
+            let observation_ts = locations
+                .iter()
+                .map(|l| s.spawn(|| Cache::refetch_observations(&l.cache, &l.config)))
+                .collect::<Vec<_>>();
+            let targets = locations
+                .iter()
+                .map(|l| (l.config.poi_station, &*l.cache))
+                .collect::<Vec<_>>();
+            let mut updated = forecast::get_all(&targets)
+                .inspect_err(|e| tracing::warn!(%e, "Failed to fetch forecast"))
                 .unwrap_or(false);
-            Ok(forecast || report || radar || synop)
+            for t in observation_ts {
+                updated |= t.join().map_err(|_| Error::Panicked)??;
+            }
+            Ok(updated)
         })
     }
 }
@@ -250,33 +487,92 @@ pub fn latlong_to_idx(lat: f64, long: f64) -> (usize, usize) {
     )
 }
 
-fn get_etag(res: &ureq::http::Response<ureq::Body>) -> Option<String> {
-    res.headers()
+/// The ETag header of a response, ureq and reqwest share the `http` types
+fn get_etag(headers: &ureq::http::HeaderMap) -> Option<String> {
+    headers
         .get("ETag")
         .and_then(|t| t.to_str().ok())
         .map(|s| s.to_owned())
 }
 
-fn needs_fetch<U>(url: U, prev_etag: Option<&str>) -> bool
-where
-    ureq::http::Uri: TryFrom<U>,
-    <ureq::http::Uri as TryFrom<U>>::Error: Into<ureq::http::Error>,
-{
-    if prev_etag.is_none() {
-        return true;
-    }
+/// Downloads the whole body of `url`
+fn download(url: &str) -> Result<(Vec<u8>, Option<String>)> {
+    let start = Instant::now();
+    let mut res = ureq::get(url).call().map_err(|e| Error::request(url, e))?;
+    let etag = get_etag(res.headers());
+    let bytes = res.body_mut().with_config().limit(1 << 28).read_to_vec()?;
+    tracing::info!(url, bytes = bytes.len(), elapsed = ?start.elapsed(), "Downloaded");
+
+    Ok((bytes, etag))
+}
 
-    let Ok(res) = ureq::head(url).call() else {
+fn needs_fetch(url: &str, prev_etag: Option<&str>) -> bool {
+    let Some(prev_etag) = prev_etag else {
+        tracing::debug!(url, "No previous ETag, fetching");
         return true;
     };
-    if !res.status().is_success() {
-        return true;
+
+    let res = match ureq::head(url).call() {
+        Ok(res) => res,
+        res => {
+            tracing::debug!(url, status = ?res.map(|r| r.status()), "HEAD failed, fetching");
+            return true;
+        }
+    };
+    etag_changed(url, prev_etag, res.headers())
+}
+
+/// Whether the ETag in the `headers` of a HEAD request differs from the cached one
+fn etag_changed(url: &str, prev_etag: &str, headers: &ureq::http::HeaderMap) -> bool {
+    let etag = get_etag(headers);
+    let fetch = etag.as_deref().is_none_or(|etag| etag != prev_etag);
+    tracing::debug!(
+        url,
+        prev_etag,
+        etag = etag.as_deref(),
+        fetch,
+        "Checked ETag"
+    );
+
+    fetch
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_version() {
+        let path = std::env::temp_dir().join(format!("dwd-fetch-cache-{}.bin", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let cache = Cache {
+            report_etag: Some("etag".to_owned()),
+            ..Default::default()
+        };
+        cache.to_file(path).unwrap();
+        assert_eq!(
+            Cache::from_file(path).unwrap().report_etag,
+            cache.report_etag
+        );
+
+        // caches from before the version started with the etag
+        let old = bincode::encode_to_vec(&cache, bincode::config::standard()).unwrap();
+        std::fs::write(path, old).unwrap();
+        assert!(matches!(
+            Cache::from_file(path),
+            Err(Error::CacheVersion(1))
+        ));
+
+        std::fs::remove_file(path).unwrap();
     }
-    let etag = get_etag(&res);
 
-    if let (Some(etag), Some(prev_etag)) = (&etag, prev_etag) {
-        etag != prev_etag
-    } else {
-        true
+    #[test]
+    fn changed_etag() {
+        let mut headers = ureq::http::HeaderMap::new();
+        assert!(etag_changed("url", "a", &headers));
+        headers.insert("ETag", "a".parse().unwrap());
+        assert!(!etag_changed("url", "a", &headers));
+        assert!(etag_changed("url", "b", &headers));
     }
 }
@@ -0,0 +1,110 @@
+//! Phase of the moon.
+//!
+//! Based on the mean length of the synodic month, the phases are accurate to about a day.
+
+use jiff::Timestamp;
+
+use crate::{description::Language, sun::julian_day};
+
+/// Mean time between two new moons in days
+const SYNODIC_MONTH: f64 = 29.530588853;
+/// Julian day of the new moon on 2000-01-06 18:14 UTC
+const NEW_MOON: f64 = 2451550.26;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum Phase {
+    #[display("New moon")]
+    New,
+    #[display("Waxing crescent")]
+    WaxingCrescent,
+    #[display("First quarter")]
+    FirstQuarter,
+    #[display("Waxing gibbous")]
+    WaxingGibbous,
+    #[display("Full moon")]
+    Full,
+    #[display("Waning gibbous")]
+    WaningGibbous,
+    #[display("Last quarter")]
+    LastQuarter,
+    #[display("Waning crescent")]
+    WaningCrescent,
+}
+
+impl Phase {
+    /// Name of the phase in the given language.
+    pub fn text(self, lang: Language) -> &'static str {
+        use Phase::*;
+
+        match (self, lang) {
+            (New, Language::German) => "Neumond",
+            (WaxingCrescent, Language::German) => "Zunehmende Sichel",
+            (FirstQuarter, Language::German) => "Erstes Viertel",
+            (WaxingGibbous, Language::German) => "Zunehmender Mond",
+            (Full, Language::German) => "Vollmond",
+            (WaningGibbous, Language::German) => "Abnehmender Mond",
+            (LastQuarter, Language::German) => "Letztes Viertel",
+            (WaningCrescent, Language::German) => "Abnehmende Sichel",
+            (New, Language::English) => "New moon",
+            (WaxingCrescent, Language::English) => "Waxing crescent",
+            (FirstQuarter, Language::English) => "First quarter",
+            (WaxingGibbous, Language::English) => "Waxing gibbous",
+            (Full, Language::English) => "Full moon",
+            (WaningGibbous, Language::English) => "Waning gibbous",
+            (LastQuarter, Language::English) => "Last quarter",
+            (WaningCrescent, Language::English) => "Waning crescent",
+        }
+    }
+}
+
+/// Fraction of the synodic month since the last new moon (0 = new moon, 0.5 = full moon).
+pub fn age(ts: Timestamp) -> f64 {
+    ((julian_day(ts) - NEW_MOON) / SYNODIC_MONTH).rem_euclid(1.0)
+}
+
+/// Illuminated fraction of the disk (0 - 1).
+pub fn illumination(ts: Timestamp) -> f64 {
+    (1.0 - (age(ts) * std::f64::consts::TAU).cos()) / 2.0
+}
+
+/// The closest of the eight principal phases.
+pub fn phase(ts: Timestamp) -> Phase {
+    use Phase::*;
+
+    const PHASES: [Phase; 8] = [
+        New,
+        WaxingCrescent,
+        FirstQuarter,
+        WaxingGibbous,
+        Full,
+        WaningGibbous,
+        LastQuarter,
+        WaningCrescent,
+    ];
+    PHASES[(age(ts) * 8.0).round() as usize % 8]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_moon_january_2025() {
+        // 2025-01-13 22:27 UTC
+        let full: Timestamp = "2025-01-13T22:27:00Z".parse().unwrap();
+        assert!((age(full) - 0.5).abs() * SYNODIC_MONTH < 1.0);
+        assert!(illumination(full) > 0.99);
+        assert_eq!(phase(full), Phase::Full);
+        assert_eq!(Phase::Full.text(Language::German), "Vollmond");
+        assert_eq!(Phase::Full.text(Language::English), Phase::Full.to_string());
+
+        // new moon on 2025-01-29 12:36 UTC
+        let new: Timestamp = "2025-01-29T12:36:00Z".parse().unwrap();
+        assert!(illumination(new) < 0.01);
+        assert_eq!(phase(new), Phase::New);
+        assert_eq!(
+            phase("2025-01-06T23:56:00Z".parse().unwrap()),
+            Phase::FirstQuarter
+        );
+    }
+}
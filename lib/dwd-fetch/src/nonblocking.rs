@@ -0,0 +1,271 @@
+//! Async variant of the fetching API (requires the `async` feature).
+//!
+//! It uses the same sources and ETags as the blocking API and writes to the same [`Cache`].
+//! Parsing runs on tokio's blocking pool, and dropping a future cancels its downloads.
+
+use std::{
+    collections::HashMap,
+    ops::ControlFlow,
+    sync::{Arc, LazyLock, RwLock},
+    time::Instant,
+};
+
+use tokio::task::JoinSet;
+
+use crate::{
+    Cache, Config, Error, Location, PoiStation, Result, StationObservation,
+    forecast::{self, MosmixType, StationForecasts},
+    radar, report, synoptic,
+};
+
+static CLIENT: LazyLock<reqwest::Client> = LazyLock::new(reqwest::Client::new);
+
+impl Cache {
+    /// Async version of [`Cache::refetch`].
+    pub async fn refetch_async(c: &Arc<RwLock<Self>>, config: &Config) -> Result<bool> {
+        let targets = [(config.poi_station, &**c)];
+        let (forecast, observations) = tokio::join!(
+            get_forecasts(&targets),
+            Self::refetch_observations_async(c, config)
+        );
+        let forecast = forecast
+            .inspect_err(|e| tracing::warn!(%e, "Failed to fetch forecast"))
+            .unwrap_or(false);
+        Ok(forecast || observations)
+    }
+
+    /// Refetches everything but the forecast
+    async fn refetch_observations_async(c: &RwLock<Self>, config: &Config) -> bool {
+        let (report, radar, synop) = tokio::join!(
+            get_report(config.poi_station, c),
+            get_radar(c, config.radar_coords),
+            get_synop(c, &config.synop_stations)
+        );
+        let report = report
+            .inspect_err(|e| tracing::warn!(%e, "Failed to fetch report"))
+            .unwrap_or(false);
+        let radar = radar
+            .inspect_err(|e| tracing::warn!(%e, "Failed to fetch radar"))
+            .unwrap_or(false);
+        let synop = synop
+            .inspect_err(|e| tracing::warn!(%e, "Failed to fetch synop"))
+            .unwrap_or(false);
+        report || radar || synop
+    }
+}
+
+impl Location {
+    /// Async version of [`Location::refetch_all`].
+    pub async fn refetch_all_async(locations: &[Location]) -> Result<bool> {
+        let mut observations = JoinSet::new();
+        for l in locations {
+            let l = l.clone();
+            observations
+                .spawn(async move { Cache::refetch_observations_async(&l.cache, &l.config).await });
+        }
+        let targets = locations
+            .iter()
+            .map(|l| (l.config.poi_station, &*l.cache))
+            .collect::<Vec<_>>();
+        let mut updated = get_forecasts(&targets)
+            .await
+            .inspect_err(|e| tracing::warn!(%e, "Failed to fetch forecast"))
+            .unwrap_or(false);
+        while let Some(res) = observations.join_next().await {
+            updated |= res?;
+        }
+        Ok(updated)
+    }
+}
+
+/// Async version of [`forecast::get_all`].
+#[tracing::instrument(name = "forecast", skip_all, fields(stations = targets.len()))]
+pub async fn get_forecasts(targets: &[(PoiStation, &RwLock<Cache>)]) -> Result<bool> {
+    let mut needs_any = false;
+    for (station, cache) in targets {
+        let (l_etag, s_etag) = {
+            let cache = cache.read().unwrap();
+            (cache.forecast_l_etag.clone(), cache.forecast_s_etag.clone())
+        };
+        if needs_fetch(&MosmixType::L.url(*station), l_etag.as_deref()).await
+            || needs_fetch(&MosmixType::S.url(*station), s_etag.as_deref()).await
+        {
+            needs_any = true;
+            break;
+        }
+    }
+    if !needs_any {
+        return Ok(false);
+    }
+
+    let stations = forecast::unique_stations(targets);
+    let mut ls = JoinSet::new();
+    // to assign failed tasks to their station
+    let mut task_idx = HashMap::new();
+    for (idx, station) in stations.iter().copied().enumerate() {
+        let handle = ls.spawn(async move {
+            let res = fetch_mosmix(vec![station], MosmixType::L)
+                .await
+                .and_then(|(mut data, etag)| Ok((forecast::take(&mut data, station)?, etag)));
+            (idx, res)
+        });
+        task_idx.insert(handle.id(), idx);
+    }
+    let (rs, rls) = tokio::join!(fetch_mosmix(stations.clone(), MosmixType::S), async {
+        let mut rls = stations
+            .iter()
+            .map(|_| Err(Error::Panicked))
+            .collect::<Vec<_>>();
+        while let Some(joined) = ls.join_next().await {
+            match joined {
+                Ok((idx, res)) => rls[idx] = res,
+                Err(e) => {
+                    if let Some(&idx) = task_idx.get(&e.id()) {
+                        rls[idx] = Err(Error::Task(e));
+                    }
+                }
+            }
+        }
+        rls
+    });
+
+    forecast::store(targets, &stations, rs, rls)
+}
+
+async fn fetch_mosmix(
+    stations: Vec<PoiStation>,
+    ty: MosmixType,
+) -> Result<(StationForecasts, Option<String>)> {
+    let Some(first) = stations.first() else {
+        return Ok(Default::default());
+    };
+    let (bytes, etag) = download(&ty.url(*first)).await?;
+    let data = tokio::task::spawn_blocking(move || forecast::parse_kmz(bytes, &stations)).await??;
+    Ok((data, etag))
+}
+
+/// Async version of [`report::get`].
+#[tracing::instrument(name = "report", skip(cache))]
+pub async fn get_report(station: PoiStation, cache: &RwLock<Cache>) -> Result<bool> {
+    let url = report::url(station);
+    let prev_etag = cache.read().unwrap().report_etag.clone();
+    if !needs_fetch(&url, prev_etag.as_deref()).await {
+        return Ok(false);
+    }
+
+    let (bytes, etag) = download(&url).await?;
+    let data = tokio::task::spawn_blocking(move || report::parse(bytes.as_slice())).await??;
+    let mut cache = cache.write().unwrap();
+    cache.report_etag = etag;
+    cache.report = data;
+
+    Ok(true)
+}
+
+/// Async version of [`radar::get`].
+#[tracing::instrument(name = "radar", skip(cache))]
+pub async fn get_radar(cache: &RwLock<Cache>, target: (usize, usize)) -> Result<bool> {
+    let prev_etag = cache.read().unwrap().radar_etag.clone();
+    if !needs_fetch(radar::URL, prev_etag.as_deref()).await {
+        return Ok(false);
+    }
+
+    let (bytes, etag) = download(radar::URL).await?;
+    let values = tokio::task::spawn_blocking(move || radar::read_archive(bytes.as_slice(), target))
+        .await??;
+    let mut cache = cache.write().unwrap();
+    cache.radar_etag = etag;
+    cache.radar = values;
+
+    Ok(true)
+}
+
+/// Async version of [`synoptic::get`].
+#[tracing::instrument(name = "synop", skip(cache))]
+pub async fn get_synop(cache: &RwLock<Cache>, stations: &[String]) -> Result<bool> {
+    let prev_etag = cache.read().unwrap().synop_etag.clone();
+    if !needs_fetch(synoptic::URL, prev_etag.as_deref()).await {
+        return Ok(false);
+    }
+
+    let (bytes, etag) = download(synoptic::URL).await?;
+    let (mut observations, _) = read_synop(bytes, stations).await?;
+    let is_old = observations.is_empty() && synoptic::last_observation_is_old(cache);
+    if is_old {
+        tracing::info!("No station in the latest report, trying older ones");
+        observations = try_old_reports(stations).await;
+    }
+
+    synoptic::store(cache, observations, etag, is_old, stations)
+}
+
+async fn try_old_reports(stations: &[String]) -> Vec<StationObservation> {
+    let Ok(res) = CLIENT.get(synoptic::LISTING_URL).send().await else {
+        return Vec::new();
+    };
+    if !res.status().is_success() {
+        return Vec::new();
+    }
+    let Ok(body) = res.text().await else {
+        return Vec::new();
+    };
+    for url in synoptic::old_report_urls(&body) {
+        let Ok((bytes, _)) = download(&url)
+            .await
+            .inspect_err(|e| tracing::warn!(%e, "Failed to fetch old report"))
+        else {
+            continue;
+        };
+
+        if let ControlFlow::Break(observations) =
+            synoptic::check_old_report(read_synop(bytes, stations).await)
+        {
+            return observations;
+        }
+    }
+
+    Vec::new()
+}
+
+async fn read_synop(
+    bytes: Vec<u8>,
+    stations: &[String],
+) -> Result<(Vec<StationObservation>, Option<jiff::Timestamp>)> {
+    let stations = stations.to_vec();
+    tokio::task::spawn_blocking(move || {
+        synoptic::read_file_to_observations(bytes.as_slice(), &stations)
+    })
+    .await?
+}
+
+async fn download(url: &str) -> Result<(Vec<u8>, Option<String>)> {
+    let start = Instant::now();
+    let res = CLIENT.get(url).send().await?;
+    if !res.status().is_success() {
+        return Err(Error::Status {
+            url: url.to_owned(),
+            status: res.status().as_u16(),
+        });
+    }
+    let etag = crate::get_etag(res.headers());
+    let bytes = res.bytes().await?;
+    tracing::info!(url, bytes = bytes.len(), elapsed = ?start.elapsed(), "Downloaded");
+
+    Ok((bytes.into(), etag))
+}
+
+async fn needs_fetch(url: &str, prev_etag: Option<&str>) -> bool {
+    let Some(prev_etag) = prev_etag else {
+        tracing::debug!(url, "No previous ETag, fetching");
+        return true;
+    };
+
+    let res = match CLIENT.head(url).send().await {
+        Ok(res) if res.status().is_success() => res,
+        res => {
+            tracing::debug!(url, status = ?res.map(|r| r.status()), "HEAD failed, fetching");
+            return true;
+        }
+    };
+    crate::etag_changed(url, prev_etag, res.headers())
+}
@@ -1,11 +1,10 @@
 use std::{
-    io::{self, Read},
+    io::{self, Read, Write},
     sync::RwLock,
+    time::Instant,
 };
 
-use anyhow::bail;
-
-use crate::{Cache, RadarReading, ZONE, get_etag, needs_fetch};
+use crate::{Cache, Error, RadarReading, Result, download, needs_fetch};
 
 const STATIC_HEADER_LEN: usize = 91;
 const DATE_0_OFFSET: usize = 2;
@@ -13,51 +12,69 @@ const DATE_1_OFFSET: usize = 13;
 const PR_OFFSET: usize = 46 + 3; // skip ' E-'
 const INT_OFFSET: usize = 54;
 const VV_OFFSET: usize = 71;
+/// Header end (ETX)
+const HEADER_END: u8 = 0x3;
+
+/// Size of the composite in km
+const WIDTH: usize = 1100;
+const HEIGHT: usize = 1200;
 
-const URL: &str = "https://opendata.dwd.de/weather/radar/composite/rv/DE1200_RV_LATEST.tar.bz2";
+pub(crate) const URL: &str =
+    "https://opendata.dwd.de/weather/radar/composite/rv/DE1200_RV_LATEST.tar.bz2";
 
-pub fn get(cache: &RwLock<Cache>, target: (usize, usize)) -> anyhow::Result<bool> {
+#[tracing::instrument(name = "radar", skip(cache))]
+pub fn get(cache: &RwLock<Cache>, target: (usize, usize)) -> Result<bool> {
     if !needs_fetch(URL, cache.read().unwrap().radar_etag.as_deref()) {
         return Ok(false);
     }
 
-    let mut res = ureq::get(URL).call()?;
-    if !res.status().is_success() {
-        bail!("Failed to get radar - got status {:?}", res.status());
-    }
-    let etag = get_etag(&res);
+    let (bytes, etag) = download(URL)?;
+    let values = read_archive(bytes.as_slice(), target)?;
+    let mut cache = cache.write().unwrap();
+    cache.radar_etag = etag;
+    cache.radar = values;
 
-    let reader = bzip2::read::BzDecoder::new(res.body_mut().as_reader());
+    Ok(true)
+}
 
-    let mut ar = tar::Archive::new(reader);
+/// Reads the value at `target` from every composite of a `.tar.bz2` bundle, sorted by time.
+pub fn read_archive(reader: impl Read, target: (usize, usize)) -> Result<Vec<RadarReading>> {
+    let start = Instant::now();
+    let mut ar = tar::Archive::new(bzip2::read::BzDecoder::new(reader));
     let mut values = Vec::new();
-    for e in ar.entries()?.filter_map(Result::ok) {
+    for e in ar
+        .entries()
+        .map_err(Error::decompression)?
+        .filter_map(Result::ok)
+    {
         values.push(read_rv(e, target)?);
     }
     values.sort_unstable_by_key(|v| v.timestamp);
-    let mut cache = cache.write().unwrap();
-    cache.radar_etag = etag;
-    cache.radar = values;
-
-    Ok(true)
+    tracing::debug!(readings = values.len(), elapsed = ?start.elapsed(), "Parsed radar");
+    Ok(values)
 }
 
 // https://www.dwd.de/DE/leistungen/radarprodukte/formatbeschreibung_rv.pdf?__blob=publicationFile&v=3
-fn read_rv(
-    mut reader: impl Read,
-    (target_x, target_y): (usize, usize),
-) -> anyhow::Result<RadarReading> {
+fn read_rv(mut reader: impl Read, (target_x, target_y): (usize, usize)) -> Result<RadarReading> {
+    // the 3x3 average needs one pixel around the target
+    if !(1..WIDTH - 1).contains(&target_x) || !(1..HEIGHT - 1).contains(&target_y) {
+        return Err(Error::format(
+            "radar coordinates",
+            format!("({target_x}, {target_y}) is outside of the composite"),
+        ));
+    }
     let mut header = [0; STATIC_HEADER_LEN];
     reader.read_exact(&mut header)?;
-    let len = std::str::from_utf8(&header[STATIC_HEADER_LEN - 3..])?
-        .trim()
-        .parse::<u64>()?;
+    let len = std::str::from_utf8(&header[STATIC_HEADER_LEN - 3..])
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .ok_or_else(|| Error::format("radar composite", "invalid header length"))?;
     // discard string
     io::copy(&mut reader.by_ref().take(len), &mut io::sink())?;
     let mut header_end = [0; 1];
     reader.read_exact(&mut header_end)?;
-    if header_end[0] != 0x3 {
-        bail!("Invalid header");
+    if header_end[0] != HEADER_END {
+        return Err(Error::format("radar composite", "invalid header end"));
     }
 
     let (Some(day), Some(hour), Some(minute), Some(month), Some(year)) = (
@@ -67,26 +84,25 @@ fn read_rv(
         atoi::atoi::<i8>(&header[DATE_1_OFFSET..DATE_1_OFFSET + 2]),
         atoi::atoi::<i16>(&header[DATE_1_OFFSET + 2..DATE_1_OFFSET + 4]),
     ) else {
-        bail!("Invalid date")
+        return Err(Error::format("radar composite", "invalid date"));
     };
     let (Some(precision), Some(interval), Some(offset_min)) = (
         atoi::atoi::<u8>(&header[PR_OFFSET..PR_OFFSET + 2]),
-        atoi::atoi::<u8>(header[INT_OFFSET..INT_OFFSET + 4].trim_ascii_start()),
+        atoi::atoi::<u8>(header[INT_OFFSET..INT_OFFSET + 4].trim_ascii_start()).filter(|i| *i > 0),
         atoi::atoi::<u8>(header[VV_OFFSET..VV_OFFSET + 4].trim_ascii_start()),
     ) else {
-        bail!("invalid other numbers")
+        return Err(Error::format(
+            "radar composite",
+            "invalid precision, interval or offset",
+        ));
     };
     let dt = jiff::civil::datetime(year + 2000, month, day, hour, minute, 0, 0);
     let timestamp =
         jiff::tz::Offset::constant(0).to_timestamp(dt)? + jiff::Span::new().minutes(offset_min);
-    let local_ts = timestamp.to_zoned(ZONE.clone());
 
     // the data is encoded from south to north and west to east
-    let mut buf = vec![0u16; 1200 * 1100];
+    let mut buf = vec![0u16; HEIGHT * WIDTH];
     reader.read_exact(bytemuck::cast_slice_mut::<_, [u8; 2]>(&mut buf).as_flattened_mut())?;
-    fn xy_to_idx(x: usize, y: usize) -> usize {
-        (1200 - y - 1) * 1100 + x
-    }
     // let mut img = image::RgbaImage::new(1100, 1200);
     // for y in 0..1200 {
     //     for x in 0..1100 {
@@ -120,17 +136,154 @@ fn read_rv(
     sum += 8 * buf[target_idx] as u64;
     let val = sum as f32 / 16.0;
 
-    let p_factor = match precision {
+    let value = val * precision_factor(precision) * (60 / interval) as f32;
+
+    Ok(RadarReading { timestamp, value })
+}
+
+/// The data is encoded from south to north and west to east.
+fn xy_to_idx(x: usize, y: usize) -> usize {
+    (HEIGHT - y - 1) * WIDTH + x
+}
+
+fn precision_factor(precision: u8) -> f32 {
+    match precision {
         0 => 1.0,
         1 => 0.1,
         2 => 0.01,
         x => 10.0f32.powi(-(x as i32)),
-    };
-    let value = val * p_factor * (60 / interval) as f32;
+    }
+}
 
-    Ok(RadarReading {
-        timestamp,
-        local_ts,
-        value,
-    })
+/// A composite to write with [`write_rv`] (e.g. synthetic data for tests and demos).
+#[derive(Debug, Clone)]
+pub struct Composite {
+    /// Time of the measurement (minutes are kept)
+    pub timestamp: jiff::Timestamp,
+    /// Values are in 10^-precision mm
+    pub precision: u8,
+    /// The values are the amount in this many minutes
+    pub interval: u8,
+    /// Minutes of the forecast after `timestamp`
+    pub offset: u8,
+    /// The raw values from north to south and west to east (`y * 1100 + x`)
+    pub values: Vec<u16>,
+}
+
+impl Composite {
+    /// A composite with the rain rate (in mm/h) of each km, like the ones from DWD (precision 2,
+    /// 5 minute interval).
+    pub fn from_rain_rate(
+        timestamp: jiff::Timestamp,
+        offset: u8,
+        rate: impl Fn(usize, usize) -> f32,
+    ) -> Self {
+        let precision = 2;
+        let interval = 5;
+        let scale = interval as f32 / 60.0 / precision_factor(precision);
+        let mut values = Vec::with_capacity(WIDTH * HEIGHT);
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                values.push((rate(x, y).max(0.0) * scale).round().min(u16::MAX as f32) as u16);
+            }
+        }
+        Self {
+            timestamp,
+            precision,
+            interval,
+            offset,
+            values,
+        }
+    }
+
+    /// The file name DWD uses in the bundle (`DE1200_RV<YYMMDDhhmm>_<VVV>`).
+    pub fn file_name(&self) -> String {
+        let dt = jiff::tz::Offset::UTC.to_datetime(self.timestamp);
+        format!("DE1200_RV{}_{:03}", dt.strftime("%y%m%d%H%M"), self.offset)
+    }
+}
+
+/// Writes a composite in the format read by [`read_archive`].
+pub fn write_rv(mut writer: impl Write, composite: &Composite) -> Result<()> {
+    if composite.values.len() != WIDTH * HEIGHT {
+        return Err(Error::format(
+            "radar composite",
+            format!("expected {} values", WIDTH * HEIGHT),
+        ));
+    }
+    let dt = jiff::tz::Offset::UTC.to_datetime(composite.timestamp);
+    let text = "<synthetic>";
+    let header_len = STATIC_HEADER_LEN + text.len() + 1;
+    let len = header_len + WIDTH * HEIGHT * 2;
+    let header = format!(
+        "RV{}10000{}BY{len:>8}VS 5SW  syntheticPR E-{:02}INT{:>4}GP{HEIGHT}x{WIDTH}VV {:03}MF 00000008MS{:>3}{text}",
+        dt.strftime("%d%H%M"),
+        dt.strftime("%m%y"),
+        composite.precision,
+        composite.interval,
+        composite.offset,
+        text.len(),
+    );
+    debug_assert_eq!(header.len() + 1, header_len);
+    writer.write_all(header.as_bytes())?;
+    writer.write_all(&[HEADER_END])?;
+
+    let mut data = Vec::with_capacity(WIDTH * HEIGHT * 2);
+    for y in 0..HEIGHT {
+        // the file starts in the south
+        let row = &composite.values[(HEIGHT - y - 1) * WIDTH..][..WIDTH];
+        data.extend(row.iter().flat_map(|v| v.to_le_bytes()));
+    }
+    writer.write_all(&data)?;
+    Ok(())
+}
+
+/// Writes composites as a `.tar.bz2` bundle like `DE1200_RV_LATEST.tar.bz2`.
+pub fn write_archive(writer: impl Write, composites: &[Composite]) -> Result<()> {
+    let mut ar = tar::Builder::new(bzip2::write::BzEncoder::new(
+        writer,
+        bzip2::Compression::default(),
+    ));
+    for composite in composites {
+        let mut data = Vec::new();
+        write_rv(&mut data, composite)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(composite.timestamp.as_second().max(0) as u64);
+        header.set_cksum();
+        ar.append_data(&mut header, composite.file_name(), data.as_slice())?;
+    }
+    ar.into_inner()?.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn archive_roundtrip() {
+        let start: jiff::Timestamp = "2025-03-24T12:15:00Z".parse().unwrap();
+        let composites = [10, 5, 0].map(|offset| {
+            Composite::from_rain_rate(start, offset, |x, y| {
+                if (x, y) == (500, 600) {
+                    1.2 + offset as f32 / 10.0
+                } else {
+                    0.0
+                }
+            })
+        });
+        let mut archive = Vec::new();
+        write_archive(&mut archive, &composites).unwrap();
+
+        let readings = read_archive(archive.as_slice(), (500, 600)).unwrap();
+        assert_eq!(readings.len(), 3);
+        for (reading, offset) in readings.iter().zip([0, 5, 10]) {
+            assert_eq!(reading.timestamp, start + jiff::Span::new().minutes(offset));
+            // only the target has rain, it's weighted with 8/16
+            let expected = (1.2 + offset as f32 / 10.0) / 2.0;
+            assert!((reading.value - expected).abs() < 0.07, "{reading:?}");
+        }
+    }
 }
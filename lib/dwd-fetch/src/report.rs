@@ -1,33 +1,41 @@
-use anyhow::{anyhow, bail};
-use std::{
-    collections::HashMap,
-    io::{BufRead, BufReader},
-    sync::RwLock,
+use std::{collections::HashMap, io::BufRead, sync::RwLock, time::Instant};
+
+use super::{
+    Cache, CloudLayer, Datapoint, Error, PoiStation, Result, WeatherCondition, download,
+    needs_fetch,
 };
 
-use super::{Cache, Datapoint, PoiStation, WeatherCondition, get_etag, needs_fetch};
+pub(crate) fn url(station: PoiStation) -> String {
+    format!("https://opendata.dwd.de/weather/weather_reports/poi/{station}-BEOB.csv")
+}
 
-pub fn get(station: PoiStation, cache: &RwLock<Cache>) -> anyhow::Result<bool> {
-    let url = format!("https://opendata.dwd.de/weather/weather_reports/poi/{station}-BEOB.csv");
+#[tracing::instrument(name = "report", skip(cache))]
+pub fn get(station: PoiStation, cache: &RwLock<Cache>) -> Result<bool> {
+    let url = url(station);
     if !needs_fetch(&url, cache.read().unwrap().report_etag.as_deref()) {
         return Ok(false);
     }
 
-    let mut res = ureq::get(url).call()?;
-    if !res.status().is_success() {
-        bail!("Failed to get station - got status {:?}", res.status());
-    }
-    let etag = get_etag(&res);
+    let (bytes, etag) = download(&url)?;
+    let data = parse(bytes.as_slice())?;
+    let mut cache = cache.write().unwrap();
+    cache.report_etag = etag;
+    cache.report = data;
+
+    Ok(true)
+}
 
-    let reader = BufReader::new(res.body_mut().as_reader());
+/// Parses a POI report CSV, sorted by time.
+pub fn parse(reader: impl BufRead) -> Result<Vec<Datapoint>> {
+    let start = Instant::now();
     let mut lines = reader.lines();
     let header = lines
         .next()
         .and_then(Result::ok)
-        .ok_or_else(|| anyhow!("No header"))?;
-    let columns = HashMap::from_iter(header.split(';').enumerate().map(|(k, v)| (v, k)));
+        .ok_or_else(|| Error::format("report", "no header"))?;
+    let columns = resolve_columns(&header);
     if lines.next().is_none() || lines.next().is_none() {
-        bail!("Too few lines");
+        return Err(Error::format("report", "too few lines"));
     }
 
     let mut data = lines
@@ -39,27 +47,280 @@ pub fn get(station: PoiStation, cache: &RwLock<Cache>) -> anyhow::Result<bool> {
         .collect::<Vec<Datapoint>>();
 
     data.sort_unstable_by_key(|it| it.timestamp);
-    let mut cache = cache.write().unwrap();
-    cache.report_etag = etag;
-    cache.report = data;
+    tracing::debug!(rows = data.len(), elapsed = ?start.elapsed(), "Parsed report");
+    Ok(data)
+}
 
-    Ok(true)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Column {
+    Date,
+    Time,
+    PresentWeather,
+    Temperature,
+    DewPoint,
+    GroundTemperature,
+    MinTemperature,
+    MaxTemperature,
+    GroundMinTemperature,
+    Precipitation,
+    Precipitation3h,
+    Precipitation6h,
+    Precipitation12h,
+    Precipitation24h,
+    PastWeather,
+    GlobalRadiation,
+    DiffuseRadiation,
+    CloudCover,
+    CloudBase,
+    /// Amount of the n-th cloud layer (from 0)
+    CloudLayerAmount(usize),
+    /// Height of the n-th cloud layer (from 0)
+    CloudLayerHeight(usize),
+    RelativeHumidity,
+    MeanWind,
+    WindGusts,
+    WindDir,
+    PressureMsl,
+    Visibility,
+    Sunshine,
+    SnowDepth,
+}
+
+/// Known names of the columns in the first header line.
+///
+/// Names are compared after [`normalize`], so case and spaces vs. underscores
+/// (e.g. `mean_wind_speed_during last_10_min_at_10_meters_above_ground`) don't need an alias.
+/// If DWD renames a column, add the new name here - the first matching column is used.
+const ALIASES: &[(Column, &[&str])] = &[
+    (Column::Date, &["surface_observations", "date"]),
+    (Column::Time, &["parameter_description", "time"]),
+    // POI code, see `WeatherCondition::Poi`
+    (Column::PresentWeather, &["present_weather"]),
+    // WMO code table 4561
+    (Column::PastWeather, &["past_weather_1", "past_weather"]),
+    // °C
+    (
+        Column::Temperature,
+        &[
+            "dry_bulb_temperature_at_2_meter_above_ground",
+            "dry_bulb_temperature_at_2_meters_above_ground",
+        ],
+    ),
+    (
+        Column::DewPoint,
+        &[
+            "dew_point_temperature_at_2_meter_above_ground",
+            "dew_point_temperature_at_2_meters_above_ground",
+        ],
+    ),
+    (
+        Column::GroundTemperature,
+        &["temperature_at_5_cm_above_ground"],
+    ),
+    (
+        Column::MinTemperature,
+        &[
+            "minimum_temperature_last_12_hours_2_meters_above_ground",
+            "minimum_temperature_last_12_hours_2_meter_above_ground",
+            "minimum_temperature_last_12_hours",
+        ],
+    ),
+    (
+        Column::MaxTemperature,
+        &[
+            "maximum_temperature_last_12_hours_2_meters_above_ground",
+            "maximum_temperature_last_12_hours_2_meter_above_ground",
+            "maximum_temperature_last_12_hours",
+        ],
+    ),
+    (
+        Column::GroundMinTemperature,
+        &["minimum_temperature_last_12_hours_5_cm_above_ground"],
+    ),
+    // mm
+    (
+        Column::Precipitation,
+        &[
+            "precipitation_amount_last_hour",
+            "total_precipitation_last_hour",
+        ],
+    ),
+    (
+        Column::Precipitation3h,
+        &[
+            "precipitation_amount_last_3_hours",
+            "total_precipitation_last_3_hours",
+        ],
+    ),
+    (
+        Column::Precipitation6h,
+        &[
+            "precipitation_amount_last_6_hours",
+            "total_precipitation_last_6_hours",
+        ],
+    ),
+    (
+        Column::Precipitation12h,
+        &[
+            "precipitation_amount_last_12_hours",
+            "total_precipitation_last_12_hours",
+        ],
+    ),
+    (
+        Column::Precipitation24h,
+        &[
+            "precipitation_amount_last_24_hours",
+            "total_precipitation_last_24_hours",
+        ],
+    ),
+    // W/m²
+    (
+        Column::GlobalRadiation,
+        &["global_radiation_last_hour", "global_radiation_past_hour"],
+    ),
+    (
+        Column::DiffuseRadiation,
+        &[
+            "diffuse_solar_radiation_last_hour",
+            "diffuse_radiation_last_hour",
+        ],
+    ),
+    // %
+    (
+        Column::CloudCover,
+        &["cloud_cover_total", "total_cloud_cover"],
+    ),
+    // m
+    (
+        Column::CloudBase,
+        &["height_of_base_of_lowest_cloud_above_station"],
+    ),
+    // eighths
+    (
+        Column::CloudLayerAmount(0),
+        &[
+            "cloud_amount_of_first_cloud_layer",
+            "cloud_cover_of_first_cloud_layer",
+        ],
+    ),
+    (
+        Column::CloudLayerAmount(1),
+        &[
+            "cloud_amount_of_second_cloud_layer",
+            "cloud_cover_of_second_cloud_layer",
+        ],
+    ),
+    (
+        Column::CloudLayerAmount(2),
+        &[
+            "cloud_amount_of_third_cloud_layer",
+            "cloud_cover_of_third_cloud_layer",
+        ],
+    ),
+    (
+        Column::CloudLayerAmount(3),
+        &[
+            "cloud_amount_of_fourth_cloud_layer",
+            "cloud_cover_of_fourth_cloud_layer",
+        ],
+    ),
+    // m
+    (
+        Column::CloudLayerHeight(0),
+        &[
+            "height_of_base_of_first_cloud_layer",
+            "base_of_first_cloud_layer",
+        ],
+    ),
+    (
+        Column::CloudLayerHeight(1),
+        &[
+            "height_of_base_of_second_cloud_layer",
+            "base_of_second_cloud_layer",
+        ],
+    ),
+    (
+        Column::CloudLayerHeight(2),
+        &[
+            "height_of_base_of_third_cloud_layer",
+            "base_of_third_cloud_layer",
+        ],
+    ),
+    (
+        Column::CloudLayerHeight(3),
+        &[
+            "height_of_base_of_fourth_cloud_layer",
+            "base_of_fourth_cloud_layer",
+        ],
+    ),
+    // %
+    (Column::RelativeHumidity, &["relative_humidity"]),
+    // km/h
+    (
+        Column::MeanWind,
+        &[
+            "mean_wind_speed_during_last_10_min_at_10_meters_above_ground",
+            "mean_wind_speed_during_last_10_min_at_10_meter_above_ground",
+        ],
+    ),
+    (Column::WindGusts, &["maximum_wind_speed_last_hour"]),
+    // °
+    (
+        Column::WindDir,
+        &[
+            "mean_wind_direction_during_last_10_min_at_10_meters_above_ground",
+            "mean_wind_direction_during_last_10_min_at_10_meter_above_ground",
+        ],
+    ),
+    // hPa
+    (Column::PressureMsl, &["pressure_reduced_to_mean_sea_level"]),
+    // km
+    (Column::Visibility, &["horizontal_visibility"]),
+    // min
+    (
+        Column::Sunshine,
+        &[
+            "total_time_of_sunshine_during_last_hour",
+            "total_time_of_sunshine_last_hour",
+        ],
+    ),
+    // cm
+    (Column::SnowDepth, &["total_snow_depth", "snow_depth"]),
+];
+
+fn normalize(name: &str) -> String {
+    name.trim().to_ascii_lowercase().replace(' ', "_")
+}
+
+fn resolve_columns(header: &str) -> HashMap<Column, usize> {
+    let mut columns = HashMap::new();
+    for (idx, name) in header.split(';').enumerate() {
+        let name = normalize(name);
+        match ALIASES
+            .iter()
+            .find(|(_, names)| names.contains(&name.as_str()))
+        {
+            Some((column, _)) => {
+                columns.entry(*column).or_insert(idx);
+            }
+            None => tracing::debug!(name, "Unknown report column"),
+        }
+    }
+    columns
 }
 
 impl Datapoint {
-    fn from_report(columns: &HashMap<&str, usize>, fields: &[&str]) -> anyhow::Result<Self> {
-        let get = |name: &'static str| -> Option<&str> {
+    fn from_report(columns: &HashMap<Column, usize>, fields: &[&str]) -> Result<Self> {
+        let get = |column: Column| -> Option<&str> {
             columns
-                .get(name)
+                .get(&column)
                 .and_then(|idx| fields.get(*idx).map(|it| &**it))
         };
-        let get_f32 =
-            |name: &'static str| -> Option<f32> { get(name).and_then(|v| v.parse().ok()) };
-        let get_u16 =
-            |name: &'static str| -> Option<u16> { get(name).and_then(|v| v.parse().ok()) };
+        let get_f32 = |column: Column| -> Option<f32> { get(column).and_then(|v| v.parse().ok()) };
+        let get_u16 = |column: Column| -> Option<u16> { get(column).and_then(|v| v.parse().ok()) };
         // parse the timestamp
-        let date = get("surface observations").ok_or_else(|| anyhow!("No date column"))?;
-        let time = get("Parameter description").ok_or_else(|| anyhow!("No time column"))?;
+        let date = get(Column::Date).ok_or_else(|| Error::format("report", "no date column"))?;
+        let time = get(Column::Time).ok_or_else(|| Error::format("report", "no time column"))?;
         let date = jiff::civil::Date::strptime(b"%d.%m.%y", date)?;
         let time = jiff::civil::Time::strptime("%R", time)?;
         let timestamp = date
@@ -67,31 +328,105 @@ impl Datapoint {
             .to_zoned(jiff::tz::TimeZone::UTC)?
             .timestamp();
 
-        let condition = match get_u16("present_weather") {
+        let condition = match get_u16(Column::PresentWeather) {
             Some(x) => WeatherCondition::Poi(x),
             None => WeatherCondition::None,
         };
-        let temperature = get_f32("dry_bulb_temperature_at_2_meter_above_ground");
-        let precipitation = get_f32("precipitation_amount_last_hour");
-        let cloud_cover = get_f32("cloud_cover_total");
-        let relative_humidity = get_f32("relative_humidity");
-        let mean_wind = get_f32("mean_wind_speed_during last_10_min_at_10_meters_above_ground");
-        let wind_gusts = get_f32("maximum_wind_speed_last_hour");
-        let wind_dir = get_f32("mean_wind_direction_during_last_10 min_at_10_meters_above_ground");
+
+        let cloud_layers = std::array::from_fn(|idx| CloudLayer {
+            amount: get_f32(Column::CloudLayerAmount(idx)),
+            height: get_f32(Column::CloudLayerHeight(idx)),
+        });
 
         Ok(Self {
             timestamp,
-            local_ts: timestamp.to_zoned(super::ZONE.clone()),
             condition,
-            temperature,
-            precipitation,
+            temperature: get_f32(Column::Temperature),
+            precipitation: get_f32(Column::Precipitation),
+            precipitation_3h: get_f32(Column::Precipitation3h),
+            precipitation_6h: get_f32(Column::Precipitation6h),
+            precipitation_12h: get_f32(Column::Precipitation12h),
+            precipitation_24h: get_f32(Column::Precipitation24h),
             p_precipitation: None,
-            cloud_cover,
-            relative_humidity,
-            mean_wind,
-            wind_gusts,
-            wind_dir,
+            cloud_cover: get_f32(Column::CloudCover),
+            relative_humidity: get_f32(Column::RelativeHumidity),
+            mean_wind: get_f32(Column::MeanWind),
+            wind_gusts: get_f32(Column::WindGusts),
+            wind_dir: get_f32(Column::WindDir),
+            dew_point: get_f32(Column::DewPoint),
+            ground_temperature: get_f32(Column::GroundTemperature),
+            min_temperature: get_f32(Column::MinTemperature),
+            max_temperature: get_f32(Column::MaxTemperature),
+            ground_min_temperature: get_f32(Column::GroundMinTemperature),
+            pressure: None,
+            pressure_msl: get_f32(Column::PressureMsl),
+            pressure_tendency: None,
+            visibility: get_f32(Column::Visibility),
+            cloud_base: get_f32(Column::CloudBase),
+            cloud_layers,
+            past_weather: get_u16(Column::PastWeather),
+            global_radiation: get_f32(Column::GlobalRadiation),
+            diffuse_radiation: get_f32(Column::DiffuseRadiation),
+            sunshine: get_f32(Column::Sunshine),
+            snow_depth: get_f32(Column::SnowDepth),
             is_report: true,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renamed_columns() {
+        let csv = "surface observations;Parameter description;Dry_bulb_temperature_at_2_meters_above_ground;mean_wind_speed_during last_10_min_at_10_meters_above_ground;horizontal_visibility;present_weather
+Datum;Uhrzeit (UTC);TT;FF;VV;ww
+;;Grad C;km/h;km;CODE_TABLE
+17.10.26;12:00;12,3;14,0;---;1
+17.10.26;11:00;11,8;---;25,0;---
+";
+        let data = parse(csv.as_bytes()).unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0].temperature, Some(11.8));
+        assert_eq!(data[0].visibility, Some(25.0));
+        assert_eq!(data[0].condition, WeatherCondition::None);
+        assert_eq!(data[1].mean_wind, Some(14.0));
+        assert_eq!(data[1].visibility, None);
+        assert_eq!(data[1].condition, WeatherCondition::Poi(1));
+
+        // columns for longer periods and cloud layers
+        let csv = "surface observations;Parameter description;precipitation_amount_last_3_hours;Precipitation amount last 6 hours;precipitation_amount_last_12_hours;precipitation_amount_last_24_hours;global_radiation_last_hour;diffuse_solar_radiation_last_hour;past_weather_1;cloud_amount_of_first_cloud_layer;height_of_base_of_first_cloud_layer;cloud_amount_of_second_cloud_layer;height_of_base_of_second_cloud_layer;maximum_temperature_last_12_hours_2_meters_above_ground;minimum_temperature_last_12_hours_2_meters_above_ground
+Datum;Uhrzeit (UTC);RR3;RR6;RR12;RR24;GS;DS;W1;C1;H1;C2;H2;TX12;TN12
+;;mm;mm;mm;mm;W/m2;W/m2;CODE_TABLE;1/8;m;1/8;m;Grad C;Grad C
+17.10.26;18:00;0,4;1,2;3,0;5,5;12,0;8,5;6;3;600;7;1500;14,2;6,1
+";
+        let data = parse(csv.as_bytes()).unwrap();
+        let [point] = data.as_slice() else {
+            panic!("expected one row");
+        };
+        assert_eq!(point.precipitation_3h, Some(0.4));
+        assert_eq!(point.precipitation_6h, Some(1.2));
+        assert_eq!(point.precipitation_12h, Some(3.0));
+        assert_eq!(point.precipitation_24h, Some(5.5));
+        assert_eq!(point.global_radiation, Some(12.0));
+        assert_eq!(point.diffuse_radiation, Some(8.5));
+        assert_eq!(point.past_weather, Some(6));
+        assert_eq!(
+            point.cloud_layers[..2],
+            [
+                CloudLayer {
+                    amount: Some(3.0),
+                    height: Some(600.0),
+                },
+                CloudLayer {
+                    amount: Some(7.0),
+                    height: Some(1500.0),
+                },
+            ]
+        );
+        assert_eq!(point.cloud_layers[2], CloudLayer::default());
+        assert_eq!(point.max_temperature, Some(14.2));
+        assert_eq!(point.min_temperature, Some(6.1));
+    }
+}
@@ -0,0 +1,157 @@
+//! Position of the sun and the times of sunrise, sunset and twilight.
+//!
+//! Uses the low precision formulas from the Astronomical Almanac, the times are accurate to about
+//! a minute.
+
+use jiff::{Timestamp, civil::Date};
+
+use crate::Coordinates;
+
+/// Elevation (in °) of the center of the sun at sunrise and sunset, includes refraction and the
+/// radius of the sun.
+pub const HORIZON: f64 = -0.833;
+/// Elevation (in °) of the sun at the start of civil dawn and the end of civil dusk.
+pub const CIVIL_TWILIGHT: f64 = -6.0;
+
+/// Julian day of 2000-01-01 12:00 UTC
+const J2000: f64 = 2451545.0;
+/// Julian day of the unix epoch
+const UNIX_EPOCH: f64 = 2440587.5;
+
+pub(crate) fn julian_day(ts: Timestamp) -> f64 {
+    ts.as_millisecond() as f64 / 86_400_000.0 + UNIX_EPOCH
+}
+
+fn from_julian_day(jd: f64) -> Option<Timestamp> {
+    Timestamp::from_millisecond(((jd - UNIX_EPOCH) * 86_400_000.0).round() as i64).ok()
+}
+
+/// Elevation of the center of the sun above the horizon in °.
+pub fn elevation(ts: Timestamp, at: Coordinates) -> f64 {
+    let n = julian_day(ts) - J2000;
+
+    let mean_longitude = (280.460 + 0.9856474 * n).to_radians();
+    let mean_anomaly = (357.528 + 0.9856003 * n).to_radians();
+    let ecliptic_longitude = mean_longitude
+        + 1.915f64.to_radians() * mean_anomaly.sin()
+        + 0.020f64.to_radians() * (2.0 * mean_anomaly).sin();
+    let obliquity = (23.439 - 0.0000004 * n).to_radians();
+
+    let right_ascension =
+        (obliquity.cos() * ecliptic_longitude.sin()).atan2(ecliptic_longitude.cos());
+    let declination = (obliquity.sin() * ecliptic_longitude.sin()).asin();
+    let sidereal_time = (280.46061837 + 360.98564736629 * n).to_radians();
+    let hour_angle = sidereal_time + at.longitude.to_radians() - right_ascension;
+
+    let lat = at.latitude.to_radians();
+    (lat.sin() * declination.sin() + lat.cos() * declination.cos() * hour_angle.cos())
+        .asin()
+        .to_degrees()
+}
+
+/// Whether the sun is below the horizon.
+pub fn is_night(ts: Timestamp, at: Coordinates) -> bool {
+    elevation(ts, at) < HORIZON
+}
+
+/// The sun events of a day.
+///
+/// Events are `None` if the sun doesn't cross the elevation on that day (polar day and night).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SunTimes {
+    /// Start of civil twilight
+    pub dawn: Option<Timestamp>,
+    pub sunrise: Option<Timestamp>,
+    /// Highest elevation of the sun
+    pub noon: Option<Timestamp>,
+    pub sunset: Option<Timestamp>,
+    /// End of civil twilight
+    pub dusk: Option<Timestamp>,
+}
+
+impl SunTimes {
+    /// The events of the solar day closest to `date` (12:00 UTC).
+    pub fn on(date: Date, at: Coordinates) -> Self {
+        let days = date
+            .since(jiff::civil::date(2000, 1, 1))
+            .map_or(0, |s| s.get_days());
+        // mean solar noon
+        let n = days as f64 - at.longitude / 360.0;
+
+        let mean_anomaly = (357.5291 + 0.98560028 * n).rem_euclid(360.0);
+        let m = mean_anomaly.to_radians();
+        let center = 1.9148 * m.sin() + 0.0200 * (2.0 * m).sin() + 0.0003 * (3.0 * m).sin();
+        let ecliptic_longitude = (mean_anomaly + center + 180.0 + 102.9372)
+            .rem_euclid(360.0)
+            .to_radians();
+        let transit = J2000 + n + 0.0053 * m.sin() - 0.0069 * (2.0 * ecliptic_longitude).sin();
+        let declination = (ecliptic_longitude.sin() * 23.4397f64.to_radians().sin()).asin();
+
+        let lat = at.latitude.to_radians();
+        let crossing = |elevation: f64| {
+            let cos_hour_angle = (elevation.to_radians().sin() - lat.sin() * declination.sin())
+                / (lat.cos() * declination.cos());
+            if !(-1.0..=1.0).contains(&cos_hour_angle) {
+                return (None, None);
+            }
+            let offset = cos_hour_angle.acos().to_degrees() / 360.0;
+            (
+                from_julian_day(transit - offset),
+                from_julian_day(transit + offset),
+            )
+        };
+        let (dawn, dusk) = crossing(CIVIL_TWILIGHT);
+        let (sunrise, sunset) = crossing(HORIZON);
+
+        Self {
+            dawn,
+            sunrise,
+            noon: from_julian_day(transit),
+            sunset,
+            dusk,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BERLIN: Coordinates = Coordinates {
+        latitude: 52.52,
+        longitude: 13.405,
+    };
+
+    #[test]
+    fn berlin_midsummer() {
+        let times = SunTimes::on(jiff::civil::date(2025, 6, 21), BERLIN);
+        let near = |ts: Option<Timestamp>, expected: &str| {
+            let expected: Timestamp = expected.parse().unwrap();
+            ts.unwrap().duration_since(expected).abs() < jiff::SignedDuration::from_mins(2)
+        };
+        assert!(near(times.sunrise, "2025-06-21T02:43:00Z"));
+        assert!(near(times.sunset, "2025-06-21T19:33:00Z"));
+        assert!(near(times.dawn, "2025-06-21T01:52:00Z"));
+        assert!(near(times.dusk, "2025-06-21T20:25:00Z"));
+        assert!(near(times.noon, "2025-06-21T11:08:00Z"));
+
+        let noon = times.noon.unwrap();
+        assert!((elevation(noon, BERLIN) - 60.9).abs() < 0.2);
+        assert!((elevation(times.dusk.unwrap(), BERLIN) - CIVIL_TWILIGHT).abs() < 0.3);
+        assert!(is_night("2025-06-21T01:00:00Z".parse().unwrap(), BERLIN));
+        assert!(!is_night("2025-06-21T19:00:00Z".parse().unwrap(), BERLIN));
+    }
+
+    #[test]
+    fn twilight_without_sunset() {
+        // the sun sets, but it doesn't get darker than civil twilight
+        let trondheim = Coordinates {
+            latitude: 63.43,
+            longitude: 10.39,
+        };
+        let times = SunTimes::on(jiff::civil::date(2025, 6, 21), trondheim);
+        assert!(times.sunrise.is_some() && times.sunset.is_some());
+        assert_eq!((times.dawn, times.dusk), (None, None));
+        assert!(times.noon.is_some());
+    }
+}
@@ -1,55 +1,72 @@
 use std::{
     collections::{HashMap, hash_map::Entry},
     io::Read,
+    ops::ControlFlow,
     sync::{LazyLock, RwLock},
+    time::Instant,
 };
 
-use anyhow::bail;
-use dwd_bufr_tables::{DWD_BUFR_TABLE_B, DWD_BUFR_TABLE_D};
-use dwd_gts::GtsHeader;
+use dwd_bufr::{Element, Match, Message, Query, Subset, VersionedTables};
+use dwd_gts::{Bbb, BulletinReader, GtsHeader};
 use regex::Regex;
-use tinybufr::{DataEvent, DataReader, DataSpec, HeaderSections, Tables, Value, XY};
+use tinybufr::XY;
 
-use crate::{Cache, Datapoint, WeatherCondition, get_etag, needs_fetch};
+use crate::{
+    Cache, Datapoint, Error, Result, StationObservation, WeatherCondition, condition::Condition,
+    download, needs_fetch,
+};
 
-const URL: &str = "https://opendata.dwd.de/weather/weather_reports/synoptic/germany/Z__C_EDZW_latest_bda01%2Csynop_bufr_GER_999999_999999__MW_XXX.bin";
-const LISTING_URL: &str = "https://opendata.dwd.de/weather/weather_reports/synoptic/germany";
+pub(crate) const URL: &str = "https://opendata.dwd.de/weather/weather_reports/synoptic/germany/Z__C_EDZW_latest_bda01%2Csynop_bufr_GER_999999_999999__MW_XXX.bin";
+pub(crate) const LISTING_URL: &str =
+    "https://opendata.dwd.de/weather/weather_reports/synoptic/germany";
 
 static HREF_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#"href\s*=\s*"(Z[^"]+)""#).unwrap());
 
-pub fn get(cache: &RwLock<Cache>, stations: &[String]) -> anyhow::Result<bool> {
+#[tracing::instrument(name = "synop", skip(cache))]
+pub fn get(cache: &RwLock<Cache>, stations: &[String]) -> Result<bool> {
     if !needs_fetch(URL, cache.read().unwrap().synop_etag.as_deref()) {
         return Ok(false);
     }
 
-    let mut res = ureq::get(URL).call()?;
-    if !res.status().is_success() {
-        bail!("Failed to get synop BUFR - got status {:?}", res.status());
+    let (bytes, etag) = download(URL)?;
+    let (mut observations, _) = read_file_to_observations(bytes.as_slice(), stations)?;
+    let is_old = observations.is_empty() && last_observation_is_old(cache);
+    if is_old {
+        tracing::info!("No station in the latest report, trying older ones");
+        observations = try_old_reports(stations);
     }
-    let etag = get_etag(&res);
 
-    let mut datapoint = read_file_to_point(res.body_mut().as_reader(), stations)?;
-    if datapoint.is_none() && last_observation_is_old(cache) {
-        datapoint = try_old_reports(stations);
-    }
+    store(cache, observations, etag, is_old, stations)
+}
 
+pub(crate) fn store(
+    cache: &RwLock<Cache>,
+    observations: Vec<StationObservation>,
+    etag: Option<String>,
+    is_old: bool,
+    stations: &[String],
+) -> Result<bool> {
     let mut cache = cache.write().unwrap();
-    if let Some(point) = datapoint {
-        cache.observation = Some(point);
-    }
     cache.synop_etag = etag;
+    if !observations.is_empty() {
+        cache.observations = observations;
+    } else if is_old {
+        // the latest file might just miss the stations - only fail without a recent observation
+        return Err(Error::StationNotFound(stations.join(", ")));
+    }
 
     Ok(true)
 }
 
-fn last_observation_is_old(cache: &RwLock<Cache>) -> bool {
+pub(crate) fn last_observation_is_old(cache: &RwLock<Cache>) -> bool {
     let Some(ts) = cache
         .read()
         .unwrap()
-        .observation
-        .as_ref()
-        .map(|o| o.timestamp)
+        .observations
+        .iter()
+        .map(|o| o.point.timestamp)
+        .max()
     else {
         return true; // no past observation
     };
@@ -60,81 +77,94 @@ fn last_observation_is_old(cache: &RwLock<Cache>) -> bool {
     !is_new
 }
 
-fn try_old_reports(stations: &[String]) -> Option<Datapoint> {
-    let mut res = ureq::get(LISTING_URL).call().ok()?;
-    if !res.status().is_success() {
-        return None;
-    }
-    let body = res.body_mut().read_to_string().ok()?;
-    for file in body.lines().rev().filter_map(|l| {
-        HREF_REGEX
-            .captures(l)
-            .and_then(|c| c.get(1).map(|m| m.as_str()))
-    }) {
-        let url = format!("{LISTING_URL}/{file}");
-        let Ok(mut res) = ureq::get(url)
-            .call()
-            .inspect_err(|e| eprintln!("Failed to fetch old report: {e}"))
+/// Older reports are only searched back this far.
+const MAX_OLD_REPORT_AGE: jiff::SignedDuration = jiff::SignedDuration::from_hours(6);
+
+/// Whether a report whose newest bulletin is from `newest` is too old to be used as a fallback.
+pub(crate) fn report_is_too_old(newest: Option<jiff::Timestamp>) -> bool {
+    newest.is_none_or(|ts| jiff::Timestamp::now().duration_since(ts) > MAX_OLD_REPORT_AGE)
+}
+
+fn try_old_reports(stations: &[String]) -> Vec<StationObservation> {
+    // unsuccessful statuses are errors in ureq
+    let Ok(mut res) = ureq::get(LISTING_URL).call() else {
+        return Vec::new();
+    };
+    let Ok(body) = res.body_mut().read_to_string() else {
+        return Vec::new();
+    };
+    for url in old_report_urls(&body) {
+        let Ok((bytes, _)) =
+            download(&url).inspect_err(|e| tracing::warn!(%e, "Failed to fetch old report"))
         else {
             continue;
         };
-        if !res.status().is_success() {
-            eprintln!(
-                "Fetching old report returned non-OK status - {}",
-                res.status()
-            );
-            continue;
+
+        if let ControlFlow::Break(observations) =
+            check_old_report(read_file_to_observations(bytes.as_slice(), stations))
+        {
+            return observations;
         }
+    }
 
-        if let Ok(Some(datapoint)) = read_file_to_point(res.body_mut().as_reader(), stations) {
-            return Some(datapoint);
-        };
+    Vec::new()
+}
+
+/// Whether the search through older reports stops at this one, and with which observations.
+///
+/// It stops at the first report with any of the stations or once the reports get too old.
+pub(crate) fn check_old_report(
+    res: Result<(Vec<StationObservation>, Option<jiff::Timestamp>)>,
+) -> ControlFlow<Vec<StationObservation>> {
+    match res {
+        Ok((observations, _)) if !observations.is_empty() => ControlFlow::Break(observations),
+        Ok((_, newest)) if report_is_too_old(newest) => ControlFlow::Break(Vec::new()),
+        _ => ControlFlow::Continue(()),
     }
+}
 
-    None
+/// URLs of the reports in the directory listing, newest first.
+pub(crate) fn old_report_urls(listing: &str) -> impl Iterator<Item = String> {
+    listing
+        .lines()
+        .rev()
+        .filter_map(|l| {
+            HREF_REGEX
+                .captures(l)
+                .and_then(|c| c.get(1).map(|m| m.as_str()))
+        })
+        .map(|file| format!("{LISTING_URL}/{file}"))
 }
 
-fn read_file_to_point(mut r: impl Read, stations: &[String]) -> anyhow::Result<Option<Datapoint>> {
-    let tables = make_tables();
+/// Reads the latest observation of each of the `stations` (in the same order) from a GTS file.
+///
+/// Stations that aren't part of the file are skipped.
+/// Also returns the time of the newest bulletin in the file.
+pub(crate) fn read_file_to_observations(
+    r: impl Read,
+    stations: &[String],
+) -> Result<(Vec<StationObservation>, Option<jiff::Timestamp>)> {
+    let start = Instant::now();
+    let now = jiff::Timestamp::now();
+    let tables = VersionedTables::new();
     let mut points = vec![None; stations.len()];
 
     let mut processed = HashMap::new();
+    let mut newest = None;
 
-    loop {
-        // 8 bytes ASCII message length
-        let mut gts_bytes = [0u8; 8];
-        r.read_exact(&mut gts_bytes)?;
-        let Some(length) = atoi::atoi::<u64>(&gts_bytes).filter(|x| *x > 0) else {
-            break;
-        };
-        // skip two '0'
-        r.read_exact(&mut gts_bytes[..2])?;
-
-        let mut bounded = r.by_ref().take(length);
-        let mut gts_header_bytes = [0; 35];
-        bounded.read_exact(&mut gts_header_bytes[..31])?;
-        if &gts_header_bytes[28..31] != b"\r\r\n" {
-            bounded.read_exact(&mut gts_header_bytes[31..])?;
-            if &gts_header_bytes[32..] != b"\r\r\n" {
-                bail!("invalid GTC header end");
-            }
-        }
-        let gts_header = GtsHeader::read(&gts_header_bytes)?;
-        if length == 31 + 7 {
-            let mut nil_end = [0; 7];
-            bounded.read_exact(&mut nil_end)?;
-            if &nil_end != b"NIL\r\r\n\x03" {
-                bail!("Invalid nil message");
-            }
+    let mut bulletins = BulletinReader::new(r);
+    while let Some(bulletin) = bulletins.next_bulletin() {
+        let bulletin = bulletin?;
+        let Some(timestamp) = bulletin.header.timestamp(now) else {
+            tracing::debug!(header = ?bulletin.header, "Invalid bulletin time");
             continue;
-        }
-
-        let my_entry = VisitedGtsMessage::new(&gts_header);
-        match processed.entry((gts_header.product_id, gts_header.source)) {
+        };
+        newest = newest.max(Some(timestamp));
+        let my_entry = VisitedGtsMessage::new(&bulletin.header, timestamp);
+        match processed.entry((bulletin.header.product_id, bulletin.header.source)) {
             Entry::Occupied(mut occupied) => {
                 if *occupied.get() >= my_entry {
                     // already seen, skip
-                    std::io::copy(&mut bounded, &mut std::io::sink())?;
                     continue;
                 }
                 *occupied.get_mut() = my_entry;
@@ -144,42 +174,34 @@ fn read_file_to_point(mut r: impl Read, stations: &[String]) -> anyhow::Result<O
             }
         }
 
-        let mut bounded = raw_bufr(bounded, &tables, &mut points, stations)?;
-        let mut footer = [0; 8];
-        bounded.read_exact(&mut footer)?;
-        if &footer != b"7777\r\r\n\x03" {
-            bail!("invalid BUFR end or GTS end");
+        let mut bulletin = raw_bufr(bulletin, &tables, &mut points, stations)?;
+        let mut end = [0; 4];
+        bulletin.read_exact(&mut end)?;
+        if &end != b"7777" {
+            return Err(Error::format("BUFR", "invalid end"));
         }
     }
 
-    let merged = points
-        .into_iter()
-        .flatten()
-        .fold(None, |r: Option<Datapoint>, v| match r {
-            Some(mut d) => {
-                d.merge_from(&v);
-                Some(d)
-            }
-            None => Some(v),
-        });
-
-    Ok(merged)
-}
-
-fn make_tables() -> Tables {
-    let mut tables = Tables::default();
-    for it in &DWD_BUFR_TABLE_B {
-        tables.table_b.insert(it.xy, it);
-    }
-    for it in &DWD_BUFR_TABLE_D {
-        tables.table_d.insert(it.xy, it);
-    }
-    tables
+    tracing::debug!(
+        found = points.iter().filter(|p| p.is_some()).count(),
+        elapsed = ?start.elapsed(),
+        "Parsed synop"
+    );
+    Ok((points.into_iter().flatten().collect(), newest))
 }
 
 const WIGOS_LOCAL_ID: XY = XY { x: 1, y: 128 };
-const DATE_SEQ: XY = XY { x: 1, y: 11 };
-const TIME_SEQ: XY = XY { x: 1, y: 12 };
+const STATION_NAME: XY = XY { x: 1, y: 15 };
+const LATITUDE: XY = XY { x: 5, y: 1 };
+const LATITUDE_COARSE: XY = XY { x: 5, y: 2 };
+const LONGITUDE: XY = XY { x: 6, y: 1 };
+const LONGITUDE_COARSE: XY = XY { x: 6, y: 2 };
+const STATION_ELEVATION: XY = XY { x: 7, y: 30 };
+const YEAR: XY = XY { x: 4, y: 1 };
+const MONTH: XY = XY { x: 4, y: 2 };
+const DAY: XY = XY { x: 4, y: 3 };
+const HOUR: XY = XY { x: 4, y: 4 };
+const MINUTE: XY = XY { x: 4, y: 5 };
 const TEMPERATURE: XY = XY { x: 12, y: 101 };
 const CLOUD_COVER: XY = XY { x: 20, y: 10 };
 const RELATIVE_HUMIDITY_A: XY = XY { x: 13, y: 3 };
@@ -191,34 +213,187 @@ const TIME_PERIOD_OR_DISPLACEMENT: XY = XY { x: 4, y: 25 };
 const WIND_SPEED: XY = XY { x: 11, y: 2 };
 const WIND_DIRECTION: XY = XY { x: 11, y: 1 };
 const MAX_WIND_GUST_SPEED: XY = XY { x: 11, y: 41 };
+const DEW_POINT: XY = XY { x: 12, y: 103 };
+const GROUND_MIN_TEMPERATURE: XY = XY { x: 12, y: 113 };
+/// DWD local descriptor for the same element
+const GROUND_MIN_TEMPERATURE_DWD: XY = XY { x: 12, y: 204 };
+const PRESSURE: XY = XY { x: 10, y: 4 };
+const PRESSURE_MSL: XY = XY { x: 10, y: 51 };
+const PRESSURE_CHANGE_3H: XY = XY { x: 10, y: 61 };
+const VISIBILITY: XY = XY { x: 20, y: 1 };
+const CLOUD_BASE: XY = XY { x: 20, y: 13 };
+const TIME_PERIOD_HOURS: XY = XY { x: 4, y: 24 };
+const TOTAL_SUNSHINE: XY = XY { x: 14, y: 31 };
+const TOTAL_SNOW_DEPTH: XY = XY { x: 13, y: 13 };
 
 const DESIRED_HEIGHT: f32 = 2.0;
 
+/// A value of a [`Datapoint`] read from a subset.
+struct Field {
+    /// The first query with a (converted) value is used
+    queries: &'static [Query],
+    target: fn(&mut Datapoint) -> &mut Option<f32>,
+    convert: fn(&Match) -> Option<f32>,
+}
+
+/// Fields measured at several sensor heights, the value closest to [`DESIRED_HEIGHT`] is used.
+const HEIGHT_FIELDS: &[Field] = &[
+    Field {
+        queries: &[
+            Query::new(TEMPERATURE).under_any(SENSOR_HEIGHT_ABOVE_GROUND),
+            Query::new(TEMPERATURE),
+        ],
+        target: |p| &mut p.temperature,
+        convert: kelvin_to_celsius,
+    },
+    Field {
+        queries: &[
+            Query::new(DEW_POINT).under_any(SENSOR_HEIGHT_ABOVE_GROUND),
+            Query::new(DEW_POINT),
+        ],
+        target: |p| &mut p.dew_point,
+        convert: kelvin_to_celsius,
+    },
+];
+
+const FIELDS: &[Field] = &[
+    Field {
+        queries: &[
+            Query::new(GROUND_MIN_TEMPERATURE),
+            Query::new(GROUND_MIN_TEMPERATURE_DWD),
+        ],
+        target: |p| &mut p.ground_min_temperature,
+        convert: kelvin_to_celsius,
+    },
+    Field {
+        queries: &[Query::new(PRESSURE)],
+        target: |p| &mut p.pressure,
+        convert: pa_to_hpa,
+    },
+    Field {
+        queries: &[Query::new(PRESSURE_MSL)],
+        target: |p| &mut p.pressure_msl,
+        convert: pa_to_hpa,
+    },
+    Field {
+        queries: &[Query::new(PRESSURE_CHANGE_3H)],
+        target: |p| &mut p.pressure_tendency,
+        convert: pa_to_hpa,
+    },
+    Field {
+        queries: &[Query::new(VISIBILITY)],
+        target: |p| &mut p.visibility,
+        convert: |m| Some(m.value()? / 1000.0),
+    },
+    // the first one is the lowest cloud, the replicated layers follow
+    Field {
+        queries: &[Query::new(CLOUD_BASE)],
+        target: |p| &mut p.cloud_base,
+        convert: value,
+    },
+    // reported for multiple periods - we only want the last hour
+    Field {
+        queries: &[Query::new(TOTAL_SUNSHINE).under(TIME_PERIOD_HOURS, -1.0)],
+        target: |p| &mut p.sunshine,
+        convert: value,
+    },
+    // negative values encode "patchy" or "not measurable" snow cover
+    Field {
+        queries: &[Query::new(TOTAL_SNOW_DEPTH)],
+        target: |p| &mut p.snow_depth,
+        convert: |m| Some(m.value()?.max(0.0) * 100.0),
+    },
+    Field {
+        queries: &[Query::new(TOTAL_PRECIPITATION).under_any(TIME_PERIOD_OR_DISPLACEMENT)],
+        target: |p| &mut p.precipitation,
+        convert: |m| {
+            if m.replicated {
+                return None;
+            }
+            let period = m.context(TIME_PERIOD_OR_DISPLACEMENT)?.as_i32()?.abs();
+            // value is the amount in the last time period -> estimate amount in 1h
+            (period > 0).then(|| m.value().map(|v| v * 60.0 / period as f32))?
+        },
+    },
+    Field {
+        queries: &[Query::new(CLOUD_COVER)],
+        target: |p| &mut p.cloud_cover,
+        convert: value,
+    },
+    Field {
+        queries: &[
+            Query::new(RELATIVE_HUMIDITY_A),
+            Query::new(RELATIVE_HUMIDITY_B),
+        ],
+        target: |p| &mut p.relative_humidity,
+        convert: value,
+    },
+    Field {
+        queries: &[Query::new(WIND_SPEED)],
+        target: |p| &mut p.mean_wind,
+        convert: ms_to_kmh,
+    },
+    Field {
+        queries: &[Query::new(WIND_DIRECTION)],
+        target: |p| &mut p.wind_dir,
+        convert: value,
+    },
+    Field {
+        queries: &[Query::new(MAX_WIND_GUST_SPEED)],
+        target: |p| &mut p.wind_gusts,
+        convert: ms_to_kmh,
+    },
+];
+
+/// The converted value of the match closest to [`DESIRED_HEIGHT`], the first one on ties.
+/// Matches without a sensor height count as ground level.
+fn closest_to_desired_height(matches: &[Match], convert: fn(&Match) -> Option<f32>) -> Option<f32> {
+    matches
+        .iter()
+        .filter_map(|m| {
+            let height = m
+                .context(SENSOR_HEIGHT_ABOVE_GROUND)
+                .and_then(Element::as_f32)
+                .unwrap_or(0.0);
+            Some(((height - DESIRED_HEIGHT).abs(), convert(m)?))
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, value)| value)
+}
+
+fn value(m: &Match) -> Option<f32> {
+    m.value()
+}
+
+fn kelvin_to_celsius(m: &Match) -> Option<f32> {
+    Some(m.value()? - 273.15)
+}
+
+fn ms_to_kmh(m: &Match) -> Option<f32> {
+    Some(m.value()? * 3.6)
+}
+
+fn pa_to_hpa(m: &Match) -> Option<f32> {
+    Some(m.value()? / 100.0)
+}
+
 pub fn raw_bufr<R: Read>(
-    mut bounded: R,
-    tables: &Tables,
-    points: &mut [Option<Datapoint>],
+    bounded: R,
+    tables: &VersionedTables,
+    points: &mut [Option<StationObservation>],
     stations: &[String],
-) -> anyhow::Result<R> {
-    let header = HeaderSections::read(&mut bounded).unwrap();
-    let data_spec =
-        DataSpec::from_data_description(&header.data_description_section, tables).unwrap();
-    let mut data_reader = DataReader::new(bounded, &data_spec).unwrap();
-
-    loop {
-        let DataEvent::Data {
-            value: Value::String(ident),
-            ..
-        } = forward_until_data(&mut data_reader, WIGOS_LOCAL_ID)?
-        else {
-            break;
-        };
-        let trimmed = ident.trim_ascii_end();
+) -> Result<R> {
+    let (message, bounded) = Message::read(bounded, tables)?;
 
-        let Some(idx) = stations.iter().position(|it| it == trimmed) else {
+    for subset in &message.subsets {
+        let Some(ident) = subset.get(WIGOS_LOCAL_ID).and_then(Element::as_str) else {
+            continue;
+        };
+        let Some(idx) = stations.iter().position(|it| it == ident) else {
             continue;
         };
-        if let Some(mut p) = read_datapoint(&mut data_reader)?
+        tracing::debug!(station = ident, "Found station");
+        if let Some(mut p) = read_observation(subset, ident)
             && let Some(existing) = points.get_mut(idx)
         {
             if let Some(existing) = existing.as_mut() {
@@ -229,198 +404,217 @@ pub fn raw_bufr<R: Read>(
             }
         }
     }
-    Ok(data_reader.into_inner())
+    Ok(bounded)
 }
 
-fn read_datapoint(r: &mut DataReader<impl Read>) -> anyhow::Result<Option<Datapoint>> {
-    if !forward_until_seq(r, DATE_SEQ)? {
-        return Ok(None);
-    }
-    let (Some(y), Some(m), Some(d)) = (read_int(r)?, read_int(r)?, read_int(r)?) else {
-        return Ok(None);
-    };
-    forward_until_seq(r, TIME_SEQ)?;
-    let (Some(h), Some(min)) = (read_int(r)?, read_int(r)?) else {
-        return Ok(None);
+/// Reads the observation of one subset, subsets without a (valid) time are skipped.
+fn read_observation(subset: &Subset, id: &str) -> Option<StationObservation> {
+    let int = |xy| subset.get(xy).and_then(Element::as_i32);
+    let float = |a, b| {
+        subset
+            .get(a)
+            .or_else(|| subset.get(b))
+            .and_then(Element::as_f32)
     };
-    let timestamp = jiff::civil::Date::new(y as i16, m as i8, d as i8)?
-        .to_datetime(jiff::civil::Time::new(h as i8, min as i8, 0, 0)?)
-        .to_zoned(jiff::tz::TimeZone::UTC)?
-        .timestamp();
-
-    let mut point = Datapoint::from_timestamp(timestamp, true);
-
-    let mut last_height_above_ground = None;
-    let mut last_time_period = None;
-    let mut repeat_level = 0;
-    let mut height_of_temp = None;
-
-    loop {
-        match r.read_event() {
-            Ok(DataEvent::SubsetEnd) => break,
-            Ok(DataEvent::ReplicationStart { .. }) => {
-                repeat_level += 1;
-            }
-            Ok(DataEvent::ReplicationEnd) => repeat_level -= 1,
-            Ok(DataEvent::Data { xy, value, .. }) => match xy {
-                SENSOR_HEIGHT_ABOVE_GROUND => {
-                    if let Some(f) = value_to_float(&value) {
-                        last_height_above_ground = Some(f);
-                    }
-                }
-                TIME_PERIOD_OR_DISPLACEMENT => {
-                    if let Value::Integer(i) = value {
-                        last_time_period = Some(i.abs());
-                    }
-                }
-                TEMPERATURE => {
-                    let Some(value) = value_to_float(&value) else {
-                        continue;
-                    };
-                    let value = value - 273.15;
-                    let cur_height = last_height_above_ground.unwrap_or(0.0);
-                    match height_of_temp {
-                        None => {
-                            point.temperature = Some(value);
-                            height_of_temp = Some(cur_height);
-                        }
-                        Some(last)
-                            if (last - DESIRED_HEIGHT).abs()
-                                > (cur_height - DESIRED_HEIGHT).abs() =>
-                        {
-                            point.temperature = Some(value);
-                            height_of_temp = Some(cur_height);
-                        }
-                        _ => (),
-                    };
-                }
-                TOTAL_PRECIPITATION if repeat_level <= 0 => {
-                    let (Some(value), Some(time_period)) =
-                        (value_to_float(&value), last_time_period)
-                    else {
-                        continue;
-                    };
-                    // value is the amount in the last time period -> estimate amount in 1h
-                    let value = value * 60.0 / time_period as f32;
-                    point.precipitation = Some(value)
-                }
-                CLOUD_COVER => {
-                    if let Some(value) = value_to_float(&value) {
-                        point.cloud_cover = Some(value);
-                    }
-                }
-                SIGNIFICANT_WEATHER => {
-                    if let Value::Integer(i) = value {
-                        point.condition = WeatherCondition::Synop(i as u16);
-                    }
-                }
-                RELATIVE_HUMIDITY_A | RELATIVE_HUMIDITY_B => {
-                    if let Some(value) = value_to_float(&value) {
-                        point.relative_humidity = Some(value);
-                    }
-                }
-                WIND_SPEED => {
-                    if let Some(value) = value_to_float(&value) {
-                        point.mean_wind = Some(value);
-                    }
-                }
-                WIND_DIRECTION => {
-                    if let Some(value) = value_to_float(&value) {
-                        point.wind_dir = Some(value);
-                    }
-                }
-                MAX_WIND_GUST_SPEED => {
-                    if let Some(value) = value_to_float(&value) {
-                        point.wind_gusts = Some(value);
-                    }
-                }
-                _ => (),
-            },
-            Ok(_) => (),
-            Err(_) => todo!(),
-        }
-    }
 
-    Ok(Some(point))
-}
+    let (y, m, d, h, min) = (int(YEAR)?, int(MONTH)?, int(DAY)?, int(HOUR)?, int(MINUTE)?);
+    let timestamp =
+        jiff::civil::DateTime::new(y as i16, m as i8, d as i8, h as i8, min as i8, 0, 0)
+            .and_then(|dt| dt.to_zoned(jiff::tz::TimeZone::UTC))
+            .inspect_err(|e| tracing::warn!(station = id, %e, "Invalid observation time"))
+            .ok()?
+            .timestamp();
 
-fn forward_until_data(r: &mut DataReader<impl Read>, target: XY) -> anyhow::Result<DataEvent> {
-    loop {
-        match r.read_event() {
-            Ok(e @ DataEvent::Data { xy, .. }) if xy == target => return Ok(e),
-            Ok(DataEvent::Eof) => return Ok(DataEvent::Eof),
-            Ok(_) => {}
-            Err(e) => return Err(e.into()),
-        }
+    let mut point = Datapoint::from_timestamp(timestamp, true);
+    for field in FIELDS {
+        *(field.target)(&mut point) = field
+            .queries
+            .iter()
+            .find_map(|q| subset.query(q).iter().find_map(|m| (field.convert)(m)));
     }
-}
-
-fn forward_until_seq(r: &mut DataReader<impl Read>, target: XY) -> anyhow::Result<bool> {
-    loop {
-        match r.read_event() {
-            Ok(DataEvent::SequenceStart { xy, .. }) if xy == target => return Ok(true),
-            Ok(DataEvent::Eof) => return Ok(false),
-            Ok(_) => {}
-            Err(e) => return Err(e.into()),
-        }
+    for field in HEIGHT_FIELDS {
+        *(field.target)(&mut point) = field
+            .queries
+            .iter()
+            .find_map(|q| closest_to_desired_height(&subset.query(q), field.convert));
     }
-}
-
-fn read_int(r: &mut DataReader<impl Read>) -> anyhow::Result<Option<i32>> {
-    match r.read_event() {
-        Ok(DataEvent::Data {
-            value: Value::Integer(i),
-            ..
-        }) => Ok(Some(i)),
-        Ok(_) => Ok(None),
-        Err(e) => Err(e.into()),
+    // reserved codes and "not observed" (509, 510) don't describe the weather
+    if let Some(ww) = subset
+        .get(SIGNIFICANT_WEATHER)
+        .and_then(Element::as_i32)
+        .and_then(|ww| u16::try_from(ww).ok())
+        && Condition::from_synop(ww).is_some()
+    {
+        point.condition = WeatherCondition::Synop(ww);
     }
-}
 
-fn value_to_float(v: &Value) -> Option<f32> {
-    match v {
-        Value::Integer(i) => Some(*i as f32),
-        Value::Decimal(v, s) => Some(*v as f32 * 10f32.powi(*s as i32)),
-        _ => None,
-    }
+    Some(StationObservation {
+        id: id.to_owned(),
+        name: subset
+            .get(STATION_NAME)
+            .and_then(Element::as_str)
+            .map(str::to_owned),
+        latitude: float(LATITUDE, LATITUDE_COARSE),
+        longitude: float(LONGITUDE, LONGITUDE_COARSE),
+        elevation: subset.get(STATION_ELEVATION).and_then(Element::as_f32),
+        point,
+    })
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct VisitedGtsMessage {
-    day: u8,
-    hour: u8,
-    minute: u8,
+    timestamp: jiff::Timestamp,
+    /// Corrections and amendments replace earlier bulletins of the same slot
+    revision: u8,
 }
 
 impl VisitedGtsMessage {
-    pub fn new(gts: &GtsHeader) -> Self {
+    pub fn new(gts: &GtsHeader, timestamp: jiff::Timestamp) -> Self {
         Self {
-            day: gts.day,
-            hour: gts.hour,
-            minute: gts.minute,
+            timestamp,
+            revision: match gts.bbb {
+                Some(Bbb::Corrected(x) | Bbb::Amended(x)) => x - b'A' + 1,
+                _ => 0,
+            },
         }
     }
 }
 
-impl PartialOrd for VisitedGtsMessage {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+#[cfg(test)]
+mod tests {
+    use dwd_bufr::Encoder;
+    use dwd_gts::BulletinWriter;
+    use tinybufr::{Descriptor, Value};
+
+    use super::*;
+
+    const fn element(x: u8, y: u8) -> Descriptor {
+        Descriptor { f: 0, x, y }
     }
-}
 
-impl Ord for VisitedGtsMessage {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self.day.cmp(&other.day) {
-            std::cmp::Ordering::Equal => {}
-            std::cmp::Ordering::Less if self.day == 1 => {
-                return std::cmp::Ordering::Greater;
-            }
-            ord => return ord,
-        }
-        match self.hour.cmp(&other.hour) {
-            std::cmp::Ordering::Equal => {}
-            ord => return ord,
+    fn header(product: &str, now: &jiff::Zoned) -> GtsHeader {
+        GtsHeader {
+            seq_no: 1,
+            product_id: product.parse().unwrap(),
+            source: "EDZW".parse().unwrap(),
+            day: now.day() as u8,
+            hour: now.hour() as u8,
+            minute: 0,
+            bbb: None,
         }
-        self.minute.cmp(&other.minute)
+    }
+
+    #[test]
+    fn reads_synthetic_bulletins() {
+        let now = jiff::Timestamp::now().to_zoned(jiff::tz::TimeZone::UTC);
+        let tables = VersionedTables::new();
+        let encoder = Encoder::new(
+            vec![
+                element(1, 128),
+                element(4, 1),
+                element(4, 2),
+                element(4, 3),
+                element(4, 4),
+                element(4, 5),
+                // (sensor height, temperature) replicated
+                Descriptor { f: 1, x: 2, y: 0 },
+                element(31, 1),
+                element(7, 32),
+                element(12, 101),
+                element(20, 3),
+            ],
+            now.datetime(),
+        );
+        let subset = |id: &str, temperatures: &[(i32, i32)], ww: Value| {
+            let mut subset = encoder.missing_subset(&tables, temperatures.len()).unwrap();
+            let mut set = |xy, n, value| assert!(subset.set(&Query::new(xy), n, value));
+            set(WIGOS_LOCAL_ID, 0, Value::String(id.to_owned()));
+            for (xy, value) in [
+                (YEAR, now.year().into()),
+                (MONTH, now.month().into()),
+                (DAY, now.day().into()),
+                (HOUR, now.hour().into()),
+            ] {
+                set(xy, 0, Value::Integer(value));
+            }
+            set(MINUTE, 0, Value::Integer(0));
+            for (n, (height, temperature)) in temperatures.iter().enumerate() {
+                set(SENSOR_HEIGHT_ABOVE_GROUND, n, Value::Decimal(*height, -2));
+                set(TEMPERATURE, n, Value::Decimal(*temperature, -2));
+            }
+            set(SIGNIFICANT_WEATHER, 0, ww);
+            subset
+        };
+        // a broken time only skips its subset
+        let mut invalid = subset("10400", &[(200, 29315)], Value::Missing);
+        assert!(invalid.set(&Query::new(MONTH), 0, Value::Integer(13)));
+        let message = encoder
+            .encode(
+                &tables,
+                &[
+                    subset("10020", &[(5, 29495), (200, 29205)], Value::Integer(61)),
+                    subset("10381", &[], Value::Integer(509)),
+                    subset("10999", &[(200, 29315)], Value::Missing),
+                    subset(
+                        "10488",
+                        &[(250, 28815), (5, 29315), (180, 29015)],
+                        Value::Integer(261),
+                    ),
+                    invalid,
+                ],
+            )
+            .unwrap();
+
+        let mut writer = BulletinWriter::new(Vec::new());
+        writer.write_nil(&header("ISMD01", &now)).unwrap();
+        writer
+            .write_bulletin(&header("ISMD02", &now), &message)
+            .unwrap();
+        let file = writer.into_inner();
+
+        let stations = ["10381", "10020", "10400", "10488"].map(str::to_owned);
+        let (observations, newest) = read_file_to_observations(file.as_slice(), &stations).unwrap();
+        assert!(newest.is_some());
+        assert_eq!(observations.len(), 3);
+
+        let missing = &observations[0];
+        assert_eq!(missing.id, "10381");
+        assert_eq!(missing.point.temperature, None);
+        assert_eq!(missing.point.condition, WeatherCondition::None);
+
+        let full = &observations[1];
+        assert_eq!(full.id, "10020");
+        // the 2 m temperature, not the first one
+        assert!((full.point.temperature.unwrap() - 18.9).abs() < 1e-3);
+        assert_eq!(full.point.condition, WeatherCondition::Synop(61));
+
+        // 1.8 m is the closest to 2 m
+        let no_2m = &observations[2];
+        assert_eq!(no_2m.id, "10488");
+        assert!((no_2m.point.temperature.unwrap() - 17.0).abs() < 1e-3);
+        // ww + 200
+        assert_eq!(no_2m.point.condition, WeatherCondition::Synop(261));
+    }
+
+    #[test]
+    fn old_report_search() {
+        let now = jiff::Timestamp::now();
+        let observation = StationObservation {
+            id: "10381".to_owned(),
+            name: None,
+            latitude: None,
+            longitude: None,
+            elevation: None,
+            point: Datapoint::from_timestamp(now, true),
+        };
+        let found = check_old_report(Ok((vec![observation], Some(now))));
+        assert!(matches!(found, ControlFlow::Break(o) if o.len() == 1));
+        // keep looking in recent reports and after errors
+        assert!(check_old_report(Ok((Vec::new(), Some(now)))).is_continue());
+        assert!(check_old_report(Err(Error::format("BUFR", "invalid end"))).is_continue());
+
+        let old = now - jiff::SignedDuration::from_hours(7);
+        let stop = check_old_report(Ok((Vec::new(), Some(old))));
+        assert!(matches!(stop, ControlFlow::Break(o) if o.is_empty()));
     }
 }
@@ -0,0 +1,237 @@
+use std::io::{self, Read, Write};
+
+use atoi::atoi;
+
+use crate::{Error, GtsHeader};
+
+const NIL: &[u8] = b"NIL";
+const TRAILER: &[u8] = b"\r\r\n\x03";
+
+/// Reads the bulletins of a GTS file (e.g. the SYNOP BUFR files from DWD).
+///
+/// Each message is `<length:8><format:2><header><payload><CR><CR><LF><ETX>`.
+/// `NIL` messages are skipped.
+///
+/// This is a lending iterator - a [`Bulletin`] borrows the reader, so use
+/// `while let Some(bulletin) = reader.next_bulletin()`.
+pub struct BulletinReader<R> {
+    inner: R,
+    /// Unread payload of the current bulletin
+    remaining: u64,
+    in_bulletin: bool,
+    finished: bool,
+}
+
+/// A single bulletin. Reading from it yields the payload (e.g. a BUFR message).
+///
+/// Unread parts of the payload are skipped when reading the next bulletin.
+pub struct Bulletin<'a, R> {
+    pub header: GtsHeader,
+    reader: &'a mut BulletinReader<R>,
+}
+
+impl<R: Read> BulletinReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            remaining: 0,
+            in_bulletin: false,
+            finished: false,
+        }
+    }
+
+    /// Returns the next bulletin or `None` at the end of the file.
+    ///
+    /// After an error, no more bulletins are returned.
+    pub fn next_bulletin(&mut self) -> Option<Result<Bulletin<'_, R>, Error>> {
+        if self.finished {
+            return None;
+        }
+        match self.read_header() {
+            Ok(Some(header)) => Some(Ok(Bulletin {
+                header,
+                reader: self,
+            })),
+            Ok(None) => {
+                self.finished = true;
+                None
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+
+    fn read_header(&mut self) -> Result<Option<GtsHeader>, Error> {
+        if self.in_bulletin {
+            io::copy(
+                &mut self.inner.by_ref().take(self.remaining),
+                &mut io::sink(),
+            )?;
+            self.remaining = 0;
+            self.in_bulletin = false;
+            self.read_trailer()?;
+        }
+
+        loop {
+            // 8 bytes ASCII message length
+            let mut length_bytes = [0u8; 8];
+            if !read_exact_or_eof(&mut self.inner, &mut length_bytes)? {
+                return Ok(None);
+            }
+            let Some(length) = atoi::<u64>(&length_bytes).filter(|x| *x > 0) else {
+                return Ok(None);
+            };
+            // skip the format identifier ("00")
+            self.inner.read_exact(&mut length_bytes[..2])?;
+
+            let mut header_bytes = [0; 35];
+            let mut header_len = 31;
+            self.inner.read_exact(&mut header_bytes[..31])?;
+            if &header_bytes[28..31] != b"\r\r\n" {
+                self.inner.read_exact(&mut header_bytes[31..])?;
+                if &header_bytes[32..] != b"\r\r\n" {
+                    return Err(Error::ExpectedCrCrLf("heading"));
+                }
+                header_len = 35;
+            }
+            let header = GtsHeader::read(&header_bytes)?;
+            let payload_len = length
+                .checked_sub(header_len + TRAILER.len() as u64)
+                .ok_or(Error::InvalidLength)?;
+
+            if payload_len == NIL.len() as u64 {
+                let mut nil = [0; 3];
+                self.inner.read_exact(&mut nil)?;
+                if nil != NIL {
+                    return Err(Error::InvalidNil);
+                }
+                self.read_trailer()?;
+                continue;
+            }
+
+            self.remaining = payload_len;
+            self.in_bulletin = true;
+            return Ok(Some(header));
+        }
+    }
+
+    fn read_trailer(&mut self) -> Result<(), Error> {
+        let mut trailer = [0; 4];
+        self.inner.read_exact(&mut trailer)?;
+        if trailer != TRAILER {
+            return Err(Error::InvalidEnd);
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for Bulletin<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let max = buf
+            .len()
+            .min(self.reader.remaining.try_into().unwrap_or(usize::MAX));
+        if max == 0 {
+            return Ok(0);
+        }
+        let n = self.reader.inner.read(&mut buf[..max])?;
+        self.reader.remaining -= n as u64;
+        Ok(n)
+    }
+}
+
+/// Writes bulletins in the format read by [`BulletinReader`] (e.g. to create fixtures).
+pub struct BulletinWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> BulletinWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Writes a bulletin with `payload` (e.g. a BUFR message).
+    pub fn write_bulletin(&mut self, header: &GtsHeader, payload: &[u8]) -> io::Result<()> {
+        let header = header.to_bytes();
+        let length = header.len() + payload.len() + TRAILER.len();
+        if length > 99_999_999 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "bulletin too long",
+            ));
+        }
+        // the format identifier is always "00"
+        write!(self.inner, "{length:08}00")?;
+        self.inner.write_all(&header)?;
+        self.inner.write_all(payload)?;
+        self.inner.write_all(TRAILER)
+    }
+
+    /// Writes a `NIL` bulletin (a slot without reports).
+    pub fn write_nil(&mut self, header: &GtsHeader) -> io::Result<()> {
+        self.write_bulletin(header, NIL)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Like [`Read::read_exact`], but returns `false` if the reader is at its end.
+fn read_exact_or_eof(r: &mut impl Read, buf: &mut [u8]) -> io::Result<bool> {
+    let mut read = 0;
+    while read < buf.len() {
+        match r.read(&mut buf[read..]) {
+            Ok(0) if read == 0 => return Ok(false),
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => read += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bbb;
+
+    fn message(heading: &str, payload: &[u8]) -> Vec<u8> {
+        let mut body = format!("\x01\r\r\n123\r\r\n{heading}\r\r\n").into_bytes();
+        body.extend_from_slice(payload);
+        body.extend_from_slice(TRAILER);
+        let mut msg = format!("{:08}00", body.len()).into_bytes();
+        msg.extend(body);
+        msg
+    }
+
+    #[test]
+    fn skips_nil_and_unread_payload() {
+        let mut file = message("ISMD01 EDZW 171200", b"NIL");
+        file.extend(message("ISMD02 EDZW 171200 RRA", b"BUFR..7777"));
+        file.extend(message("ISMD03 EDZW 171300", b"BUFR...7777"));
+
+        let mut reader = BulletinReader::new(file.as_slice());
+        let mut payloads = Vec::new();
+        while let Some(bulletin) = reader.next_bulletin() {
+            let mut bulletin = bulletin.unwrap();
+            let mut start = [0; 4];
+            bulletin.read_exact(&mut start).unwrap();
+            payloads.push((
+                bulletin.header.product_id.to_string(),
+                bulletin.header.bbb,
+                start,
+            ));
+        }
+
+        assert_eq!(
+            payloads,
+            [
+                ("ISMD02".to_owned(), Some(Bbb::Delayed(b'A')), *b"BUFR"),
+                ("ISMD03".to_owned(), None, *b"BUFR")
+            ]
+        );
+    }
+}
@@ -1,47 +1,124 @@
-use anyhow::bail;
 use atoi::atoi;
 use tinystr::TinyAsciiStr;
 
+mod bulletin;
+
+pub use bulletin::{Bulletin, BulletinReader, BulletinWriter};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid message length")]
+    InvalidLength,
+    #[error("invalid start of GTS message")]
+    InvalidStart,
+    #[error("expected <cr><cr><lf> after {0}")]
+    ExpectedCrCrLf(&'static str),
+    #[error("expected space after {0}")]
+    ExpectedSpace(&'static str),
+    #[error("invalid {0}")]
+    InvalidField(&'static str),
+    #[error("invalid NIL message")]
+    InvalidNil,
+    #[error("invalid end of GTS message")]
+    InvalidEnd,
+}
+
 #[derive(Debug)]
 pub struct GtsHeader {
     pub seq_no: u16,
+    /// `T1T2A1A2ii`
     pub product_id: TinyAsciiStr<6>,
+    /// `CCCC` - the originating centre
     pub source: TinyAsciiStr<4>,
     pub day: u8,
     pub hour: u8,
     pub minute: u8,
+    pub bbb: Option<Bbb>,
+}
+
+/// The optional `BBB` group of the abbreviated heading.
+///
+/// The letter (`x`) counts up from `A` for each additional bulletin of the same slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bbb {
+    /// `RRx` - delayed (additional) reports
+    Delayed(u8),
+    /// `CCx` - corrected bulletin
+    Corrected(u8),
+    /// `AAx` - amended bulletin
+    Amended(u8),
+    /// `Pxx` - segment of a split bulletin
+    Segment(TinyAsciiStr<2>),
+}
+
+impl Bbb {
+    pub fn parse(bbb: &[u8]) -> Option<Self> {
+        let [a, b, x] = *bbb else {
+            return None;
+        };
+        let letter = || x.is_ascii_uppercase().then_some(x);
+        match [a, b] {
+            [b'R', b'R'] => letter().map(Self::Delayed),
+            [b'C', b'C'] => letter().map(Self::Corrected),
+            [b'A', b'A'] => letter().map(Self::Amended),
+            [b'P', _] => TinyAsciiStr::try_from_utf8(&[b, x]).ok().map(Self::Segment),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Bbb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Delayed(x) => write!(f, "RR{}", *x as char),
+            Self::Corrected(x) => write!(f, "CC{}", *x as char),
+            Self::Amended(x) => write!(f, "AA{}", *x as char),
+            Self::Segment(xx) => write!(f, "P{xx}"),
+        }
+    }
 }
 
 impl GtsHeader {
     // from https://www.eumetnet.eu/wp-content/uploads/2025/05/OPERA_bufr_sw_desc.pdf
     // and https://rom-saf.eumetsat.int/romsaf_bufr.pdf
-    // <SOH><CR><CR><LF>nnn<CR><CR><LF>T_1 T_2 A_1 A_2 ii<SP>cccc<SP>YYGGgg<CR><CR><LF>
-    pub fn read(header: &[u8; 35]) -> anyhow::Result<Self> {
+    // <SOH><CR><CR><LF>nnn<CR><CR><LF>T_1 T_2 A_1 A_2 ii<SP>cccc<SP>YYGGgg[<SP>BBB]<CR><CR><LF>
+    //
+    // Without BBB, only the first 31 bytes are used.
+    pub fn read(header: &[u8; 35]) -> Result<Self, Error> {
         if &header[..4] != b"\x01\r\r\n" {
-            bail!("invalid start of GTS message");
+            return Err(Error::InvalidStart);
         }
         let Some(seq_no) = atoi(&header[4..7]) else {
-            bail!("invalid seq no");
+            return Err(Error::InvalidField("seq no"));
         };
         if &header[7..10] != b"\r\r\n" {
-            bail!("expected <cr><cr><lf>");
+            return Err(Error::ExpectedCrCrLf("seq no"));
         }
-        let product_id = TinyAsciiStr::<6>::try_from_utf8(&header[10..16])?;
+        let product_id = TinyAsciiStr::<6>::try_from_utf8(&header[10..16])
+            .map_err(|_| Error::InvalidField("product"))?;
         if header[16] != b' ' {
-            bail!("expected space after product");
+            return Err(Error::ExpectedSpace("product"));
         }
-        let source = TinyAsciiStr::<4>::try_from_utf8(&header[17..21])?;
+        let source = TinyAsciiStr::<4>::try_from_utf8(&header[17..21])
+            .map_err(|_| Error::InvalidField("source"))?;
         if header[21] != b' ' {
-            bail!("expected space after source");
+            return Err(Error::ExpectedSpace("source"));
         }
         let Some(day) = atoi(&header[22..24]) else {
-            bail!("invalid day");
+            return Err(Error::InvalidField("day"));
         };
         let Some(hour) = atoi(&header[24..26]) else {
-            bail!("invalid hour");
+            return Err(Error::InvalidField("hour"));
         };
         let Some(minute) = atoi(&header[26..28]) else {
-            bail!("invalid minute");
+            return Err(Error::InvalidField("minute"));
+        };
+        let bbb = match header[28] {
+            b'\r' => None,
+            b' ' => Some(Bbb::parse(&header[29..32]).ok_or(Error::InvalidField("BBB"))?),
+            _ => return Err(Error::ExpectedSpace("time")),
         };
 
         Ok(Self {
@@ -51,6 +128,101 @@ impl GtsHeader {
             day,
             hour,
             minute,
+            bbb,
         })
     }
+
+    /// The header in the format read by [`GtsHeader::read`] (31 or 35 bytes).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut heading = format!(
+            "\x01\r\r\n{:03}\r\r\n{} {} {:02}{:02}{:02}",
+            self.seq_no % 1000,
+            self.product_id,
+            self.source,
+            self.day,
+            self.hour,
+            self.minute
+        );
+        if let Some(bbb) = self.bbb {
+            heading.push(' ');
+            heading.push_str(&bbb.to_string());
+        }
+        heading.push_str("\r\r\n");
+        heading.into_bytes()
+    }
+
+    /// Resolves `YYGGgg` (UTC) to the timestamp closest to `reference`.
+    ///
+    /// The heading only contains the day of the month, so the month and year are taken from the
+    /// reference (or the month before/after it).
+    pub fn timestamp(&self, reference: jiff::Timestamp) -> Option<jiff::Timestamp> {
+        let month = reference
+            .to_zoned(jiff::tz::TimeZone::UTC)
+            .date()
+            .first_of_month();
+        [
+            month.checked_sub(jiff::Span::new().months(1)).ok(),
+            Some(month),
+            month.checked_add(jiff::Span::new().months(1)).ok(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|m| {
+            jiff::civil::datetime(m.year(), m.month(), 1, 0, 0, 0, 0)
+                .with()
+                .day(self.day as i8)
+                .hour(self.hour as i8)
+                .minute(self.minute as i8)
+                .build()
+                .ok()?
+                .to_zoned(jiff::tz::TimeZone::UTC)
+                .ok()
+        })
+        .map(|z| z.timestamp())
+        .min_by_key(|ts| ts.duration_since(reference).abs())
+    }
+
+    /// `T1T2` - the data type designator (e.g. `IS` for surface observations in BUFR).
+    pub fn data_type(&self) -> &str {
+        self.product_id.as_str().get(..2).unwrap_or_default()
+    }
+
+    /// `A1A2` - the geographical area (or data type for `I`/`J` bulletins).
+    pub fn area(&self) -> &str {
+        self.product_id.as_str().get(2..4).unwrap_or_default()
+    }
+
+    /// `ii` - distinguishes bulletins with the same `T1T2A1A2` and origin.
+    pub fn ii(&self) -> Option<u8> {
+        atoi(self.product_id.as_bytes().get(4..)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_rolls_over_months() {
+        let header = |day, hour| GtsHeader {
+            seq_no: 0,
+            product_id: tinystr::tinystr!(6, "ISMD01"),
+            source: tinystr::tinystr!(4, "EDZW"),
+            day,
+            hour,
+            minute: 0,
+            bbb: None,
+        };
+        let reference: jiff::Timestamp = "2026-11-01T01:00:00Z".parse().unwrap();
+        assert_eq!(
+            header(31, 23).timestamp(reference),
+            Some("2026-10-31T23:00:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            header(1, 0).timestamp(reference),
+            Some("2026-11-01T00:00:00Z".parse().unwrap())
+        );
+        let reference: jiff::Timestamp = "2026-10-03T12:00:00Z".parse().unwrap();
+        assert!(header(2, 12).timestamp(reference) < header(3, 6).timestamp(reference));
+    }
 }
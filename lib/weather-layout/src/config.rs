@@ -0,0 +1,174 @@
+//! Config sections shared by the desktop app and picolini-srv.
+
+use std::collections::BTreeMap;
+
+use dwd_fetch::{Coordinates, PoiStation, description::Language};
+use icu_locale::Locale;
+use jiff::tz::TimeZone;
+
+use crate::{
+    PlanOptions,
+    fmt::{self, Units},
+};
+
+/// Name of the location specified at the top level of the config
+pub const DEFAULT_LOCATION: &str = "default";
+
+/// A location with its stations as written in the config
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LocationData {
+    station: u16,
+    latitude: f64,
+    longitude: f64,
+    synop_stations: Vec<String>,
+}
+
+impl LocationData {
+    pub fn into_dwd(self) -> dwd_fetch::Config {
+        dwd_fetch::Config {
+            poi_station: PoiStation(self.station),
+            coordinates: Coordinates {
+                latitude: self.latitude,
+                longitude: self.longitude,
+            },
+            radar_coords: dwd_fetch::latlong_to_idx(self.latitude, self.longitude),
+            synop_stations: self.synop_stations,
+        }
+    }
+}
+
+/// The top-level location named [`DEFAULT_LOCATION`] followed by the named ones
+///
+/// # Panics
+///
+/// If one of the named locations is called [`DEFAULT_LOCATION`].
+pub fn dwd_locations(
+    default: LocationData,
+    named: BTreeMap<String, LocationData>,
+) -> Vec<(String, dwd_fetch::Config)> {
+    let mut locations = vec![(DEFAULT_LOCATION.to_owned(), default.into_dwd())];
+    for (name, location) in named {
+        if name == DEFAULT_LOCATION {
+            panic!("The location '{DEFAULT_LOCATION}' is reserved for the top-level station");
+        }
+        locations.push((name, location.into_dwd()));
+    }
+    locations
+}
+
+/// How values, labels and the forecast graph are displayed, as written in the config
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct DisplayData {
+    /// Language of the weather descriptions, derived from the locale by default
+    #[serde(default)]
+    language: Option<Language>,
+    /// IANA time zone of the labels, the system time zone by default
+    #[serde(default)]
+    time_zone: Option<String>,
+    /// Locale of the date labels
+    #[serde(default)]
+    locale: Option<String>,
+    /// Units values are displayed in
+    #[serde(default)]
+    units: Units,
+    /// Hours of forecast shown in the graph
+    #[serde(default)]
+    horizon_hours: Option<i32>,
+}
+
+/// [`DisplayData`] with the defaults filled in
+#[derive(Debug, Clone)]
+pub struct DisplayConfig {
+    pub language: Language,
+    pub units: Units,
+    pub time_zone: TimeZone,
+    pub locale: Locale,
+    /// Time span of the forecast graph, already validated
+    pub plan_options: PlanOptions,
+}
+
+impl DisplayData {
+    /// # Panics
+    ///
+    /// If the time zone, the locale or the horizon is invalid.
+    pub fn load(self) -> DisplayConfig {
+        let time_zone = fmt::time_zone_or_system(self.time_zone.as_deref());
+        let locale = fmt::locale_or_default(self.locale.as_deref());
+        let plan_options = PlanOptions {
+            horizon_hours: self
+                .horizon_hours
+                .unwrap_or(PlanOptions::default().horizon_hours),
+            ..Default::default()
+        };
+        if let Err(e) = plan_options.validate() {
+            panic!("Invalid horizon_hours: {e}");
+        }
+
+        DisplayConfig {
+            language: self
+                .language
+                .unwrap_or_else(|| Language::from_code(locale.id.language.as_str())),
+            units: self.units,
+            time_zone,
+            locale,
+            plan_options,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(station: u16) -> LocationData {
+        LocationData {
+            station,
+            latitude: 52.52,
+            longitude: 13.40,
+            synop_stations: vec!["10381".to_owned()],
+        }
+    }
+
+    #[test]
+    fn default_location_first() {
+        let named = BTreeMap::from([("office".to_owned(), location(10384))]);
+        let locations = dwd_locations(location(10382), named);
+        let names = locations
+            .iter()
+            .map(|(n, _)| n.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, [DEFAULT_LOCATION, "office"]);
+        assert_eq!(locations[1].1.poi_station, PoiStation(10384));
+    }
+
+    #[test]
+    #[should_panic = "reserved"]
+    fn reserved_location_name() {
+        let named = BTreeMap::from([(DEFAULT_LOCATION.to_owned(), location(10384))]);
+        dwd_locations(location(10382), named);
+    }
+
+    #[test]
+    fn display_defaults() {
+        let display = DisplayData {
+            locale: Some("de-DE".to_owned()),
+            ..Default::default()
+        }
+        .load();
+        assert_eq!(display.language, Language::German);
+        assert_eq!(
+            display.plan_options.horizon_hours,
+            PlanOptions::default().horizon_hours
+        );
+    }
+
+    #[test]
+    #[should_panic = "horizon_hours"]
+    fn horizon_within_near_future() {
+        DisplayData {
+            horizon_hours: Some(12),
+            ..Default::default()
+        }
+        .load();
+    }
+}
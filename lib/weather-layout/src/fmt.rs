@@ -1,3 +1,320 @@
+//! Unit conversion and formatting of values and dates for display.
+//!
+//! Values from [`dwd_fetch::Datapoint`] are always in °C, km/h, mm and hPa.
+
+use dwd_fetch::description::Language;
+use icu_datetime::{
+    DateTimeFormatter,
+    fieldsets::{E, YMDE},
+    input::Date,
+};
+use icu_locale::{Locale, locale};
+use jiff::tz::TimeZone;
+
 pub fn optional_fract(x: f32) -> String {
     format!("{x:.*}", if x.fract() == 0.0 { 0 } else { 1 })
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+pub enum TemperatureUnit {
+    #[default]
+    #[serde(rename = "C")]
+    Celsius,
+    #[serde(rename = "F")]
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    /// Converts from °C
+    pub fn convert(self, celsius: f32) -> f32 {
+        match self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+pub enum WindUnit {
+    #[default]
+    #[serde(rename = "km/h")]
+    KilometersPerHour,
+    #[serde(rename = "m/s")]
+    MetersPerSecond,
+    #[serde(rename = "kn")]
+    Knots,
+    #[serde(rename = "Bft")]
+    Beaufort,
+}
+
+impl WindUnit {
+    /// Converts from km/h
+    pub fn convert(self, kmh: f32) -> f32 {
+        match self {
+            Self::KilometersPerHour => kmh,
+            Self::MetersPerSecond => kmh / 3.6,
+            Self::Knots => kmh / 1.852,
+            Self::Beaufort => dwd_fetch::comfort::beaufort(kmh) as f32,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::KilometersPerHour => "km/h",
+            Self::MetersPerSecond => "m/s",
+            Self::Knots => "kn",
+            Self::Beaufort => "Bft",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+pub enum PrecipitationUnit {
+    #[default]
+    #[serde(rename = "mm")]
+    Millimeters,
+    #[serde(rename = "in")]
+    Inches,
+}
+
+impl PrecipitationUnit {
+    /// Converts from mm
+    pub fn convert(self, mm: f32) -> f32 {
+        match self {
+            Self::Millimeters => mm,
+            Self::Inches => mm / 25.4,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Millimeters => "mm",
+            Self::Inches => "in",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+pub enum PressureUnit {
+    #[default]
+    #[serde(rename = "hPa")]
+    Hectopascal,
+    #[serde(rename = "inHg")]
+    InchesOfMercury,
+}
+
+impl PressureUnit {
+    /// Converts from hPa
+    pub fn convert(self, hpa: f32) -> f32 {
+        match self {
+            Self::Hectopascal => hpa,
+            Self::InchesOfMercury => hpa / 33.8639,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Hectopascal => "hPa",
+            Self::InchesOfMercury => "inHg",
+        }
+    }
+}
+
+/// The units values are displayed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct Units {
+    pub temperature: TemperatureUnit,
+    pub wind: WindUnit,
+    pub precipitation: PrecipitationUnit,
+    pub pressure: PressureUnit,
+}
+
+impl Units {
+    /// `21.5°`
+    pub fn temperature(&self, celsius: f32) -> String {
+        format!("{}°", optional_fract(self.temperature.convert(celsius)))
+    }
+
+    /// `22°`
+    pub fn temperature_rounded(&self, celsius: f32) -> String {
+        format!("{:.0}°", self.temperature.convert(celsius))
+    }
+
+    /// `feels like 22°`, if the apparent temperature differs by at least a degree (Celsius)
+    pub fn feels_like(&self, lang: Language, celsius: f32, apparent: f32) -> Option<String> {
+        if (apparent - celsius).abs() < 1.0 {
+            return None;
+        }
+        let apparent = self.temperature_rounded(apparent);
+        Some(match lang {
+            Language::German => format!("gefühlt {apparent}"),
+            Language::English => format!("feels like {apparent}"),
+        })
+    }
+
+    /// `21.5 °C`
+    pub fn temperature_with_symbol(&self, celsius: f32) -> String {
+        format!(
+            "{}\u{00A0}{}",
+            optional_fract(self.temperature.convert(celsius)),
+            self.temperature.symbol()
+        )
+    }
+
+    /// `14.4 km/h`
+    pub fn wind(&self, kmh: f32) -> String {
+        format!(
+            "{}\u{00A0}{}",
+            optional_fract(self.wind.convert(kmh)),
+            self.wind.symbol()
+        )
+    }
+
+    /// `1.2 mm`
+    pub fn precipitation(&self, mm: f32) -> String {
+        let value = self.precipitation.convert(mm);
+        let precision = match self.precipitation {
+            PrecipitationUnit::Millimeters => 1,
+            PrecipitationUnit::Inches => 2,
+        };
+        format!("{value:.precision$}\u{00A0}{}", self.precipitation.symbol())
+    }
+
+    /// `1013 hPa`
+    pub fn pressure(&self, hpa: f32) -> String {
+        let value = self.pressure.convert(hpa);
+        let precision = match self.pressure {
+            PressureUnit::Hectopascal => 0,
+            PressureUnit::InchesOfMercury => 2,
+        };
+        format!("{value:.precision$}\u{00A0}{}", self.pressure.symbol())
+    }
+}
+
+/// Looks up a time zone by its IANA name, the system time zone by default
+///
+/// # Panics
+///
+/// If the name is not in the time zone database.
+pub fn time_zone_or_system(name: Option<&str>) -> TimeZone {
+    match name {
+        Some(name) => jiff::tz::db()
+            .get(name)
+            .unwrap_or_else(|_| panic!("Unknown time zone '{name}'")),
+        None => TimeZone::system(),
+    }
+}
+
+/// Parses a BCP 47 locale tag, `en-GB` by default
+///
+/// # Panics
+///
+/// If the tag is not a valid locale.
+pub fn locale_or_default(tag: Option<&str>) -> Locale {
+    match tag {
+        Some(tag) => tag
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid locale '{tag}'")),
+        None => locale!("en-GB"),
+    }
+}
+
+/// `Sunrise 04:43  Sunset 21:33` from the formatted times
+pub fn sun_times(lang: Language, sunrise: &str, sunset: &str) -> String {
+    match lang {
+        Language::German => format!("Sonnenaufgang {sunrise}  Sonnenuntergang {sunset}"),
+        Language::English => format!("Sunrise {sunrise}  Sunset {sunset}"),
+    }
+}
+
+/// Localized date labels.
+pub struct DateFormatter {
+    weekday: DateTimeFormatter<E>,
+    long: DateTimeFormatter<YMDE>,
+}
+
+impl DateFormatter {
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            weekday: DateTimeFormatter::try_new(locale.clone().into(), E::short()).unwrap(),
+            long: DateTimeFormatter::try_new(locale.clone().into(), YMDE::long()).unwrap(),
+        }
+    }
+
+    /// `Mon`
+    pub fn weekday(&self, date: jiff::civil::Date) -> String {
+        let date =
+            Date::try_new_iso(date.year() as i32, date.month() as u8, date.day() as u8).unwrap();
+        self.weekday.format(&date).to_string()
+    }
+
+    /// `Monday, 6 January 2025`
+    pub fn long(&self, date: jiff::civil::Date) -> String {
+        let date =
+            Date::try_new_iso(date.year() as i32, date.month() as u8, date.day() as u8).unwrap();
+        self.long.format(&date).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let near = |a: f32, b: f32| (a - b).abs() < 0.01;
+        assert!(near(TemperatureUnit::Fahrenheit.convert(100.0), 212.0));
+        assert!(near(TemperatureUnit::Fahrenheit.convert(-40.0), -40.0));
+        assert!(near(WindUnit::Knots.convert(18.52), 10.0));
+        assert!(near(WindUnit::MetersPerSecond.convert(36.0), 10.0));
+        assert_eq!(WindUnit::Beaufort.convert(20.0), 4.0);
+        assert!(near(PrecipitationUnit::Inches.convert(25.4), 1.0));
+        assert!(near(PressureUnit::InchesOfMercury.convert(1013.25), 29.92));
+    }
+
+    #[test]
+    fn formats() {
+        let metric = Units::default();
+        assert_eq!(metric.temperature(21.5), "21.5°");
+        assert_eq!(metric.temperature(21.0), "21°");
+        assert_eq!(metric.temperature_rounded(21.6), "22°");
+        assert_eq!(metric.temperature_with_symbol(21.5), "21.5\u{a0}°C");
+        assert_eq!(
+            metric.feels_like(Language::English, 2.0, -4.2).as_deref(),
+            Some("feels like -4°")
+        );
+        assert_eq!(
+            metric.feels_like(Language::German, 2.0, -4.2).as_deref(),
+            Some("gefühlt -4°")
+        );
+        assert_eq!(metric.feels_like(Language::English, 2.0, 1.5), None);
+        assert_eq!(metric.wind(14.4), "14.4\u{a0}km/h");
+        assert_eq!(metric.precipitation(1.24), "1.2\u{a0}mm");
+        assert_eq!(metric.pressure(1013.25), "1013\u{a0}hPa");
+
+        let imperial = Units {
+            temperature: TemperatureUnit::Fahrenheit,
+            wind: WindUnit::Knots,
+            precipitation: PrecipitationUnit::Inches,
+            pressure: PressureUnit::InchesOfMercury,
+        };
+        assert_eq!(imperial.temperature(20.0), "68°");
+        assert_eq!(imperial.temperature_with_symbol(-40.0), "-40\u{a0}°F");
+        assert_eq!(imperial.wind(18.52), "10\u{a0}kn");
+        assert_eq!(imperial.precipitation(1.2), "0.05\u{a0}in");
+        assert_eq!(imperial.pressure(1013.25), "29.92\u{a0}inHg");
+        let beaufort = Units {
+            wind: WindUnit::Beaufort,
+            ..metric
+        };
+        assert_eq!(beaufort.wind(20.0), "4\u{a0}Bft");
+    }
+}
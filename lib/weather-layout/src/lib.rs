@@ -1,10 +1,14 @@
-use jiff::ToSpan;
+use std::num::NonZeroUsize;
+
+use icu_locale::Locale;
+use jiff::{ToSpan, tz::TimeZone};
 use skia_safe::{Color4f, Path, PathBuilder, Point, Rect, Shader};
 use skia_util::RectExt;
 
-use dwd_fetch::{Datapoint, RadarReading};
+use dwd_fetch::{Coordinates, Datapoint, RadarReading, sun::SunTimes};
 use skia_util::gradient::AutoGradientBuilder;
 
+pub mod config;
 pub mod data;
 pub mod fmt;
 mod gradients;
@@ -14,6 +18,7 @@ pub use gradients::{ColorMap, Colorful, Grayscale};
 
 use crate::{
     data::{YMapping, min_max_n_by},
+    fmt::{DateFormatter, TemperatureUnit},
     gradients::{create_r_gradient, create_t_gradient},
 };
 
@@ -39,19 +44,111 @@ pub struct SectionPlan {
 pub struct Plan<'a> {
     points: Vec<GraphPoint<'a>>,
     rect: Rect,
+    /// The near and far future
+    segments: [AxisSegment; 2],
+}
+
+/// Maps a time span linearly to a horizontal span.
+#[derive(Debug, Clone, Copy)]
+struct AxisSegment {
+    base_ts: jiff::Timestamp,
+    base_off: f32,
+    width: f32,
+    total_minutes: f32,
+}
+
+impl AxisSegment {
+    fn x_of(&self, ts: jiff::Timestamp) -> f32 {
+        let offset = (ts - self.base_ts).total(jiff::Unit::Minute).unwrap() as f32 * self.width
+            / self.total_minutes;
+        self.base_off + offset
+    }
+
+    fn end_ts(&self) -> jiff::Timestamp {
+        self.base_ts + (self.total_minutes as i64).minutes()
+    }
 }
 
-const BEGIN_HOURS: i32 = 8;
-const NEAR_FUT_HOURS: i32 = 12;
-const END_HOURS: i32 = 24 * 4; // yes, not every day has 24h, i know...
+impl Plan<'_> {
+    /// The x position of `ts`, clamped to the planned time span.
+    fn x_of(&self, ts: jiff::Timestamp) -> f32 {
+        let [near, far] = &self.segments;
+        if ts < far.base_ts {
+            near.x_of(ts.max(near.base_ts))
+        } else {
+            far.x_of(ts.min(far.end_ts()))
+        }
+    }
+}
 
-const BEGIN_MINUTES: i32 = BEGIN_HOURS * 60;
-const NEAR_FUT_MINUTES: i32 = NEAR_FUT_HOURS * 60;
-const END_MINUTES: i32 = END_HOURS * 60;
+/// The time span of a [`Plan`] and how it's split into sections.
+///
+/// The past and near future are labeled by hour, the far future by day.
+/// See [`PlanOptions::validate`] for the allowed values.
+#[derive(Debug, Clone, Copy)]
+pub struct PlanOptions {
+    /// Hours before now at the start of the plan
+    pub past_hours: i32,
+    /// Hours after now that are still labeled by hour
+    pub near_future_hours: i32,
+    /// Hours after now at the end of the plan
+    pub horizon_hours: i32,
+    /// Fraction of the width used for the past and near future
+    pub near_fraction: f32,
+    /// Label every n-th hour in the near section
+    pub hour_label_every: NonZeroUsize,
+}
+
+impl Default for PlanOptions {
+    fn default() -> Self {
+        Self {
+            past_hours: 8,
+            near_future_hours: 12,
+            horizon_hours: 24 * 4, // yes, not every day has 24h, i know...
+            near_fraction: 2.0 / 3.0,
+            hour_label_every: NonZeroUsize::new(2).unwrap(),
+        }
+    }
+}
+
+/// Why [`PlanOptions`] can't be used for a plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidPlanOptions {
+    #[error("The past and near future hours must not be negative")]
+    NegativeHours,
+    #[error("The near section of the plan is empty")]
+    EmptyNearSection,
+    #[error("The plan horizon must be after the near future")]
+    HorizonBeforeNearFuture,
+    #[error("The near fraction must be between 0 and 1")]
+    NearFraction,
+}
 
-const FIRST_SECTION_MINUTES: i32 = BEGIN_MINUTES + NEAR_FUT_MINUTES;
+impl PlanOptions {
+    /// Checks that the near section isn't empty, the horizon is after the near future
+    /// and both sections get some of the width.
+    pub fn validate(&self) -> Result<(), InvalidPlanOptions> {
+        if self.past_hours < 0 || self.near_future_hours < 0 {
+            Err(InvalidPlanOptions::NegativeHours)
+        } else if self.past_hours + self.near_future_hours == 0 {
+            Err(InvalidPlanOptions::EmptyNearSection)
+        } else if self.horizon_hours <= self.near_future_hours {
+            Err(InvalidPlanOptions::HorizonBeforeNearFuture)
+        } else if !(self.near_fraction > 0.0 && self.near_fraction < 1.0) {
+            Err(InvalidPlanOptions::NearFraction)
+        } else {
+            Ok(())
+        }
+    }
+}
 
-pub fn plan_in<'a>(rect: skia_safe::Rect, all_points: &'a [Datapoint]) -> (Plan<'a>, SectionPlan) {
+pub fn plan_in<'a>(
+    rect: skia_safe::Rect,
+    all_points: &'a [Datapoint],
+    options: &PlanOptions,
+    tz: &TimeZone,
+    locale: &Locale,
+) -> Result<(Plan<'a>, SectionPlan), InvalidPlanOptions> {
     let now = jiff::Timestamp::now()
         .round(
             jiff::TimestampRound::new()
@@ -59,9 +156,21 @@ pub fn plan_in<'a>(rect: skia_safe::Rect, all_points: &'a [Datapoint]) -> (Plan<
                 .mode(jiff::RoundMode::Trunc),
         )
         .unwrap();
-    let begin = now - BEGIN_HOURS.hours();
-    let near_fut_ts = now + NEAR_FUT_HOURS.hours();
-    let end = now + END_HOURS.hours();
+    plan_at(now, rect, all_points, options, tz, locale)
+}
+
+fn plan_at<'a>(
+    now: jiff::Timestamp,
+    rect: skia_safe::Rect,
+    all_points: &'a [Datapoint],
+    options: &PlanOptions,
+    tz: &TimeZone,
+    locale: &Locale,
+) -> Result<(Plan<'a>, SectionPlan), InvalidPlanOptions> {
+    options.validate()?;
+    let begin = now - options.past_hours.hours();
+    let near_fut_ts = now + options.near_future_hours.hours();
+    let end = now + options.horizon_hours.hours();
 
     let begin_i = all_points.partition_point(|it| it.timestamp < begin);
     let future_i = all_points.partition_point(|it| it.timestamp < near_fut_ts);
@@ -72,26 +181,29 @@ pub fn plan_in<'a>(rect: skia_safe::Rect, all_points: &'a [Datapoint]) -> (Plan<
 
     let mut plan = Vec::with_capacity(near.len() + far_fut.len());
     let mut sections = Vec::new();
-    let section_size = rect.width() / 3.0;
-    calc_x_offsets(
-        near,
-        rect.left,
-        2.0 * section_size,
-        FIRST_SECTION_MINUTES as f32,
-        begin,
-        &mut plan,
-    );
-
-    let my_tz = jiff::tz::db().get("Europe/Berlin").unwrap();
+    let near_width = rect.width() * options.near_fraction;
+    let near_segment = AxisSegment {
+        base_ts: begin,
+        base_off: rect.left,
+        width: near_width,
+        total_minutes: ((options.past_hours + options.near_future_hours) * 60) as f32,
+    };
+    let far_segment = AxisSegment {
+        base_ts: near_fut_ts,
+        base_off: rect.left + near_width,
+        width: rect.width() - near_width,
+        total_minutes: ((options.horizon_hours - options.near_future_hours) * 60) as f32,
+    };
+    calc_x_offsets(near, &near_segment, &mut plan);
 
     for (_, p) in plan
         .iter()
         .enumerate()
         .rev()
-        .step_by(2)
+        .step_by(options.hour_label_every.get())
         .filter(|it| it.0 != 0)
     {
-        let zoned = p.data.timestamp.to_zoned(my_tz.clone());
+        let zoned = p.data.timestamp.to_zoned(tz.clone());
         sections.push(Section {
             x: p.x_pos,
             text: zoned.strftime("%H").to_string(),
@@ -99,8 +211,9 @@ pub fn plan_in<'a>(rect: skia_safe::Rect, all_points: &'a [Datapoint]) -> (Plan<
         });
     }
 
-    let first_day = plan.first().map(|p| p.data.local_ts.day()).unwrap_or(42);
-    let mut cur_day = plan.last().map(|p| p.data.local_ts.day()).unwrap_or(42);
+    let day_of = |p: &GraphPoint| p.data.timestamp.to_zoned(tz.clone()).day();
+    let first_day = plan.first().map(day_of).unwrap_or(42);
+    let mut cur_day = plan.last().map(day_of).unwrap_or(42);
     let mut fut_start = plan.len();
 
     // The last section of the near future will be merged with the far future and show as a day
@@ -112,25 +225,20 @@ pub fn plan_in<'a>(rect: skia_safe::Rect, all_points: &'a [Datapoint]) -> (Plan<
         fut_start -= 1;
     }
 
-    calc_x_offsets(
-        far_fut,
-        rect.left + 2.0 * section_size,
-        section_size,
-        (END_MINUTES - NEAR_FUT_MINUTES) as f32,
-        near_fut_ts,
-        &mut plan,
-    );
+    calc_x_offsets(far_fut, &far_segment, &mut plan);
 
+    let dates = DateFormatter::new(locale);
     let mut pending_sec = None;
-    for p in &plan[fut_start..plan.len() - 3] {
-        let ts = p.data.timestamp.to_zoned(my_tz.clone());
+    let far_end = plan.len().saturating_sub(3).max(fut_start);
+    for p in &plan[fut_start..far_end] {
+        let ts = p.data.timestamp.to_zoned(tz.clone());
         if ts.day() != cur_day {
             if let Some(s) = pending_sec.take() {
                 sections.push(s);
             }
             pending_sec = Some(Section {
                 x: p.x_pos,
-                text: ts.strftime("%a").to_string(),
+                text: dates.weekday(ts.date()),
                 data: p.data.clone(),
             });
             cur_day = ts.day();
@@ -149,38 +257,79 @@ pub fn plan_in<'a>(rect: skia_safe::Rect, all_points: &'a [Datapoint]) -> (Plan<
     }
     sections.sort_by_key(|s| s.data.timestamp);
 
-    (
-        Plan { points: plan, rect },
+    Ok((
+        Plan {
+            points: plan,
+            rect,
+            segments: [near_segment, far_segment],
+        },
         SectionPlan {
             sections,
             near_base_ts: begin,
-            near_minute_scale: (2.0 * section_size) / (FIRST_SECTION_MINUTES as f32),
+            near_minute_scale: near_segment.width / near_segment.total_minutes,
         },
-    )
+    ))
 }
 
-fn calc_x_offsets<'a>(
-    src: &'a [Datapoint],
-    base_off: f32,
-    scale: f32,
-    total_minutes: f32,
-    base_ts: jiff::Timestamp,
-    dst: &mut Vec<GraphPoint<'a>>,
-) {
+fn calc_x_offsets<'a>(src: &'a [Datapoint], segment: &AxisSegment, dst: &mut Vec<GraphPoint<'a>>) {
     for p in src {
-        let offset = (p.timestamp - base_ts).total(jiff::Unit::Minute).unwrap() as f32 * scale
-            / total_minutes;
         dst.push(GraphPoint {
-            x_pos: base_off + offset,
+            x_pos: segment.x_of(p.timestamp),
             data: p,
         });
     }
 }
 
+/// The night between a sunset and the next sunrise, clipped to the plan.
+#[derive(Debug)]
+pub struct NightInterval {
+    pub start_x: f32,
+    pub end_x: f32,
+    /// `None` if the sun set before the start of the plan
+    pub sunset_x: Option<f32>,
+    /// `None` if the sun rises after the end of the plan
+    pub sunrise_x: Option<f32>,
+}
+
+pub struct NightPlan {
+    pub nights: Vec<NightInterval>,
+}
+
+/// The nights at `at` within the plan.
+///
+/// Days without a sunrise or sunset (polar day and night) don't produce intervals.
+pub fn create_night_plan(plan: &Plan, at: Coordinates) -> NightPlan {
+    let [near, far] = &plan.segments;
+    let (begin, end) = (near.base_ts, far.end_ts());
+    let utc_date = |ts: jiff::Timestamp| ts.to_zoned(jiff::tz::TimeZone::UTC).date();
+
+    let mut nights = Vec::new();
+    let mut day = utc_date(begin).yesterday().unwrap();
+    let mut prev_sunset = None;
+    while day <= utc_date(end) {
+        let times = SunTimes::on(day, at);
+        if let (Some(sunset), Some(sunrise)) = (prev_sunset, times.sunrise)
+            && sunset < end
+            && sunrise > begin
+        {
+            nights.push(NightInterval {
+                start_x: plan.x_of(sunset),
+                end_x: plan.x_of(sunrise),
+                sunset_x: (sunset >= begin).then(|| plan.x_of(sunset)),
+                sunrise_x: (sunrise <= end).then(|| plan.x_of(sunrise)),
+            });
+        }
+        prev_sunset = times.sunset;
+        day = day.tomorrow().unwrap();
+    }
+
+    NightPlan { nights }
+}
+
 pub struct HorizontalLine {
     pub y_pos: f32,
-    pub temperature: i32, // rounded
-    pub rain: Option<f32>,
+    pub temperature: i32,  // rounded, in the unit of the plan
+    pub rain: Option<f32>, // in mm
 }
 
 pub struct TemperaturePlan {
@@ -190,13 +339,19 @@ pub struct TemperaturePlan {
 
 pub fn create_temperature_path<M: ColorMap>(
     plan: &Plan,
+    unit: TemperatureUnit,
 ) -> (Option<TemperaturePlan>, Vec<HorizontalLine>) {
     // first, determine the bounds
     let Some((min, max, n_points)) = min_max_n_by(&plan.points, |p| p.data.temperature) else {
         return (None, Vec::new());
     };
-    let min = (min / 5.0).round() * 5.0 - 5.0;
-    let max = (max / 5.0).round() * 5.0 + 5.0;
+    let step = match unit {
+        TemperatureUnit::Celsius => 5.0,
+        TemperatureUnit::Fahrenheit => 10.0,
+    };
+    let (min, max) = (unit.convert(min), unit.convert(max));
+    let min = (min / step).round() * step - step;
+    let max = (max / step).round() * step + step;
     let mapping = YMapping::from_min_max(min, max, plan.rect);
 
     let mut points = Vec::with_capacity(n_points);
@@ -204,18 +359,21 @@ pub fn create_temperature_path<M: ColorMap>(
         let Some(t) = p.data.temperature else {
             continue;
         };
-        points.push(Point::new(p.x_pos, mapping.map(t)));
+        points.push(Point::new(p.x_pos, mapping.map(unit.convert(t))));
     }
 
     let path = lines::create_interpolated_path(&points);
 
-    let shader = create_t_gradient::<M>(mapping.map(M::MIN_T), mapping.map(M::MAX_T));
+    let shader = create_t_gradient::<M>(
+        mapping.map(unit.convert(M::MIN_T)),
+        mapping.map(unit.convert(M::MAX_T)),
+    );
     let mut horizontal = Vec::new();
     {
-        let mut t = min + 5.0;
+        let mut t = min + step;
         while t < max {
             horizontal.push(HorizontalLine::new(mapping.map(t).round(), t));
-            t += 5.0;
+            t += step;
         }
     }
 
@@ -341,6 +499,7 @@ pub struct RadarPlan {
 pub fn create_radar_plan<M: ColorMap>(
     inner_rect: Rect,
     values: &[RadarReading],
+    tz: &TimeZone,
 ) -> Option<RadarPlan> {
     if values.len() < 2 || values.iter().all(|v| v.value == 0.0) {
         return None;
@@ -355,7 +514,11 @@ pub fn create_radar_plan<M: ColorMap>(
     let reading_to_x = |r: &RadarReading| (r.timestamp.as_second() as f32 - start_s) / duration;
     let reading_to_lbl = |r: &RadarReading| XLabel {
         x_pos: inner_rect.left + reading_to_x(r) * inner_rect.width(),
-        text: r.local_ts.strftime("%H:%M").to_string(),
+        text: r
+            .timestamp
+            .to_zoned(tz.clone())
+            .strftime("%H:%M")
+            .to_string(),
     };
 
     let shader = create_r_gradient::<M>(values, (inner_rect.left, inner_rect.right), reading_to_x);
@@ -375,3 +538,194 @@ pub fn create_radar_plan<M: ColorMap>(
         end_text,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BERLIN: Coordinates = Coordinates {
+        latitude: 52.52,
+        longitude: 13.405,
+    };
+
+    /// A plan from `begin` with 100px for the near and far segment each.
+    fn plan(begin: &str, near_hours: i64, far_hours: i64) -> Plan<'static> {
+        let begin: jiff::Timestamp = begin.parse().unwrap();
+        let near = AxisSegment {
+            base_ts: begin,
+            base_off: 0.0,
+            width: 100.0,
+            total_minutes: (near_hours * 60) as f32,
+        };
+        let far = AxisSegment {
+            base_ts: begin + near_hours.hours(),
+            base_off: 100.0,
+            width: 100.0,
+            total_minutes: (far_hours * 60) as f32,
+        };
+        Plan {
+            points: Vec::new(),
+            rect: Rect::from_wh(200.0, 100.0),
+            segments: [near, far],
+        }
+    }
+
+    #[test]
+    fn nights_within_plan() {
+        // sunset at 19:33 and sunrise at 02:43 (UTC)
+        let days = plan("2025-06-21T12:00:00Z", 24, 48);
+        let nights = create_night_plan(&days, BERLIN).nights;
+        assert_eq!(nights.len(), 3);
+        let first = &nights[0];
+        assert_eq!(first.sunset_x, Some(first.start_x));
+        assert_eq!(first.sunrise_x, Some(first.end_x));
+        // 7.5h and 14.7h into the near segment
+        assert!((first.start_x - 31.3).abs() < 0.5, "{first:?}");
+        assert!((first.end_x - 61.3).abs() < 0.5, "{first:?}");
+        assert!(nights.windows(2).all(|n| n[0].end_x < n[1].start_x));
+    }
+
+    #[test]
+    fn nights_clipped_to_plan() {
+        // starts and ends during the night
+        let night = plan("2025-06-21T22:00:00Z", 2, 1);
+        let nights = create_night_plan(&night, BERLIN).nights;
+        assert_eq!(nights.len(), 1);
+        let night = &nights[0];
+        assert_eq!(night.start_x, 0.0);
+        assert_eq!(night.end_x, 200.0);
+        assert_eq!(night.sunset_x, None);
+        assert_eq!(night.sunrise_x, None);
+
+        // ends before the sunrise
+        let evening = plan("2025-06-21T18:00:00Z", 4, 4);
+        let nights = create_night_plan(&evening, BERLIN).nights;
+        assert_eq!(nights.len(), 1);
+        assert!(nights[0].sunset_x.is_some());
+        assert_eq!(nights[0].sunrise_x, None);
+        assert_eq!(nights[0].end_x, 200.0);
+    }
+
+    #[test]
+    fn polar_day_and_night() {
+        let tromso = Coordinates {
+            latitude: 69.65,
+            longitude: 18.96,
+        };
+        let summer = plan("2025-06-21T00:00:00Z", 24, 48);
+        assert!(create_night_plan(&summer, tromso).nights.is_empty());
+        // the sun doesn't rise either
+        let winter = plan("2025-12-21T00:00:00Z", 24, 48);
+        assert!(create_night_plan(&winter, tromso).nights.is_empty());
+    }
+
+    const SHORT: PlanOptions = PlanOptions {
+        past_hours: 2,
+        near_future_hours: 4,
+        horizon_hours: 24,
+        near_fraction: 0.5,
+        hour_label_every: NonZeroUsize::new(1).unwrap(),
+    };
+
+    /// Hourly points from 5h before `now` to 30h after it.
+    fn hourly(now: jiff::Timestamp) -> Vec<Datapoint> {
+        (-5..=30)
+            .map(|h| Datapoint::from_timestamp(now + h.hours(), h <= 0))
+            .collect()
+    }
+
+    fn texts(sections: &SectionPlan) -> Vec<&str> {
+        sections.sections.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn sections_with_custom_options() {
+        let now: jiff::Timestamp = "2025-06-21T10:00:00Z".parse().unwrap();
+        let points = hourly(now);
+        let (plan, sections) = plan_at(
+            now,
+            Rect::from_wh(200.0, 100.0),
+            &points,
+            &SHORT,
+            &TimeZone::UTC,
+            &icu_locale::locale!("en-GB"),
+        )
+        .unwrap();
+        assert_eq!(plan.points.len(), 26);
+        assert_eq!(plan.points[0].data.timestamp, now - 2.hours());
+        assert_eq!(plan.points[0].x_pos, 0.0);
+        // the near future ends at the middle
+        assert_eq!(plan.points[6].data.timestamp, now + 4.hours());
+        assert_eq!(plan.points[6].x_pos, 100.0);
+        assert_eq!(sections.near_minute_scale, 100.0 / 360.0);
+        assert_eq!(texts(&sections), ["09", "10", "11", "12", "13", "Sun"]);
+
+        let every_other = PlanOptions {
+            hour_label_every: NonZeroUsize::new(2).unwrap(),
+            ..SHORT
+        };
+        let (_, sections) = plan_at(
+            now,
+            Rect::from_wh(200.0, 100.0),
+            &points,
+            &every_other,
+            &TimeZone::UTC,
+            &icu_locale::locale!("en-GB"),
+        )
+        .unwrap();
+        assert_eq!(texts(&sections), ["09", "11", "13", "Sun"]);
+    }
+
+    #[test]
+    fn invalid_options() {
+        assert_eq!(SHORT.validate(), Ok(()));
+        assert_eq!(PlanOptions::default().validate(), Ok(()));
+        let invalid = |options: PlanOptions| options.validate().unwrap_err();
+        assert_eq!(
+            invalid(PlanOptions {
+                past_hours: -1,
+                ..SHORT
+            }),
+            InvalidPlanOptions::NegativeHours
+        );
+        assert_eq!(
+            invalid(PlanOptions {
+                past_hours: 0,
+                near_future_hours: 0,
+                ..SHORT
+            }),
+            InvalidPlanOptions::EmptyNearSection
+        );
+        assert_eq!(
+            invalid(PlanOptions {
+                horizon_hours: 4,
+                ..SHORT
+            }),
+            InvalidPlanOptions::HorizonBeforeNearFuture
+        );
+        for near_fraction in [0.0, 1.5, f32::NAN] {
+            assert_eq!(
+                invalid(PlanOptions {
+                    near_fraction,
+                    ..SHORT
+                }),
+                InvalidPlanOptions::NearFraction
+            );
+        }
+
+        let options = PlanOptions {
+            horizon_hours: 4,
+            ..SHORT
+        };
+        let now = jiff::Timestamp::UNIX_EPOCH;
+        let res = plan_at(
+            now,
+            Rect::from_wh(200.0, 100.0),
+            &[],
+            &options,
+            &TimeZone::UTC,
+            &icu_locale::locale!("en-GB"),
+        );
+        assert_eq!(res.err(), Some(InvalidPlanOptions::HorizonBeforeNearFuture));
+    }
+}
@@ -6,6 +6,7 @@
 
 from typing import Optional
 import xml.etree.ElementTree as ET
+import csv
 import re
 import os
 import sys
@@ -16,10 +17,16 @@ from datetime import datetime, timezone
 
 import urllib.request
 
-if len(sys.argv) < 2:
-    print("Usage: read-libdwd-tables.py <path-to-DWD-bufrtables>")
+if len(sys.argv) < 3:
+    print(
+        "Usage: read-libdwd-tables.py <path-to-DWD-bufrtables> <master>_<local> [path-to-BUFRCREX_CodeFlag_en.txt]"
+    )
     exit(1)
 
+# code and flag tables to include (all of them would be a few MB)
+CODE_DESCRIPTORS = ["020003", "020004", "020005", "020011"]
+FLAG_DESCRIPTORS = ["002002"]
+
 existing_table_b = urllib.request.urlopen(
     "https://raw.githubusercontent.com/MIERUNE/tinybufr/master/src/tables/table_b.rs"
 ).read()
@@ -141,39 +148,62 @@ for f in d_files:
 
 print(len(new_xy_b), len(new_xy_d))
 
-OUT_FILE = Path(__file__).parent.parent / "src" / "dwd" / "bufr_tables.rs"
-with open(OUT_FILE, "w") as f:
-    f.write(
-        r"""// This file was automatically generated
+OUT_DIR = Path(__file__).parent.parent / "lib" / "dwd-bufr-tables" / "tables"
+VERSION_DIR = OUT_DIR / sys.argv[2]
+VERSION_DIR.mkdir(parents=True, exist_ok=True)
 
-use tinybufr::{TableBEntry, TableDEntry, XY, Descriptor};
-"""
-    )
-    f.write(f"pub static DWD_BUFR_TABLE_B: [TableBEntry; {len(new_xy_b)}] = [\n")
+
+def fxy(id: FXY) -> str:
+    return f"{id[0]}{id[1]:02}{id[2]:03}"
+
+
+with open(VERSION_DIR / "table_b.csv", "w", newline="") as f:
+    w = csv.writer(f, lineterminator="\n")
+    w.writerow(["fxy", "name", "unit", "scale", "reference", "bits"])
     for k, v in new_xy_b.items():
-        f.write(
-            f"""TableBEntry{{  xy: XY {{ x: {k[1]}, y: {k[2]} }},
-            class_name: "dwd",
-            element_name: "{v.name.replace('"', '\\"')}",
-            unit: "{v.unit}",
-            scale: {v.scale},
-            reference_value: {v.reference},
-            bits: {v.bits},
-        }},"""
-        )
-    f.write("];\n\n")
-
-    f.write(f"pub static DWD_BUFR_TABLE_D: [TableDEntry; {len(new_xy_d)}] = [\n")
-    for k, v in new_xy_d.items():
-        f.write(
-            f"""TableDEntry {{
-        xy: XY {{ x: {k[1]}, y: {k[2]} }},
-        category: "DWD sequences",
-        title: "{v[0].replace('"', '\\"')}",
-        sub_title: "",
-        elements: &[
-            {"\n".join(f"Descriptor {{ f: {f}, x: {x}, y: {y} }}," for f, x, y in v[1])}
-        ],
-    }},"""
-        )
-    f.write("];\n")
+        w.writerow([fxy(k), v.name, v.unit, v.scale, v.reference, v.bits])
+
+with open(VERSION_DIR / "table_d.csv", "w", newline="") as f:
+    w = csv.writer(f, lineterminator="\n")
+    w.writerow(["fxy", "title", "element"])
+    for k, (title, elements) in new_xy_d.items():
+        for element in elements:
+            w.writerow([fxy(k), title, fxy(element)])
+
+
+# line format (WMO BUFR4 CSV): "FXY,ElementName_en,CodeFigure,EntryName_en,..."
+# CodeFigure is the code (code tables) or bit number (flag tables), possibly a range like "106-109"
+def process_code_flag_file(path: str) -> tuple[list, list]:
+    codes: list[tuple[FXY, int, str]] = []
+    flags: list[tuple[FXY, int, str]] = []
+    with open(path, newline="", encoding="utf-8") as f:
+        for row in csv.DictReader(f):
+            if row["FXY"] not in CODE_DESCRIPTORS + FLAG_DESCRIPTORS:
+                continue
+            meaning = row["EntryName_en"].strip()
+            if not meaning or meaning.startswith("Reserved") or meaning == "Missing value":
+                continue
+            figure = row["CodeFigure"].strip()
+            if not figure.isdigit():
+                continue
+            id = parse_id(row["FXY"])
+            if row["FXY"] in FLAG_DESCRIPTORS:
+                flags.append((id, int(figure), meaning))
+            else:
+                codes.append((id, int(figure), meaning))
+    codes.sort(key=lambda it: (it[0], it[1]))
+    flags.sort(key=lambda it: (it[0], it[1]))
+    return codes, flags
+
+
+if len(sys.argv) >= 4:
+    codes, flags = process_code_flag_file(sys.argv[3])
+    for name, column, rows in [
+        ("code_tables.csv", "code", codes),
+        ("flag_tables.csv", "bit", flags),
+    ]:
+        with open(OUT_DIR / name, "w", newline="") as f:
+            w = csv.writer(f, lineterminator="\n")
+            w.writerow(["fxy", column, "meaning"])
+            for k, code, meaning in rows:
+                w.writerow([fxy(k), code, meaning])
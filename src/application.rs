@@ -4,7 +4,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use dwd_fetch::Cache;
+use dwd_fetch::Location;
 use windows::Win32::{
     Foundation::HWND,
     UI::WindowsAndMessaging::{MSG, RegisterWindowMessageW, WM_DISPLAYCHANGE},
@@ -177,7 +177,12 @@ impl Application {
         let event_loop = builder.build().unwrap();
         let proxy = event_loop.create_proxy();
         *msg_proxy.borrow_mut() = Some(proxy.clone());
-        let cache = self.context.cache.clone();
+        let locations = self.context.locations.clone();
+        let radar_cache = self
+            .context
+            .widget_location(CONFIG.current_location())
+            .cache
+            .clone();
         let pico = self.context.picolini.clone();
         let notifier = NotifyHandle::new();
         crate::http_server::run_http(CONFIG.server_port(), notifier.weak());
@@ -188,7 +193,7 @@ impl Application {
             loop {
                 std::thread::sleep(Duration::from_secs(30));
                 let pico_hdl = PicoliniCache::start_refresh();
-                let cache_res = Cache::refetch(&cache, CONFIG.dwd()).unwrap_or_default();
+                let cache_res = Location::refetch_all(&locations).unwrap_or_default();
                 let pico_res = pico.write().unwrap().collect_refresh(pico_hdl);
                 if cache_res {
                     tracing::info!("DWD updated");
@@ -203,7 +208,8 @@ impl Application {
                 } else {
                     pending_ticks -= 1;
                 }
-                notifier.tick(&cache.write().unwrap().radar);
+                let cache = radar_cache.read().unwrap();
+                notifier.tick(&cache.radar, &cache.forecast);
             }
         });
         event_loop
@@ -1,30 +1,54 @@
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
-use dwd_fetch::PoiStation;
+use dwd_fetch::{Cache, Location, description::Language};
+use icu_locale::Locale;
+use jiff::tz::TimeZone;
+use weather_layout::{
+    PlanOptions,
+    config::{DEFAULT_LOCATION, DisplayConfig, DisplayData, LocationData, dwd_locations},
+    fmt::Units,
+};
 
 const DEFAULT_SERVER_PORT: u16 = 47549;
 
+/// Which location is shown in which widget
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct WidgetData {
+    #[serde(default)]
+    forecast: Option<String>,
+    #[serde(default)]
+    current: Option<String>,
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 struct ConfigData {
-    station: u16,
-    latitude: f64,
-    longitude: f64,
+    #[serde(flatten)]
+    location: LocationData,
+    #[serde(default)]
+    locations: BTreeMap<String, LocationData>,
+    #[serde(default)]
+    widgets: WidgetData,
     cache_file: String,
     monitor_at_pos: (i32, i32),
-    synop_stations: Vec<String>,
     picolini_url: String,
     access_secret: String,
+    #[serde(flatten)]
+    display: DisplayData,
     server_port: Option<u16>,
 }
 
 pub struct Config {
-    dwd: dwd_fetch::Config,
+    locations: Vec<(String, dwd_fetch::Config)>,
+    forecast_location: String,
+    current_location: String,
     cache_file: String,
     monitor_at_pos: (i32, i32),
     picolini_url: String,
     access_secret: String,
     server_port: u16,
+    display: DisplayConfig,
 }
 
 pub static CONFIG: LazyLock<Config> = LazyLock::new(|| {
@@ -40,38 +64,99 @@ pub static CONFIG: LazyLock<Config> = LazyLock::new(|| {
 
 impl Config {
     fn new(data: ConfigData) -> Self {
-        let radar_coords = latlong_to_idx(data.latitude, data.longitude);
-        let dwd = dwd_fetch::Config {
-            poi_station: PoiStation(data.station),
-            radar_coords,
-            synop_stations: data.synop_stations,
+        let locations = dwd_locations(data.location, data.locations);
+        let widget_location = |name: Option<String>| {
+            let name = name.unwrap_or_else(|| DEFAULT_LOCATION.to_owned());
+            if !locations.iter().any(|(n, _)| *n == name) {
+                panic!("Unknown location '{name}' in widgets");
+            }
+            name
         };
+        let forecast_location = widget_location(data.widgets.forecast);
+        let current_location = widget_location(data.widgets.current);
+
         Self {
-            dwd,
+            locations,
+            forecast_location,
+            current_location,
             monitor_at_pos: data.monitor_at_pos,
             cache_file: data.cache_file,
             picolini_url: data.picolini_url,
             access_secret: data.access_secret,
+            display: data.display.load(),
             server_port: data.server_port.unwrap_or(DEFAULT_SERVER_PORT),
         }
     }
 
-    pub fn cache_file(&self) -> &str {
-        &self.cache_file
-    }
-
     pub fn monitor_at_pos(&self) -> (i32, i32) {
         self.monitor_at_pos
     }
 
-    pub fn dwd(&self) -> &dwd_fetch::Config {
-        &self.dwd
+    /// Creates the locations with their caches loaded from disk
+    pub fn load_locations(&self) -> Vec<Location> {
+        self.locations
+            .iter()
+            .map(|(name, config)| {
+                Location::new(
+                    name.clone(),
+                    config.clone(),
+                    Cache::from_file_or_default(&self.cache_file_for(name)),
+                )
+            })
+            .collect()
+    }
+
+    /// The cache file of a location. The default location uses `cache_file` directly.
+    pub fn cache_file_for(&self, location: &str) -> String {
+        if location == DEFAULT_LOCATION {
+            return self.cache_file.clone();
+        }
+        let path = Path::new(&self.cache_file);
+        let mut name = path.file_stem().unwrap_or_default().to_os_string();
+        name.push("-");
+        name.push(location);
+        if let Some(ext) = path.extension() {
+            name.push(".");
+            name.push(ext);
+        }
+        path.with_file_name(name).to_string_lossy().into_owned()
+    }
+
+    /// Location shown in the main forecast graph
+    pub fn forecast_location(&self) -> &str {
+        &self.forecast_location
+    }
+
+    /// Location shown in the side panel with the current conditions and radar
+    pub fn current_location(&self) -> &str {
+        &self.current_location
     }
 
     pub fn access_secret(&self) -> &str {
         &self.access_secret
     }
 
+    pub fn language(&self) -> Language {
+        self.display.language
+    }
+
+    pub fn units(&self) -> Units {
+        self.display.units
+    }
+
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.display.time_zone
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.display.locale
+    }
+
+    /// Time span of the forecast graph, already validated
+    pub fn plan_options(&self) -> &PlanOptions {
+        &self.display.plan_options
+    }
+
     pub fn picolini_url(&self) -> &str {
         &self.picolini_url
     }
@@ -81,19 +166,6 @@ impl Config {
     }
 }
 
-fn latlong_to_idx(lat: f64, long: f64) -> (usize, usize) {
-    let proj = proj4rs::Proj::from_user_string("+proj=stere +lat_0=90 +lat_ts=60 +lon_0=10 +a=6378137 +b=6356752.3142451802 +no_defs +x_0=543196.83521776402 +y_0=3622588.8619310018").unwrap();
-    let latlon = proj4rs::Proj::from_user_string("+proj=latlong").unwrap();
-    let mut p = (long.to_radians(), lat.to_radians());
-    proj4rs::transform::transform(&latlon, &proj, &mut p).unwrap();
-    let (x, y) = p;
-    // for some reason, y is negative
-    (
-        (x.round() / 1000.0) as usize,
-        (-(y.round() / 1000.0)) as usize,
-    )
-}
-
 #[cfg(windows)]
 fn default_config_paths() -> [PathBuf; 2] {
     [PathBuf::from("config.toml"), {
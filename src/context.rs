@@ -15,18 +15,19 @@ use crate::{
     },
     paint::{
         BlurredSquircleItem, CurrentTime, ImageItem, LineItem, LinesItem, PaintLineItem, PathItem,
-        Pipeline, RestoreOp, RrectItem, ShaderClipOp, TextItem, TextsItem,
+        Pipeline, RectItem, RestoreOp, RrectItem, ShaderClipOp, TextItem, TextsItem,
     },
     picolini::{self, PicoliniCache, PicoliniPlan},
 };
-use dwd_fetch::{Cache, Datapoint, icons::Msn};
+use dwd_fetch::{Cache, Coordinates, Datapoint, Location, icons::Msn};
 use weather_layout::{
-    self, Colorful, HorizontalLine, PPrecipitationPlan, RadarPlan, RainPlan, SectionPlan,
-    TemperaturePlan,
+    self, Colorful, HorizontalLine, NightPlan, PPrecipitationPlan, RadarPlan, RainPlan,
+    SectionPlan, TemperaturePlan,
 };
 
 pub struct Context {
-    pub cache: Arc<RwLock<Cache>>,
+    /// All locations - the default location is always first
+    pub locations: Vec<Location>,
     pub picolini: Arc<RwLock<PicoliniCache>>,
     pub plans: Option<Plans>,
     pub icons: IconRenderer<Msn>,
@@ -40,6 +41,7 @@ pub struct Plans {
     pub temperature: Option<TemperaturePlan>,
     pub rain: Option<RainPlan>,
     pub p_precipitation: Option<PPrecipitationPlan>,
+    pub nights: NightPlan,
     pub horizontal_lines: Vec<HorizontalLine>,
     pub current: Option<Datapoint>,
     pub radar: Option<RadarPlan>,
@@ -47,21 +49,39 @@ pub struct Plans {
 }
 
 impl Plans {
-    pub fn new(cache: &Cache, picolini: &PicoliniCache, ctx: &LayoutCtx) -> Self {
-        let merged = Datapoint::merge_series_ref(&cache.report, &cache.forecast);
-        let (overall, sections) = weather_layout::plan_in(ctx.main_rect, &merged);
-        let (temperature, mut horizontal_lines) =
-            weather_layout::create_temperature_path::<Colorful>(&overall);
+    pub fn new(
+        forecast: &Cache,
+        forecast_at: Coordinates,
+        current: &Cache,
+        picolini: &PicoliniCache,
+        ctx: &LayoutCtx,
+    ) -> Self {
+        let merged = Datapoint::merge_series_ref(&forecast.report, &forecast.forecast);
+        let (overall, sections) = weather_layout::plan_in(
+            ctx.main_rect,
+            &merged,
+            CONFIG.plan_options(),
+            CONFIG.time_zone(),
+            CONFIG.locale(),
+        )
+        .expect("The plan options are validated with the config");
+        let (temperature, mut horizontal_lines) = weather_layout::create_temperature_path::<Colorful>(
+            &overall,
+            CONFIG.units().temperature,
+        );
         let rain = weather_layout::create_rain_plan::<Colorful>(&overall, &mut horizontal_lines);
         let p_precipitation = weather_layout::create_p_precipitation_plan(&overall);
-        let current = cache
-            .observation
-            .clone()
-            .or_else(|| merged.iter().rfind(|x| x.is_report).cloned());
+        let nights = weather_layout::create_night_plan(&overall, forecast_at);
         let radar = weather_layout::create_radar_plan::<Colorful>(
             ctx.r_side_rect.with_inset((20.0, 0.0)),
-            &cache.radar,
+            &current.radar,
+            CONFIG.time_zone(),
         );
+        let current = current.observation().or_else(|| {
+            Datapoint::merge_series_ref(&current.report, &current.forecast)
+                .into_iter()
+                .rfind(|x| x.is_report)
+        });
         let picolini = picolini.plan(ctx.l_inner_rect);
 
         Self {
@@ -69,6 +89,7 @@ impl Plans {
             temperature,
             rain,
             p_precipitation,
+            nights,
             horizontal_lines,
             current,
             radar,
@@ -78,11 +99,12 @@ impl Plans {
 }
 
 impl Context {
-    pub fn new(cache: Cache, bg_image: Image) -> Self {
+    pub fn new(locations: Vec<Location>, bg_image: Image) -> Self {
+        assert!(!locations.is_empty(), "At least one location is required");
         let layout_ctx =
             LayoutCtx::new(Size::new(bg_image.width() as f32, bg_image.height() as f32));
         Self {
-            cache: Arc::new(RwLock::new(cache)),
+            locations,
             picolini: Arc::new(RwLock::new(PicoliniCache::new())),
             plans: None,
             icons: IconRenderer::new(),
@@ -93,7 +115,7 @@ impl Context {
 
     pub fn update(&mut self) -> bool {
         let pico_hdl = PicoliniCache::start_refresh();
-        let cache_res = Cache::refetch(&self.cache, CONFIG.dwd()).unwrap();
+        let cache_res = Location::refetch_all(&self.locations).unwrap();
         let pico_res = self.picolini.write().unwrap().collect_refresh(pico_hdl);
         if pico_res || cache_res {
             self.replan();
@@ -104,15 +126,30 @@ impl Context {
     }
 
     pub fn replan(&mut self) {
-        let cache = self.cache.read().unwrap();
-        cache.to_file(CONFIG.cache_file()).unwrap();
+        for location in &self.locations {
+            location
+                .cache
+                .read()
+                .unwrap()
+                .to_file(&CONFIG.cache_file_for(&location.name))
+                .unwrap();
+        }
+        let forecast = self.widget_location(CONFIG.forecast_location());
+        let current = self.widget_location(CONFIG.current_location());
         self.plans = Some(Plans::new(
-            &cache,
+            &forecast.cache.read().unwrap(),
+            forecast.config.coordinates,
+            &current.cache.read().unwrap(),
             &self.picolini.read().unwrap(),
             &self.layout_ctx,
         ));
     }
 
+    /// The location shown in a widget - falls back to the default location
+    pub fn widget_location(&self, name: &str) -> &Location {
+        Location::find(&self.locations, name).unwrap_or(&self.locations[0])
+    }
+
     pub fn relayout(&mut self, pipl: &mut Pipeline) {
         pipl.items.clear();
         pipl.add(ImageItem {
@@ -126,6 +163,10 @@ impl Context {
     }
 
     fn main_pipeline(&mut self, pipl: &mut Pipeline) {
+        let at = self
+            .widget_location(CONFIG.forecast_location())
+            .config
+            .coordinates;
         let Some(ref plans) = self.plans else {
             return;
         };
@@ -136,6 +177,35 @@ impl Context {
             shader: mask_gradient_vert(outer_rect),
             save: true,
         });
+        for night in &plans.nights.nights {
+            pipl.add(RectItem {
+                rect: Rect::from_ltrb(
+                    night.start_x,
+                    outer_rect.top,
+                    night.end_x,
+                    outer_rect.bottom,
+                ),
+                color: Color::from_argb(40, 0, 0, 0),
+            });
+        }
+        let main_bottom = self.layout_ctx.main_rect.bottom;
+        pipl.add(LinesItem {
+            points: plans
+                .nights
+                .nights
+                .iter()
+                .flat_map(|n| [n.sunset_x, n.sunrise_x])
+                .flatten()
+                .map(|x| {
+                    (
+                        Point::new(x, main_bottom - 3.0),
+                        Point::new(x, main_bottom + 3.0),
+                    )
+                })
+                .collect(),
+            color: Color::from_argb(160, 255, 214, 120),
+            stroke: 1.0,
+        });
         pipl.add(LinesItem {
             points: plans
                 .sections
@@ -237,8 +307,11 @@ impl Context {
             ));
 
             if let Some(r) = line.rain {
-                let blob =
-                    TextBlob::new(format!("{r:.1} mm"), &self.layout_ctx.fonts.small).unwrap();
+                let blob = TextBlob::new(
+                    CONFIG.units().precipitation(r),
+                    &self.layout_ctx.fonts.small,
+                )
+                .unwrap();
                 let w = blob.bounds().width();
                 texts.push((
                     blob,
@@ -261,7 +334,7 @@ impl Context {
                 24.0,
                 24.0,
             );
-            if let Some(item) = self.icons.layout(&cur.data, rect) {
+            if let Some(item) = self.icons.layout(&cur.data, at, rect) {
                 pipl.add(item);
             }
         }
@@ -270,6 +343,10 @@ impl Context {
     }
 
     fn r_side_pipl(&mut self, pipl: &mut Pipeline) {
+        let at = self
+            .widget_location(CONFIG.current_location())
+            .config
+            .coordinates;
         let Some(current) = self.plans.as_ref().and_then(|p| p.current.as_ref()) else {
             return;
         };
@@ -282,19 +359,38 @@ impl Context {
 
         if let Some(temp) = current.temperature {
             let (blob, pos) = align_text(
-                &format!("{temp:.*}°", if temp.fract() == 0.0 { 0 } else { 1 }),
+                &CONFIG.units().temperature(temp),
                 &self.layout_ctx.fonts.large,
                 self.layout_ctx.r_side_rect.top_right() + Point::new(-20.0, 20.0),
                 Align::TopRight,
             );
+            let below = pos.y + 4.0;
             pipl.add(TextItem {
                 blob,
                 pos,
                 color: Color::WHITE,
             });
+
+            if let Some(feels_like) = current
+                .apparent_temperature()
+                .and_then(|feels| CONFIG.units().feels_like(CONFIG.language(), temp, feels))
+            {
+                let (blob, pos) = align_text(
+                    &feels_like,
+                    &self.layout_ctx.fonts.small,
+                    (self.layout_ctx.r_side_rect.right - 20.0, below),
+                    Align::TopRight,
+                );
+                pipl.add(TextItem {
+                    blob,
+                    pos,
+                    color: Color::WHITE,
+                });
+            }
         }
         if let Some(svg) = self.icons.layout(
             current,
+            at,
             Rect::from_xywh(
                 self.layout_ctx.r_side_rect.left + 20.0,
                 self.layout_ctx.r_side_rect.top + 20.0,
@@ -325,6 +421,10 @@ impl Context {
             ));
         };
 
+        if let Some(text) = current.description(CONFIG.language()) {
+            y += 25.0;
+            label(&mut texts, text, y);
+        }
         if let Some(cc) = current.cloud_cover {
             y += 25.0;
             label(&mut texts, "Cloud Cover", y);
@@ -333,7 +433,7 @@ impl Context {
         if let Some(wind) = current.mean_wind {
             y += 25.0;
             label(&mut texts, "Wind Speed", y);
-            unit(&mut texts, &format!("{wind:.1}\u{00A0}km/h"), y);
+            unit(&mut texts, &CONFIG.units().wind(wind), y);
         }
         if let Some(h) = current.relative_humidity {
             y += 25.0;
@@ -6,7 +6,7 @@ use skia_safe::{
 use std::{collections::HashMap, fs, marker::PhantomData, path::PathBuf};
 
 use crate::paint::SvgItem;
-use dwd_fetch::{Datapoint, icons::IconSet};
+use dwd_fetch::{Coordinates, Datapoint, icons::IconSet};
 
 pub struct IconRenderer<I> {
     cache: HashMap<PathBuf, Option<Dom>>,
@@ -25,8 +25,8 @@ impl<I> IconRenderer<I> {
 }
 
 impl<I: IconSet> IconRenderer<I> {
-    pub fn layout(&mut self, d: &Datapoint, rect: Rect) -> Option<SvgItem> {
-        let path = d.icon::<I>()?;
+    pub fn layout(&mut self, d: &Datapoint, at: Coordinates, rect: Rect) -> Option<SvgItem> {
+        let path = d.icon::<I>(at)?;
         let dom = self
             .cache
             .entry(path)
@@ -98,10 +98,7 @@ fn main() -> anyhow::Result<()> {
     )
     .ok_or_else(|| anyhow!("Failed to read bg.png as image"))?;
 
-    let mut context = Context::new(
-        dwd_fetch::Cache::from_file_or_default(CONFIG.cache_file()),
-        bg_img,
-    );
+    let mut context = Context::new(CONFIG.load_locations(), bg_img);
     if !context.update() {
         context.replan();
     }
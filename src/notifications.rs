@@ -1,7 +1,9 @@
 use std::sync::{Arc, RwLock, Weak};
 
-use dwd_fetch::RadarReading;
-use jiff::{SignedDuration, tz::TimeZone};
+use dwd_fetch::{Datapoint, RadarReading, condition::Condition, description::Language};
+use jiff::SignedDuration;
+
+use crate::config::CONFIG;
 
 /// Max interval without rain where different streaks absorb each other.
 const ABSORB_UNTIL_MIN: i64 = 15;
@@ -25,11 +27,11 @@ impl NotifyHandle {
         }
     }
 
-    pub fn tick(&self, radar: &[RadarReading]) {
+    pub fn tick(&self, radar: &[RadarReading], forecast: &[Datapoint]) {
         let Ok(mut v) = self.state.write() else {
             return;
         };
-        v.tick(radar);
+        v.tick(radar, forecast);
     }
 
     pub fn weak(&self) -> WeakNotifyHandle {
@@ -64,7 +66,7 @@ impl NotifyState {
         }
     }
 
-    fn tick(&mut self, radar: &[RadarReading]) {
+    fn tick(&mut self, radar: &[RadarReading], forecast: &[Datapoint]) {
         if !self.enabled {
             return;
         }
@@ -87,13 +89,18 @@ impl NotifyState {
             for_rain_at: start - SignedDuration::from_mins(NOTIFY_BEFORE_MIN),
             active_until: end,
         });
+        let (summary, body) = texts(
+            CONFIG.language(),
+            expected_condition(forecast, start),
+            dur.max(SignedDuration::ZERO).as_mins(),
+            &start
+                .to_zoned(CONFIG.time_zone().clone())
+                .strftime("%H:%M")
+                .to_string(),
+        );
         let res = notify_rust::Notification::new()
-            .summary("Rain Notification")
-            .body(&format!(
-                "Rain is expected in {} min ({}).",
-                dur.max(SignedDuration::ZERO).as_mins(),
-                start.to_zoned(TimeZone::system()).strftime("%H:%M")
-            ))
+            .summary(summary)
+            .body(&body)
             .appname("Background")
             .finalize()
             .show();
@@ -147,6 +154,34 @@ fn split_binary<'a, T>(
     }
 }
 
+/// Summary and body of the notification, `what` is the expected precipitation if known.
+fn texts(lang: Language, what: Option<&str>, mins: i64, at: &str) -> (&'static str, String) {
+    match lang {
+        Language::German => (
+            "Regenwarnung",
+            format!("{} in {mins} min erwartet ({at}).", what.unwrap_or("Regen")),
+        ),
+        Language::English => (
+            "Rain Notification",
+            format!("{} expected in {mins} min ({at}).", what.unwrap_or("Rain")),
+        ),
+    }
+}
+
+/// The text of the forecasted precipitation in the hour starting at `at`.
+fn expected_condition(forecast: &[Datapoint], at: jiff::Timestamp) -> Option<&'static str> {
+    // conditions describe the hour before the timestamp
+    let point = split_binary(forecast, |x| x.timestamp.cmp(&at)).1.first()?;
+    if point.timestamp.duration_since(at) > SignedDuration::from_hours(1) {
+        return None;
+    }
+    point
+        .condition
+        .normalized()
+        .filter(Condition::is_precipitation)?;
+    point.description(CONFIG.language())
+}
+
 fn find_rain_streak(radar: &[RadarReading]) -> Option<(jiff::Timestamp, jiff::Timestamp)> {
     let mut range = None;
     for reading in radar {
@@ -9,7 +9,6 @@ use skia_util::{RectExt, color::rgb4f, gridify};
 use weather_layout::{
     ColorMap,
     data::{YMapping, min_max_n_by},
-    fmt,
     lines::create_interpolated_path,
 };
 
@@ -87,9 +86,7 @@ fn get() -> Vec<DataItem> {
             format!("Bearer {}", CONFIG.access_secret()),
         )
         .call()
-        .inspect_err(|e| {
-            dbg!(e);
-        })
+        .inspect_err(|e| tracing::warn!(%e, "Failed to get picolini history"))
     else {
         return Vec::new();
     };
@@ -109,13 +106,12 @@ pub fn plan(items: &[DataItem], rect: Rect) -> Option<PicoliniPlan> {
     let graph_width = temp[0].width();
     let ts_to_x = |ts: i64| temp[0].left + ((ts - first.timestamp) as f32 / ts_span) * graph_width;
 
-    let my_tz = jiff::tz::db().get("Europe/Berlin").unwrap();
     let start_zoned = jiff::Timestamp::from_millisecond(first.timestamp)
         .unwrap_or_default()
-        .to_zoned(my_tz.clone());
+        .to_zoned(CONFIG.time_zone().clone());
     let cur_zoned = jiff::Timestamp::from_millisecond(current.timestamp)
         .unwrap_or_default()
-        .to_zoned(my_tz);
+        .to_zoned(CONFIG.time_zone().clone());
 
     let top_label = format!(
         "{} — {}",
@@ -132,7 +128,7 @@ pub fn plan(items: &[DataItem], rect: Rect) -> Option<PicoliniPlan> {
             ts_to_x,
             1.0,
             0.0,
-            format!("{} °C", fmt::optional_fract(current.temperature)),
+            CONFIG.units().temperature_with_symbol(current.temperature),
         ),
         iaq: make_item::<IaqColors>(
             iaq,